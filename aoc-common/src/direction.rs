@@ -0,0 +1,57 @@
+use Direction::*;
+
+// One of the four directions on a 2D map (up is towards smaller y)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    // Clockwise, starting from the top
+    pub const ALL: [Direction; 4] = [Up, Right, Down, Left];
+
+    // Accepts all the notations used by the puzzles: UDLR, ^v<> and NSEW
+    pub fn from_char(c: char) -> Option<Direction> {
+        match c {
+            'U' | '^' | 'N' => Some(Up),
+            'R' | '>' | 'E' => Some(Right),
+            'D' | 'v' | 'S' => Some(Down),
+            'L' | '<' | 'W' => Some(Left),
+            _ => None,
+        }
+    }
+
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Up => Right,
+            Right => Down,
+            Down => Left,
+            Left => Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Direction {
+        match self {
+            Up => Left,
+            Left => Down,
+            Down => Right,
+            Right => Up,
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Up => Down,
+            Right => Left,
+            Down => Up,
+            Left => Right,
+        }
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Left | Right)
+    }
+}
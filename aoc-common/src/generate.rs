@@ -0,0 +1,34 @@
+use rand::SeedableRng;
+use crate::{Answer, Solver};
+
+// Everything the generators need, so that the day crates do not have to depend on rand themselves
+pub use rand::rngs::StdRng;
+pub use rand::seq::{IndexedRandom, SliceRandom};
+pub use rand::Rng;
+
+// Days that can make up random inputs of their own, to stress-test the solvers on inputs
+// other than the demo and the real one. The generated inputs are always valid: they parse
+// and follow whatever the puzzle promises about them (a path to the exit, a single zero, etc.)
+pub trait Generator: Solver {
+    // What the size means is up to the day: lines of the input, side of a map, number of valves...
+    // The default is about as large as the real input.
+    const DEFAULT_SIZE: usize;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String;
+
+    // A slow but plainly correct answer to check the solver against, for the days that have one.
+    // It only has to cope with the generated inputs, which are kept small enough for it.
+    fn reference(_input: &Self::Input, _part: u8) -> Option<Answer> {
+        None
+    }
+}
+
+// Same seed, same input, so that the failures can be reproduced
+pub fn rng(seed: u64) -> StdRng {
+    StdRng::seed_from_u64(seed)
+}
+
+// Generates an input for the given day with the given seed
+pub fn generate<G: Generator>(seed: u64, size: usize) -> String {
+    G::generate(&mut rng(seed), size)
+}
@@ -0,0 +1,214 @@
+use std::collections::HashMap;
+use std::ops::{Index, IndexMut};
+use crate::parse::{self, Line, ParseError};
+use crate::point::{Coordinate, Point};
+
+// A dense rectangular map stored row by row.
+// Can be indexed either with a point (grid[p]) or with a row and a column (grid[y][x]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid { width, height, cells: vec![fill; width * height] }
+    }
+
+    // Builds a grid from the lines of the input, shorter lines are padded with the fill value
+    pub fn parse(input: &str, fill: T, mut cell: impl FnMut(char) -> T) -> Self {
+        let lines: Vec<&str> = input.lines().collect();
+        let width = lines.iter().map(|line| line.chars().count()).max().unwrap_or(0);
+
+        let mut grid = Grid::new(width, lines.len(), fill);
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                grid[y][x] = cell(c);
+            }
+        }
+        grid
+    }
+
+    // Like parse, for maps made of a known set of characters: the closure returns None for
+    // the unexpected ones and the error points at the first of them
+    pub fn try_parse(input: &str, fill: T, expected: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, ParseError> {
+        let lines: Vec<Line> = parse::lines(input).collect();
+        let width = lines.iter().map(|line| line.text.chars().count()).max().unwrap_or(0);
+
+        let mut grid = Grid::new(width, lines.len(), fill);
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.text.chars().enumerate() {
+                grid[y][x] = cell(c).ok_or_else(|| line.error(x + 1, expected))?;
+            }
+        }
+        Ok(grid)
+    }
+
+    // Returns a copy of the grid with a border of the given width around it
+    pub fn with_border(&self, border: usize, fill: T) -> Self {
+        let mut grid = Grid::new(self.width + 2 * border, self.height + 2 * border, fill);
+        for (y, row) in self.rows().enumerate() {
+            grid[y + border][border..border + self.width].clone_from_slice(row);
+        }
+        grid
+    }
+
+    pub fn push_row(&mut self, fill: T) {
+        self.cells.resize(self.cells.len() + self.width, fill);
+        self.height += 1;
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point<usize>) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point<usize>) -> Option<&T> {
+        if self.contains(p) { Some(&self.cells[p.y * self.width + p.x]) } else { None }
+    }
+
+    pub fn get_mut(&mut self, p: Point<usize>) -> Option<&mut T> {
+        if self.contains(p) { Some(&mut self.cells[p.y * self.width + p.x]) } else { None }
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        self.cells.chunks_exact(self.width.max(1)).take(self.height)
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    // Finds the first cell (in reading order) matching the predicate
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point<usize>> {
+        let idx = self.cells.iter().position(predicate)?;
+        Some(Point::new(idx % self.width, idx / self.width))
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<usize>) -> &T {
+        self.get(p).unwrap_or_else(|| panic!("{:?} is outside of {}x{} grid", p, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p).unwrap_or_else(|| panic!("{:?} is outside of {}x{} grid", p, width, height))
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    fn index(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    fn index_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[y * self.width..(y + 1) * self.width]
+    }
+}
+
+// A map of unknown (or infinite) size where only the interesting cells are stored
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T, C = i64> {
+    cells: HashMap<Point<C>, T>,
+}
+
+impl<T, C: Coordinate> Default for SparseGrid<T, C> {
+    fn default() -> Self {
+        SparseGrid { cells: HashMap::new() }
+    }
+}
+
+impl<T, C: Coordinate> SparseGrid<T, C> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn insert(&mut self, p: Point<C>, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: &Point<C>) -> Option<T> {
+        self.cells.remove(p)
+    }
+
+    pub fn get(&self, p: &Point<C>) -> Option<&T> {
+        self.cells.get(p)
+    }
+
+    pub fn contains(&self, p: &Point<C>) -> bool {
+        self.cells.contains_key(p)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Point<C>, &T)> {
+        self.cells.iter()
+    }
+
+    // Top left and bottom right corners of the area with all the stored cells
+    pub fn bounding_box(&self) -> Option<(Point<C>, Point<C>)> {
+        Point::bounding_box(self.cells.keys())
+    }
+}
+
+impl<T, C: Coordinate> FromIterator<(Point<C>, T)> for SparseGrid<T, C> {
+    fn from_iter<I: IntoIterator<Item = (Point<C>, T)>>(iter: I) -> Self {
+        SparseGrid { cells: iter.into_iter().collect() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_index() {
+        let mut grid = Grid::parse("ab\nc", ' ', |c| c);
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+        assert_eq!(grid[Point::new(1, 0)], 'b');
+        assert_eq!(grid[1], ['c', ' ']);
+        assert_eq!(grid.get(Point::new(2, 0)), None);
+
+        grid[Point::new(1, 1)] = 'd';
+        assert_eq!(grid.position(|&c| c == 'd'), Some(Point::new(1, 1)));
+    }
+
+    #[test]
+    fn border() {
+        let grid = Grid::parse("ab", '.', |c| c).with_border(1, '~');
+        assert_eq!(grid.rows().map(|row| row.iter().collect::<String>()).collect::<Vec<_>>(), ["~~~~", "~ab~", "~~~~"]);
+    }
+
+    #[test]
+    fn sparse_bounding_box() {
+        let grid: SparseGrid<()> = [(Point::new(3, -2), ()), (Point::new(-1, 5), ())].into_iter().collect();
+        assert_eq!(grid.bounding_box(), Some((Point::new(-1, -2), Point::new(3, 5))));
+    }
+}
@@ -0,0 +1,73 @@
+use std::fs::read_to_string;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use anyhow::{Context, Result};
+use clap::Parser;
+use crate::log::Verbosity;
+use crate::output::Format;
+
+// Passing "-" instead of an input file reads the input from stdin
+pub const STDIN: &str = "-";
+
+// Every day keeps its inputs next to its Cargo.toml: demo-input.txt, real-input.txt
+// and sometimes extra ones (like mini-input.txt in day23 and day24).
+// An empty kind means the demo input from the puzzle description.
+pub fn input_file_name(kind: &str) -> String {
+    if kind.is_empty() {
+        "demo-input.txt".to_string()
+    } else {
+        format!("{}-input.txt", kind)
+    }
+}
+
+pub fn read_input(path: impl AsRef<Path>) -> Result<String> {
+    let path = path.as_ref();
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input).context("failed to read the input from stdin")?;
+        return Ok(input);
+    }
+    read_to_string(path).with_context(|| format!("failed to read the data file {}", path.display()))
+}
+
+// How to refer to the input file in the messages, stdin does not have a name of its own
+pub fn display_name(path: &Path) -> PathBuf {
+    if path == Path::new(STDIN) { PathBuf::from("<stdin>") } else { path.to_path_buf() }
+}
+
+// Command line of the day binaries
+#[derive(Parser)]
+pub struct DayArgs {
+    /// Input file to solve, - reads the input from stdin
+    #[arg(long, value_name = "PATH", conflicts_with = "kind")]
+    input: Option<PathBuf>,
+
+    /// Kind of the input kept next to the day's sources: real, mini, etc. (demo when omitted), - for stdin
+    kind: Option<String>,
+
+    /// How to print the answers
+    #[arg(long, value_enum, default_value_t)]
+    pub format: Format,
+
+    #[command(flatten)]
+    pub verbosity: Verbosity,
+}
+
+impl DayArgs {
+    fn input_file(&self) -> PathBuf {
+        match (&self.input, &self.kind) {
+            (Some(path), _) => path.clone(),
+            (None, Some(kind)) if kind == STDIN => PathBuf::from(STDIN),
+            (None, kind) => PathBuf::from(input_file_name(kind.as_deref().unwrap_or_default())),
+        }
+    }
+
+    // The input is either given with --input (or "-" for stdin), or selected by its kind
+    // ("real", "mini", etc), the demo input is used when both are missing
+    pub fn load(&self) -> Result<(PathBuf, String)> {
+        let input_file = self.input_file();
+        crate::info!("Using input file: {}", display_name(&input_file).display());
+        let input = read_input(&input_file)?;
+        Ok((display_name(&input_file), input))
+    }
+}
@@ -0,0 +1,105 @@
+use std::fmt;
+use std::path::Path;
+use std::time::Instant;
+use anyhow::{anyhow, Result};
+use clap::Parser;
+
+pub mod direction;
+pub mod generate;
+pub mod grid;
+pub mod input;
+pub mod log;
+pub mod output;
+pub mod parse;
+pub mod point;
+
+pub use direction::Direction;
+pub use grid::{Grid, SparseGrid};
+pub use parse::ParseError;
+pub use point::{Point, Point3};
+
+// Every day crate exposes a unit struct implementing this trait, which lets the `aoc`
+// runner parse the input once and then call both parts in the same way for all days.
+pub trait Solver {
+    const DAY: u8;
+    const PARTS: &'static [u8] = &[1, 2];
+
+    // Whatever the day needs to keep around after parsing the input
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+}
+
+// Most puzzles have a numeric answer, but a few (day5 crates, day10 screen, day25 SNAFU) are text
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(n) => write!(f, "{n}"),
+            Answer::Text(s) => write!(f, "{s}"),
+        }
+    }
+}
+
+macro_rules! answer_from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Number(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+answer_from_int!(i32, i64, u32, u64, u128, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Text(s.to_string())
+    }
+}
+
+// Used by the day binaries: loads the input selected on the command line and prints the answers
+pub fn run<S: Solver>() -> Result<()> {
+    let args = input::DayArgs::parse();
+    args.verbosity.init();
+    let (input_file, input) = args.load()?;
+    let parsed = parse_input::<S>(&input_file, &input)?;
+
+    for &part in S::PARTS {
+        let start = Instant::now();
+        let answer = solve_part::<S>(&parsed, part)?;
+        output::print_answer(args.format, S::DAY, part, &answer, start.elapsed());
+    }
+    Ok(())
+}
+
+// Parses the input read from the given file, printing a diagnostic if it does not parse
+pub fn parse_input<S: Solver>(input_file: &Path, input: &str) -> Result<S::Input> {
+    S::parse(input).map_err(|e| {
+        eprintln!("{}", parse::report(&parse::in_file(e, input_file)));
+        anyhow!("failed to parse {}", input_file.display())
+    })
+}
+
+pub fn solve_part<S: Solver>(parsed: &S::Input, part: u8) -> Result<Answer> {
+    match part {
+        1 => S::part1(parsed),
+        _ => S::part2(parsed),
+    }
+}
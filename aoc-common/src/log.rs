@@ -0,0 +1,91 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+use clap::{ArgAction, Args};
+
+// Everything logged goes to stderr, stdout is reserved for the answers.
+// The errors are always printed, they do not go through the log.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    // Only the answers (--quiet)
+    Quiet,
+    // What the runner is doing: which input it uses, etc.
+    Info,
+    // What the solvers have found along the way (-v)
+    Debug,
+    // Every step of the way: maps, intermediate states, search progress (-vv)
+    Trace,
+}
+
+static LEVEL: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+pub fn set_level(level: Level) {
+    LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(level: Level) -> bool {
+    LEVEL.load(Ordering::Relaxed) >= level as u8
+}
+
+// Shared by the day binaries and the runner
+#[derive(Args, Debug, Default)]
+pub struct Verbosity {
+    /// Only print the answers and the errors
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Print what the solvers are doing, -vv prints every step of the way
+    #[arg(short, long, global = true, action = ArgAction::Count)]
+    verbose: u8,
+}
+
+impl Verbosity {
+    pub fn level(&self) -> Level {
+        match (self.quiet, self.verbose) {
+            (true, _) => Level::Quiet,
+            (_, 0) => Level::Info,
+            (_, 1) => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    pub fn init(&self) {
+        set_level(self.level());
+    }
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)*) => {
+        if $crate::log::enabled($level) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Info, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Debug, $($arg)*) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => { $crate::log!($crate::log::Level::Trace, $($arg)*) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verbosity_levels() {
+        let level = |quiet, verbose| Verbosity { quiet, verbose }.level();
+        assert_eq!(level(true, 0), Level::Quiet);
+        assert_eq!(level(false, 0), Level::Info);
+        assert_eq!(level(false, 1), Level::Debug);
+        assert_eq!(level(false, 3), Level::Trace);
+    }
+}
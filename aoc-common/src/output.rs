@@ -0,0 +1,57 @@
+use std::time::Duration;
+use clap::ValueEnum;
+use serde::{Serialize, Serializer};
+use crate::Answer;
+
+// How the answers are printed to stdout, everything else goes to stderr
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+    // Human readable lines: Part 1: 24000
+    #[default]
+    Text,
+    // One JSON object per line: {"day":1,"part":1,"answer":24000,"elapsed_ms":0.012}
+    Json,
+}
+
+#[derive(Serialize)]
+struct AnswerRecord<'a> {
+    day: u8,
+    part: u8,
+    answer: &'a Answer,
+    elapsed_ms: f64,
+}
+
+// Numbers stay numbers in JSON, so that they do not need to be parsed back from strings
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Number(n) => serializer.serialize_i128(*n),
+            Answer::Text(s) => serializer.serialize_str(s),
+        }
+    }
+}
+
+pub fn json_record(day: u8, part: u8, answer: &Answer, elapsed: Duration) -> String {
+    let record = AnswerRecord { day, part, answer, elapsed_ms: elapsed.as_secs_f64() * 1000.0 };
+    serde_json::to_string(&record).expect("answers can always be serialized")
+}
+
+// Used by the day binaries, the runner prints the text answers on its own
+pub fn print_answer(format: Format, day: u8, part: u8, answer: &Answer, elapsed: Duration) {
+    match format {
+        Format::Text => println!("Part {}: {}", part, answer),
+        Format::Json => println!("{}", json_record(day, part, answer, elapsed)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn json_records() {
+        let elapsed = Duration::from_micros(1500);
+        assert_eq!(json_record(1, 2, &Answer::Number(45000), elapsed), r#"{"day":1,"part":2,"answer":45000,"elapsed_ms":1.5}"#);
+        assert_eq!(json_record(10, 2, &"#.\n.#".into(), elapsed), r##"{"day":10,"part":2,"answer":"#.\n.#","elapsed_ms":1.5}"##);
+    }
+}
@@ -0,0 +1,216 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+// Error returned by all the day parsers when the input does not look like what they expect.
+// Lines and columns are 1-based, like in editors. The file is unknown to the parsers (they only
+// see the input text), so it is filled in by whoever has read the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub file: Option<PathBuf>,
+    pub line: usize,
+    pub column: usize,
+    pub expected: String,
+    pub line_text: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, expected: impl Into<String>, line_text: &str) -> Self {
+        ParseError { file: None, line, column, expected: expected.into(), line_text: line_text.to_string() }
+    }
+
+    // Whether the error quotes a line of the input (or points right after the last one)
+    // and its column is on that line
+    pub fn points_into(&self, input: &str) -> bool {
+        let line_text = match input.lines().nth(self.line.wrapping_sub(1)) {
+            Some(text) => text,
+            None if self.line == input.lines().count() + 1 => "",
+            None => return false,
+        };
+        self.line_text == line_text && (1..=line_text.chars().count() + 1).contains(&self.column)
+    }
+
+    pub fn with_file(self, file: impl AsRef<Path>) -> Self {
+        ParseError { file: Some(file.as_ref().to_path_buf()), ..self }
+    }
+
+    // Multi-line report quoting the offending line, similar to the compiler errors:
+    //
+    //   error: expected a number
+    //    --> day1/demo-input.txt:3:1
+    //     |
+    //   3 | 30x0
+    //     | ^
+    pub fn diagnostic(&self) -> String {
+        let location = match &self.file {
+            Some(file) => format!("{}:{}:{}", file.display(), self.line, self.column),
+            None => format!("line {}, column {}", self.line, self.column),
+        };
+        let line_no = self.line.to_string();
+        let pad = " ".repeat(line_no.len());
+        let marker = " ".repeat(self.line_text.chars().take(self.column - 1).count());
+
+        format!(
+            "error: expected {}\n{pad}--> {}\n{pad} |\n{} | {}\n{pad} | {}^",
+            self.expected, location, line_no, self.line_text, marker, pad = pad
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        write!(f, "{}:{}: expected {}", self.line, self.column, self.expected)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Parsers do not know where their input came from, this fills the file in for the parse errors
+pub fn in_file(err: anyhow::Error, file: &Path) -> anyhow::Error {
+    match err.downcast::<ParseError>() {
+        Ok(parse_error) => parse_error.with_file(file).into(),
+        Err(err) => err,
+    }
+}
+
+// Report for the errors returned by the parsers, quoting the offending line of the parse errors
+pub fn report(err: &anyhow::Error) -> String {
+    match err.downcast_ref::<ParseError>() {
+        Some(parse_error) => parse_error.diagnostic(),
+        None => format!("error: {:#}", err),
+    }
+}
+
+// Used by the property tests of the parsers: a parser either succeeds or returns an error
+// pointing into the input it was given (other errors are not about the input itself)
+pub fn error_points_into<T>(result: &anyhow::Result<T>, input: &str) -> bool {
+    match result {
+        Ok(_) => true,
+        Err(e) => e.downcast_ref::<ParseError>().is_none_or(|e| e.points_into(input)),
+    }
+}
+
+// A single line of the input that knows its position, so that errors can point at it
+#[derive(Debug, Clone, Copy)]
+pub struct Line<'a> {
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    // Error pointing at the given (1-based) column
+    pub fn error(&self, column: usize, expected: impl Into<String>) -> ParseError {
+        ParseError::new(self.number, column, expected, self.text)
+    }
+
+    // Error pointing at the start of a part of the line (like a token returned by split)
+    pub fn error_at(&self, part: &str, expected: impl Into<String>) -> ParseError {
+        self.error(self.column_of(part), expected)
+    }
+
+    // Error pointing right after the end of the line, when something is missing there
+    pub fn error_at_end(&self, expected: impl Into<String>) -> ParseError {
+        self.error(self.text.chars().count() + 1, expected)
+    }
+
+    // Parses a part of the line, pointing the error at it if it does not parse
+    pub fn parse<T: FromStr>(&self, part: &str, expected: impl Into<String>) -> Result<T, ParseError> {
+        part.trim().parse().map_err(|_| self.error_at(part, expected))
+    }
+
+    // Like parse, but for optional parts (missing parts are reported at the end of the line)
+    pub fn parse_some<T: FromStr>(&self, part: Option<&str>, expected: impl Into<String>) -> Result<T, ParseError> {
+        match part {
+            Some(part) => self.parse(part, expected),
+            None => Err(self.error_at_end(expected)),
+        }
+    }
+
+    // Strips a fixed prefix from the line (ignoring the indentation), returning the rest of it
+    pub fn strip_prefix(&self, prefix: &str) -> Result<&'a str, ParseError> {
+        let text = self.text.trim_start();
+        text.strip_prefix(prefix).ok_or_else(|| self.error_at(text, format!("\"{}\"", prefix.trim_end())))
+    }
+
+    // Column of a part of the line, parts that do not come from the line point to the start of it
+    fn column_of(&self, part: &str) -> usize {
+        let start = self.text.as_ptr() as usize;
+        let offset = (part.as_ptr() as usize).wrapping_sub(start);
+        if offset <= self.text.len() && self.text.is_char_boundary(offset) {
+            self.text[..offset].chars().count() + 1
+        } else {
+            1
+        }
+    }
+}
+
+// Iterates over the lines of the input together with their line numbers
+pub struct Lines<'a> {
+    lines: std::str::Lines<'a>,
+    number: usize,
+}
+
+impl<'a> Lines<'a> {
+    // For inputs made of records spanning multiple lines: a missing line is an error
+    pub fn next_line(&mut self, expected: impl Into<String>) -> Result<Line<'a>, ParseError> {
+        let number = self.number + 1;
+        self.next().ok_or_else(|| ParseError::new(number, 1, expected, ""))
+    }
+}
+
+impl<'a> Iterator for Lines<'a> {
+    type Item = Line<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let text = self.lines.next()?;
+        self.number += 1;
+        Some(Line { number: self.number, text })
+    }
+}
+
+pub fn lines(input: &str) -> Lines<'_> {
+    Lines { lines: input.lines(), number: 0 }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_tokens() {
+        let line = lines("1,2\n3,x4").nth(1).unwrap();
+        let token = line.text.split(',').nth(1).unwrap();
+        let err = line.parse::<u32>(token, "a number").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.to_string(), "2:3: expected a number");
+        assert_eq!(line.error_at_end("a comma").column, 5);
+    }
+
+    #[test]
+    fn reports_missing_lines() {
+        let mut lines = lines("  Test: 1\n");
+        let line = lines.next_line("a test").unwrap();
+        assert_eq!(line.strip_prefix("Test: "), Ok("1"));
+        assert_eq!(line.strip_prefix("If true: ").unwrap_err().column, 3);
+        assert_eq!(lines.next_line("a condition").unwrap_err().line, 2);
+    }
+
+    #[test]
+    fn checks_errors_point_into_the_input() {
+        let input = "ab\ncd";
+        assert!(ParseError::new(2, 3, "e", "cd").points_into(input));
+        assert!(ParseError::new(3, 1, "a line", "").points_into(input));
+        assert!(!ParseError::new(2, 4, "e", "cd").points_into(input));
+        assert!(!ParseError::new(1, 1, "e", "cd").points_into(input));
+        assert!(!ParseError::new(0, 1, "e", "").points_into(input));
+    }
+
+    #[test]
+    fn diagnostic_quotes_the_line() {
+        let err = ParseError::new(12, 3, "a digit", "ab?d").with_file("input.txt");
+        assert_eq!(err.diagnostic(), "error: expected a digit\n  --> input.txt:12:3\n   |\n12 | ab?d\n   |   ^");
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::{Add, Sub};
+use crate::Direction;
+
+// Numeric types that can be used as point coordinates
+pub trait Coordinate: Copy + Debug + Ord + Hash + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+}
+
+macro_rules! coordinate {
+    ($($t:ty),*) => {
+        $(
+            impl Coordinate for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+            }
+        )*
+    };
+}
+
+coordinate!(i32, i64, isize, u32, u64, usize);
+
+// Distance between two coordinates that works for unsigned types as well
+fn abs_diff<T: Coordinate>(a: T, b: T) -> T {
+    if a > b { a - b } else { b - a }
+}
+
+// A point on a 2D map. Like in all the puzzles, y grows downwards, so "up" means y - 1.
+// Stepping past zero with unsigned coordinates panics, so maps indexed with usize points
+// should be surrounded with a border the walkers never step over.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    pub fn manhattan_distance(&self, other: &Point<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y)
+    }
+
+    pub fn step(&self, dir: Direction) -> Point<T> {
+        match dir {
+            Direction::Up => Point::new(self.x, self.y - T::ONE),
+            Direction::Right => Point::new(self.x + T::ONE, self.y),
+            Direction::Down => Point::new(self.x, self.y + T::ONE),
+            Direction::Left => Point::new(self.x - T::ONE, self.y),
+        }
+    }
+
+    // Orthogonal neighbors in the Direction::ALL order
+    pub fn neighbors(&self) -> [Point<T>; 4] {
+        Direction::ALL.map(|dir| self.step(dir))
+    }
+
+    // Orthogonal and diagonal neighbors, clockwise starting from the top left one
+    pub fn neighbors_with_diagonals(&self) -> [Point<T>; 8] {
+        let up = self.step(Direction::Up);
+        let down = self.step(Direction::Down);
+        [
+            up.step(Direction::Left), up, up.step(Direction::Right),
+            self.step(Direction::Right),
+            down.step(Direction::Right), down, down.step(Direction::Left),
+            self.step(Direction::Left),
+        ]
+    }
+
+    // Returns the top left and bottom right corners of the smallest box containing all the points
+    pub fn bounding_box<'a>(points: impl IntoIterator<Item = &'a Point<T>>) -> Option<(Point<T>, Point<T>)> where T: 'a {
+        points.into_iter().fold(None, |bbox, p| match bbox {
+            None => Some((*p, *p)),
+            Some((min, max)) => Some((
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )),
+        })
+    }
+}
+
+impl<T: Coordinate> Add for Point<T> {
+    type Output = Point<T>;
+
+    fn add(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coordinate> Sub for Point<T> {
+    type Output = Point<T>;
+
+    fn sub(self, other: Point<T>) -> Point<T> {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan_distance(&self, other: &Point3<T>) -> T {
+        abs_diff(self.x, other.x) + abs_diff(self.y, other.y) + abs_diff(self.z, other.z)
+    }
+
+    // Points sharing a side with this one
+    pub fn neighbors(&self) -> [Point3<T>; 6] {
+        let (x, y, z) = (self.x, self.y, self.z);
+        [
+            Point3::new(x - T::ONE, y, z),
+            Point3::new(x + T::ONE, y, z),
+            Point3::new(x, y - T::ONE, z),
+            Point3::new(x, y + T::ONE, z),
+            Point3::new(x, y, z - T::ONE),
+            Point3::new(x, y, z + T::ONE),
+        ]
+    }
+}
+
+impl<T: Coordinate> Add for Point3<T> {
+    type Output = Point3<T>;
+
+    fn add(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Coordinate> Sub for Point3<T> {
+    type Output = Point3<T>;
+
+    fn sub(self, other: Point3<T>) -> Point3<T> {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn manhattan_distance() {
+        assert_eq!(Point::new(1, 2).manhattan_distance(&Point::new(-3, 5)), 7);
+        assert_eq!(Point::<usize>::new(4, 0).manhattan_distance(&Point::new(1, 3)), 6);
+        assert_eq!(Point3::new(1, 1, 1).manhattan_distance(&Point3::new(2, 0, 3)), 4);
+    }
+
+    #[test]
+    fn steps_and_neighbors() {
+        let p = Point::new(5, 5);
+        assert_eq!(p.step(Direction::Up), Point::new(5, 4));
+        assert_eq!(p.step(Direction::Right), Point::new(6, 5));
+        assert_eq!(p.step(Direction::Down), Point::new(5, 6));
+        assert_eq!(p.step(Direction::Left), Point::new(4, 5));
+        assert_eq!(p.neighbors(), [Point::new(5, 4), Point::new(6, 5), Point::new(5, 6), Point::new(4, 5)]);
+        assert_eq!(p.neighbors_with_diagonals()[0], Point::new(4, 4));
+        assert_eq!(p.neighbors_with_diagonals()[4], Point::new(6, 6));
+    }
+
+    #[test]
+    fn bounding_box() {
+        let points = [Point::new(2, -1), Point::new(-3, 4), Point::new(0, 0)];
+        assert_eq!(Point::bounding_box(&points), Some((Point::new(-3, -1), Point::new(2, 4))));
+        assert_eq!(Point::<i32>::bounding_box(&[]), None);
+    }
+}
@@ -0,0 +1,95 @@
+use std::fs::read_to_string;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use crate::days::day_dir;
+
+pub const ANSWERS_FILE: &str = "answers.toml";
+
+// Known answers of a day, kept in dayN/answers.toml:
+//
+//   [demo]
+//   part1 = 24000
+//   part2 = "MCD"
+//
+//   [real]
+//   part2 = { answer = 2111, known_issue = "why the solver does not get it right yet" }
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Answers {
+    #[serde(default)]
+    pub demo: InputAnswers,
+    #[serde(default)]
+    pub real: InputAnswers,
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InputAnswers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum AnswerValue {
+    Number(i64),
+    Text(String),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Expected {
+    Answer(AnswerValue),
+    KnownIssue { answer: AnswerValue, known_issue: String },
+}
+
+impl Answers {
+    // A day without the answers file simply has no known answers
+    pub fn load(day: u8) -> Result<Answers> {
+        let path = day_dir(day).join(ANSWERS_FILE);
+        if !path.exists() {
+            return Ok(Answers::default());
+        }
+
+        let data = read_to_string(&path).with_context(|| format!("failed to read {}", path.display()))?;
+        toml::from_str(&data).with_context(|| format!("failed to parse {}", path.display()))
+    }
+
+    pub fn for_input(&self, kind: &str) -> &InputAnswers {
+        if kind == "real" { &self.real } else { &self.demo }
+    }
+}
+
+impl InputAnswers {
+    pub fn part(&self, part: u8) -> Option<&Expected> {
+        match part {
+            1 => self.part1.as_ref(),
+            _ => self.part2.as_ref(),
+        }
+    }
+}
+
+impl Expected {
+    pub fn answer(&self) -> String {
+        let value = match self {
+            Expected::Answer(value) => value,
+            Expected::KnownIssue { answer, .. } => answer,
+        };
+        match value {
+            AnswerValue::Number(n) => n.to_string(),
+            AnswerValue::Text(s) => s.clone(),
+        }
+    }
+
+    pub fn known_issue(&self) -> Option<&str> {
+        match self {
+            Expected::Answer(_) => None,
+            Expected::KnownIssue { known_issue, .. } => Some(known_issue),
+        }
+    }
+
+    // Multi-line answers (like the day10 screen) are compared without the trailing whitespace
+    pub fn matches(&self, actual: &str) -> bool {
+        self.answer().trim_end() == actual.trim_end()
+    }
+}
@@ -0,0 +1,184 @@
+use std::fs::File;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::time::Duration;
+use anyhow::{Context, Result};
+use aoc_common::input;
+use clap::Args;
+use serde::Serialize;
+use crate::days::{self, LAST_DAY};
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Day to benchmark (all days are benchmarked when omitted)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Use the day's real-input.txt instead of the demo input
+    #[arg(long)]
+    real: bool,
+
+    /// How many times to parse the input and solve each part
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    repeat: u32,
+
+    /// Write the report as JSON to the given file instead of printing a table, - prints it to stdout
+    #[arg(long, value_name = "PATH")]
+    json: Option<PathBuf>,
+}
+
+#[derive(Serialize)]
+struct Report {
+    input: &'static str,
+    repeat: u32,
+    // Where the benchmark was running, to be able to compare the results between machines
+    os: &'static str,
+    arch: &'static str,
+    cpus: usize,
+    days: Vec<DayReport>,
+}
+
+#[derive(Serialize)]
+struct DayReport {
+    day: u8,
+    stages: Vec<StageReport>,
+}
+
+#[derive(Serialize)]
+struct StageReport {
+    stage: String,
+    runs: usize,
+    min_ms: f64,
+    median_ms: f64,
+    max_ms: f64,
+}
+
+impl StageReport {
+    fn new(stage: String, mut timings: Vec<Duration>) -> Self {
+        timings.sort();
+        let middle = timings.len() / 2;
+        let median = if timings.len().is_multiple_of(2) {
+            (timings[middle - 1] + timings[middle]) / 2
+        } else {
+            timings[middle]
+        };
+
+        StageReport {
+            stage,
+            runs: timings.len(),
+            min_ms: as_ms(timings[0]),
+            median_ms: as_ms(median),
+            max_ms: as_ms(timings[timings.len() - 1]),
+        }
+    }
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+pub fn bench_days(args: &BenchArgs) -> Result<ExitCode> {
+    let days = match args.day {
+        Some(day) => day..=day,
+        None => 1..=LAST_DAY,
+    };
+    let kind = if args.real { "real" } else { "demo" };
+
+    let mut failed = false;
+    let mut report = Report {
+        input: kind,
+        repeat: args.repeat,
+        os: std::env::consts::OS,
+        arch: std::env::consts::ARCH,
+        cpus: std::thread::available_parallelism().map_or(1, |n| n.get()),
+        days: Vec::new(),
+    };
+    for day in days {
+        let input_file = days::input_file(day, if args.real { "real" } else { "" });
+        if !input_file.exists() {
+            eprintln!("Day {}: skipped, no {}", day, input_file.display());
+            continue;
+        }
+
+        let input = input::read_input(&input_file)?;
+        let (day_report, day_failed) = bench_day(day, &input, args.repeat);
+        failed |= day_failed;
+        report.days.push(day_report);
+    }
+
+    // The table would only get in the way of the JSON printed to stdout
+    match &args.json {
+        Some(path) => write_json(&report, path)?,
+        None => print_report(&report),
+    }
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+// Returns the timings of all the stages that succeeded and whether any of them failed
+fn bench_day(day: u8, input: &str, repeat: u32) -> (DayReport, bool) {
+    let mut parts = days::parts_of(day).to_vec();
+    let mut failed = false;
+    let mut parse_timings = Vec::new();
+    let mut part_timings: Vec<Vec<Duration>> = vec![Vec::new(); parts.len()];
+
+    for _ in 0..repeat {
+        let solution = match days::solve_day(day, input, &parts) {
+            Ok(solution) => solution,
+            Err(e) => {
+                eprintln!("Day {}: failed to parse the input: {:#}", day, e);
+                return (DayReport { day, stages: Vec::new() }, true);
+            }
+        };
+        parse_timings.push(solution.parse_time);
+
+        // Parts that fail once are not worth solving again
+        for result in solution.parts.iter() {
+            match &result.answer {
+                Ok(_) => part_timings[result.part as usize - 1].push(result.elapsed),
+                Err(e) => {
+                    eprintln!("Day {} part {} failed: {:#}", day, result.part, e);
+                    failed = true;
+                    parts.retain(|&part| part != result.part);
+                }
+            }
+        }
+    }
+
+    let mut stages = vec![StageReport::new("parse".to_string(), parse_timings)];
+    for (i, timings) in part_timings.into_iter().enumerate() {
+        if !timings.is_empty() {
+            stages.push(StageReport::new(format!("part{}", i + 1), timings));
+        }
+    }
+    (DayReport { day, stages }, failed)
+}
+
+fn print_report(report: &Report) {
+    println!("Benchmark of the {} inputs, {} runs each:", report.input, report.repeat);
+    println!("{:>3}  {:<6} {:>12} {:>12} {:>12}", "Day", "Stage", "Min", "Median", "Max");
+    for day in report.days.iter() {
+        for stage in day.stages.iter() {
+            println!(
+                "{:>3}  {:<6} {:>12} {:>12} {:>12}",
+                day.day,
+                stage.stage,
+                format!("{:.3}ms", stage.min_ms),
+                format!("{:.3}ms", stage.median_ms),
+                format!("{:.3}ms", stage.max_ms),
+            );
+        }
+    }
+}
+
+fn write_json(report: &Report, path: &Path) -> Result<()> {
+    let json = serde_json::to_string_pretty(report)?;
+    if path.as_os_str() == input::STDIN {
+        println!("{}", json);
+        return Ok(());
+    }
+
+    let mut file = File::create(path).with_context(|| format!("failed to create {}", path.display()))?;
+    writeln!(file, "{}", json).with_context(|| format!("failed to write {}", path.display()))?;
+    Ok(())
+}
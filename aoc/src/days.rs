@@ -0,0 +1,153 @@
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use anyhow::{anyhow, Result};
+use aoc_common::generate::{generate, Generator};
+use aoc_common::{input, solve_part, Answer, Solver};
+
+// Day 25 only has a single puzzle, the second star is given for completing all the others
+pub const LAST_DAY: u8 = 25;
+
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Result<Answer>,
+    pub elapsed: Duration,
+}
+
+pub struct Solution {
+    pub parse_time: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+// Parses the input and solves the requested parts of a single day
+type SolveFn = fn(&str, &[u8]) -> Result<Solution>;
+
+const DAYS: [SolveFn; 25] = [
+    solve::<day1::Day1>, solve::<day2::Day2>, solve::<day3::Day3>, solve::<day4::Day4>, solve::<day5::Day5>,
+    solve::<day6::Day6>, solve::<day7::Day7>, solve::<day8::Day8>, solve::<day9::Day9>, solve::<day10::Day10>,
+    solve::<day11::Day11>, solve::<day12::Day12>, solve::<day13::Day13>, solve::<day14::Day14>, solve::<day15::Day15>,
+    solve::<day16::Day16>, solve::<day17::Day17>, solve::<day18::Day18>, solve::<day19::Day19>, solve::<day20::Day20>,
+    solve::<day21::Day21>, solve::<day22::Day22>, solve::<day23::Day23>, solve::<day24::Day24>, solve::<day25::Day25>,
+];
+
+pub fn solve_day(day: u8, input: &str, parts: &[u8]) -> Result<Solution> {
+    DAYS[day as usize - 1](input, parts)
+}
+
+// Generates a random input of the given size (or of about the real input size) from a seed
+type GenerateFn = fn(u64, Option<usize>) -> String;
+
+const GENERATORS: [GenerateFn; 25] = [
+    generate_input::<day1::Day1>, generate_input::<day2::Day2>, generate_input::<day3::Day3>,
+    generate_input::<day4::Day4>, generate_input::<day5::Day5>, generate_input::<day6::Day6>,
+    generate_input::<day7::Day7>, generate_input::<day8::Day8>, generate_input::<day9::Day9>,
+    generate_input::<day10::Day10>, generate_input::<day11::Day11>, generate_input::<day12::Day12>,
+    generate_input::<day13::Day13>, generate_input::<day14::Day14>, generate_input::<day15::Day15>,
+    generate_input::<day16::Day16>, generate_input::<day17::Day17>, generate_input::<day18::Day18>,
+    generate_input::<day19::Day19>, generate_input::<day20::Day20>, generate_input::<day21::Day21>,
+    generate_input::<day22::Day22>, generate_input::<day23::Day23>, generate_input::<day24::Day24>,
+    generate_input::<day25::Day25>,
+];
+
+pub fn generate_day(day: u8, seed: u64, size: Option<usize>) -> String {
+    GENERATORS[day as usize - 1](seed, size)
+}
+
+fn generate_input<G: Generator>(seed: u64, size: Option<usize>) -> String {
+    generate::<G>(seed, size.unwrap_or(G::DEFAULT_SIZE))
+}
+
+// The brute-force answer of a part, None when the day has no reference for it
+type ReferenceFn = fn(&str, u8) -> Option<Answer>;
+
+const REFERENCES: [ReferenceFn; 25] = [
+    reference::<day1::Day1>, reference::<day2::Day2>, reference::<day3::Day3>,
+    reference::<day4::Day4>, reference::<day5::Day5>, reference::<day6::Day6>,
+    reference::<day7::Day7>, reference::<day8::Day8>, reference::<day9::Day9>,
+    reference::<day10::Day10>, reference::<day11::Day11>, reference::<day12::Day12>,
+    reference::<day13::Day13>, reference::<day14::Day14>, reference::<day15::Day15>,
+    reference::<day16::Day16>, reference::<day17::Day17>, reference::<day18::Day18>,
+    reference::<day19::Day19>, reference::<day20::Day20>, reference::<day21::Day21>,
+    reference::<day22::Day22>, reference::<day23::Day23>, reference::<day24::Day24>,
+    reference::<day25::Day25>,
+];
+
+pub fn reference_answer(day: u8, input: &str, part: u8) -> Option<Answer> {
+    REFERENCES[day as usize - 1](input, part)
+}
+
+fn reference<G: Generator>(input: &str, part: u8) -> Option<Answer> {
+    G::reference(&G::parse(input).ok()?, part)
+}
+
+pub fn parts_of(day: u8) -> &'static [u8] {
+    if day == LAST_DAY { &[1] } else { &[1, 2] }
+}
+
+// Inputs and answers live next to each day's sources, so they can be found from any working directory
+pub fn day_dir(day: u8) -> PathBuf {
+    let workspace_dir = Path::new(env!("CARGO_MANIFEST_DIR")).parent().expect("aoc crate is in a workspace");
+    workspace_dir.join(format!("day{}", day))
+}
+
+pub fn input_file(day: u8, kind: &str) -> PathBuf {
+    day_dir(day).join(input::input_file_name(kind))
+}
+
+fn solve<S: Solver>(input: &str, parts: &[u8]) -> Result<Solution> {
+    let start = Instant::now();
+    let parsed = catch_panic(|| S::parse(input))?;
+    let parse_time = start.elapsed();
+
+    let parts = parts.iter().map(|&part| {
+        let start = Instant::now();
+        let answer = catch_panic(|| solve_part::<S>(&parsed, part));
+        PartResult { part, answer, elapsed: start.elapsed() }
+    }).collect();
+
+    Ok(Solution { parse_time, parts })
+}
+
+thread_local! {
+    // Where the solver caught by catch_panic on this thread panicked, None while no solver runs
+    static CAUGHT_PANIC: RefCell<Option<Option<String>>> = const { RefCell::new(None) };
+}
+
+// Some solvers still panic on inputs they do not support (like the demo cube in day22),
+// that should not stop us from running all the other days. The default hook would print the
+// panic with a backtrace in the middle of the report, so while catch_panic runs a solver the hook
+// only remembers where the panic happened, for the error. Any other panic is reported as usual.
+// Installed once at startup, as swapping the process-wide hook around every solver would race
+// with solvers running on other threads.
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        let caught = CAUGHT_PANIC.with_borrow_mut(|caught| match caught {
+            Some(location) => {
+                *location = info.location().map(|l| l.to_string());
+                true
+            },
+            None => false,
+        });
+        if !caught {
+            default_hook(info);
+        }
+    }));
+}
+
+fn catch_panic<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+    CAUGHT_PANIC.set(Some(None));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    let location = CAUGHT_PANIC.take().flatten();
+
+    result.unwrap_or_else(|payload| {
+        let message = payload.downcast_ref::<&str>().map(|s| s.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown reason".to_string());
+        match location {
+            Some(location) => Err(anyhow!("solver panicked at {}: {}", location, message)),
+            None => Err(anyhow!("solver panicked: {}", message)),
+        }
+    })
+}
@@ -0,0 +1,145 @@
+use std::{path::PathBuf, process::ExitCode, time::Duration};
+use anyhow::{bail, Result};
+use aoc_common::log::Verbosity;
+use aoc_common::output::{self, Format};
+use aoc_common::{info, input, parse};
+use clap::{Args, Parser, Subcommand};
+use days::{PartResult, LAST_DAY};
+
+mod answers;
+mod bench;
+mod days;
+mod stress;
+mod verify;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2022 solutions runner")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    #[command(flatten)]
+    verbosity: Verbosity,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one or all of the days
+    Run(RunArgs),
+    /// Check the answers of one or all of the days against their answers.toml
+    Verify(verify::VerifyArgs),
+    /// Measure how long parsing and solving both parts takes for one or all of the days
+    Bench(bench::BenchArgs),
+    /// Print a random input for a day
+    Generate(stress::GenerateArgs),
+    /// Solve random inputs of one or all of the days and check the answers against the brute-force
+    /// references of the days that have one, reporting the seeds of the inputs that fail
+    Stress(stress::StressArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to solve (all days are solved when omitted)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Part to solve (both parts are solved when omitted)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Input file (defaults to the day's demo-input.txt), - reads the input from stdin
+    #[arg(long, conflicts_with = "real", requires = "day")]
+    input: Option<PathBuf>,
+
+    /// Use the day's real-input.txt instead of the demo input
+    #[arg(long)]
+    real: bool,
+
+    /// How to print the answers (the progress is logged to stderr)
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+}
+
+fn main() -> Result<ExitCode> {
+    let cli = Cli::parse();
+    cli.verbosity.init();
+    days::install_panic_hook();
+    match cli.command {
+        Command::Run(args) => run_days(&args),
+        Command::Verify(args) => verify::verify_days(&args),
+        Command::Bench(args) => bench::bench_days(&args),
+        Command::Generate(args) => stress::generate_input(&args),
+        Command::Stress(args) => stress::stress_days(&args),
+    }
+}
+
+fn run_days(args: &RunArgs) -> Result<ExitCode> {
+    let days = match args.day {
+        Some(day) => day..=day,
+        None => 1..=LAST_DAY,
+    };
+
+    let mut failed = false;
+    for day in days {
+        let input_file = match &args.input {
+            Some(path) => path.clone(),
+            None => days::input_file(day, if args.real { "real" } else { "" }),
+        };
+        let file_name = input::display_name(&input_file);
+        info!("=== Day {} ({}) ===", day, file_name.display());
+
+        let input = input::read_input(&input_file)?;
+        let solution = match days::solve_day(day, &input, &parts_to_run(day, args.part)?) {
+            Ok(solution) => solution,
+            Err(e) => {
+                eprintln!("{}", parse::report(&parse::in_file(e, &file_name)));
+                failed = true;
+                continue;
+            }
+        };
+        info!("Parsed input in {}", format_duration(solution.parse_time));
+
+        for result in solution.parts.iter() {
+            failed |= !print_part(args.format, day, result);
+        }
+    }
+
+    Ok(if failed { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+// When running all the parts, skip the missing second part of the last day instead of failing
+fn parts_to_run(day: u8, part: Option<u8>) -> Result<Vec<u8>> {
+    match part {
+        Some(2) if day == LAST_DAY => bail!("day {} has no second part", LAST_DAY),
+        Some(part) => Ok(vec![part]),
+        None => Ok(days::parts_of(day).to_vec()),
+    }
+}
+
+// Returns false if the part could not be solved
+fn print_part(format: Format, day: u8, result: &PartResult) -> bool {
+    let elapsed = format_duration(result.elapsed);
+    let answer = match &result.answer {
+        Ok(answer) if format == Format::Json => {
+            println!("{}", output::json_record(day, result.part, answer, result.elapsed));
+            return true;
+        },
+        Ok(answer) => answer.to_string(),
+        Err(e) => {
+            eprintln!("Part {} failed after {}: {:#}", result.part, elapsed, e);
+            return false;
+        }
+    };
+
+    // Multi-line answers (like the day10 screen) are easier to read starting on a new line
+    if answer.contains('\n') {
+        println!("Part {} ({}):\n{}", result.part, elapsed, answer);
+    } else {
+        println!("Part {}: {} ({})", result.part, answer, elapsed);
+    }
+    true
+}
+
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
@@ -0,0 +1,86 @@
+use std::process::ExitCode;
+use anyhow::Result;
+use clap::Args;
+use crate::days::{self, LAST_DAY};
+
+#[derive(Args)]
+pub struct GenerateArgs {
+    /// Day to generate an input for
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Size of the input (its meaning depends on the day, defaults to about the real input size)
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Seed for the random generator, the same seed always produces the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+#[derive(Args)]
+pub struct StressArgs {
+    /// Day to stress-test (all days are tested when omitted)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// How many random inputs to solve for each day
+    #[arg(long, default_value_t = 10)]
+    runs: u64,
+
+    /// Size of the inputs (its meaning depends on the day, defaults to about the real input size)
+    #[arg(long)]
+    size: Option<usize>,
+
+    /// Seed of the first input, the following ones use the next seeds
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+}
+
+pub fn generate_input(args: &GenerateArgs) -> Result<ExitCode> {
+    print!("{}", days::generate_day(args.day, args.seed, args.size));
+    Ok(ExitCode::SUCCESS)
+}
+
+pub fn stress_days(args: &StressArgs) -> Result<ExitCode> {
+    let days = match args.day {
+        Some(day) => day..=day,
+        None => 1..=LAST_DAY,
+    };
+
+    let mut failures = 0;
+    for day in days {
+        let mut solved = 0;
+        for seed in args.seed..args.seed + args.runs {
+            let input = days::generate_day(day, seed, args.size);
+            let errors = match days::solve_day(day, &input, days::parts_of(day)) {
+                Ok(solution) => solution.parts.into_iter()
+                    .filter_map(|result| match result.answer {
+                        Err(e) => Some(format!("part {}: {:#}", result.part, e)),
+                        Ok(answer) => match days::reference_answer(day, &input, result.part) {
+                            Some(expected) if expected != answer => {
+                                Some(format!("part {}: answered {} but the reference says {}", result.part, answer, expected))
+                            },
+                            _ => None,
+                        },
+                    })
+                    .collect(),
+                Err(e) => vec![format!("parse: {:#}", e)],
+            };
+
+            // Every failure can be reproduced from the printed seed
+            for error in errors.iter() {
+                println!("Day {:>2} seed {}: {}", day, seed, error);
+            }
+            failures += errors.len();
+            solved += errors.is_empty() as u64;
+        }
+        println!("Day {:>2}: {} of {} inputs solved", day, solved, args.runs);
+    }
+
+    if failures > 0 {
+        let size = args.size.map(|size| format!(" --size {}", size)).unwrap_or_default();
+        println!("\n{} failures, reproduce with: aoc generate --day <DAY> --seed <SEED>{} | aoc run --day <DAY> --input -", failures, size);
+    }
+    Ok(if failures > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
@@ -0,0 +1,143 @@
+use std::process::ExitCode;
+use anyhow::Result;
+use aoc_common::{input, parse};
+use clap::Args;
+use crate::answers::{Answers, Expected};
+use crate::days::{self, LAST_DAY};
+
+#[derive(Args)]
+pub struct VerifyArgs {
+    /// Day to verify (all days are verified when omitted)
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Only verify the demo inputs
+    #[arg(long, conflicts_with = "real")]
+    demo: bool,
+
+    /// Only verify the real inputs
+    #[arg(long)]
+    real: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Status {
+    // The solver produced the expected answer
+    Pass,
+    // The solver is known to get this one wrong (see known_issue in answers.toml)
+    Fail,
+    // The solver used to produce the expected answer, but does not anymore
+    Regression,
+    // There is no input file or no known answer to compare with
+    Skip,
+}
+
+#[derive(Default)]
+struct Summary {
+    passed: usize,
+    failed: usize,
+    regressions: usize,
+    skipped: usize,
+}
+
+impl Summary {
+    fn add(&mut self, status: &Status) {
+        match status {
+            Status::Pass => self.passed += 1,
+            Status::Fail => self.failed += 1,
+            Status::Regression => self.regressions += 1,
+            Status::Skip => self.skipped += 1,
+        }
+    }
+}
+
+pub fn verify_days(args: &VerifyArgs) -> Result<ExitCode> {
+    let days = match args.day {
+        Some(day) => day..=day,
+        None => 1..=LAST_DAY,
+    };
+    let kinds: &[&str] = match (args.demo, args.real) {
+        (true, _) => &["demo"],
+        (_, true) => &["real"],
+        _ => &["demo", "real"],
+    };
+
+    let mut summary = Summary::default();
+    for day in days {
+        let answers = Answers::load(day)?;
+        for &kind in kinds {
+            for (part, status, details) in verify_input(day, kind, &answers)? {
+                println!("Day {:>2} {} part {}: {:?}{}", day, kind, part, status, details);
+                summary.add(&status);
+            }
+        }
+    }
+
+    println!(
+        "\n{} passed, {} failed, {} regressions, {} skipped",
+        summary.passed, summary.failed, summary.regressions, summary.skipped
+    );
+    Ok(if summary.regressions > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS })
+}
+
+// Solves all the parts of a day for the given kind of input and compares them with the known answers
+fn verify_input(day: u8, kind: &str, answers: &Answers) -> Result<Vec<(u8, Status, String)>> {
+    let parts = days::parts_of(day);
+    let input_answers = answers.for_input(kind);
+
+    let input_file = days::input_file(day, if kind == "demo" { "" } else { kind });
+    if !input_file.exists() {
+        let details = format!(" (no {})", input_file.display());
+        return Ok(parts.iter().map(|&part| (part, Status::Skip, details.clone())).collect());
+    }
+
+    // Only solve the parts we know the answers to, some of them are rather slow
+    let known_parts: Vec<u8> = parts.iter().copied().filter(|&part| input_answers.part(part).is_some()).collect();
+    let mut results: Vec<(u8, Status, String)> = parts.iter()
+        .filter(|part| !known_parts.contains(part))
+        .map(|&part| (part, Status::Skip, " (no known answer)".to_string()))
+        .collect();
+    if known_parts.is_empty() {
+        return Ok(results);
+    }
+
+    let input = input::read_input(&input_file)?;
+    let solution = days::solve_day(day, &input, &known_parts).map_err(|e| parse::in_file(e, &input_file));
+    for &part in known_parts.iter() {
+        let expected = input_answers.part(part).expect("only known parts are solved");
+        let actual = match &solution {
+            Ok(solution) => {
+                let result = solution.parts.iter().find(|r| r.part == part).expect("all requested parts are solved");
+                result.answer.as_ref().map(|answer| answer.to_string()).map_err(|e| format!("{:#}", e))
+            },
+            Err(e) => Err(format!("{:#}", e)),
+        };
+        let (status, details) = compare(expected, actual);
+        results.push((part, status, details));
+    }
+
+    results.sort_by_key(|(part, _, _)| *part);
+    Ok(results)
+}
+
+fn compare(expected: &Expected, actual: Result<String, String>) -> (Status, String) {
+    let problem = match actual {
+        Ok(actual) if expected.matches(&actual) => return (Status::Pass, String::new()),
+        Ok(actual) => format!("expected {}, got {}", one_line(&expected.answer()), one_line(&actual)),
+        Err(e) => format!("expected {}, got an error: {}", one_line(&expected.answer()), e),
+    };
+
+    match expected.known_issue() {
+        Some(issue) => (Status::Fail, format!(" - {} (known issue: {})", problem, issue)),
+        None => (Status::Regression, format!(" - {}", problem)),
+    }
+}
+
+// Keeps multi-line answers from breaking the report
+fn one_line(answer: &str) -> String {
+    if answer.trim_end().contains('\n') {
+        format!("{:?}", answer.trim_end())
+    } else {
+        answer.to_string()
+    }
+}
@@ -0,0 +1,34 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day1;
+
+impl Generator for Day1 {
+    // Number of elves
+    const DEFAULT_SIZE: usize = 250;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // The second part needs at least three elves
+        let elves: Vec<String> = (0..size.max(3))
+            .map(|_| {
+                let items = rng.random_range(1..=15);
+                (0..items).map(|_| format!("{}\n", rng.random_range(1000..60_000))).collect()
+            })
+            .collect();
+        elves.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let elves = Day1::parse(&generate::<Day1>(seed, 10)).unwrap();
+            assert_eq!(elves.len(), 10);
+            Day1::part2(&elves).unwrap();
+        }
+    }
+}
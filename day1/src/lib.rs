@@ -0,0 +1,79 @@
+use anyhow::Result;
+use aoc_common::{debug, parse, Answer, Solver};
+
+mod generate;
+
+pub struct Day1;
+
+impl Solver for Day1 {
+    const DAY: u8 = 1;
+    type Input = Vec<u32>;
+
+    // Returns the total calories carried by each elf
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut elves_calories: Vec<u32> = Vec::new();
+
+        let mut current_elf_calories: u32 = 0;
+        for line in parse::lines(input) {
+          if line.text.is_empty() {
+            elves_calories.push(current_elf_calories);
+            current_elf_calories = 0;
+            continue;
+          }
+          let calories: u32 = line.parse(line.text, "a number of calories")?;
+          current_elf_calories = current_elf_calories.checked_add(calories)
+            .ok_or_else(|| line.error(1, "fewer calories (the total does not fit into 32 bits)"))?;
+        }
+        elves_calories.push(current_elf_calories);
+
+        Ok(elves_calories)
+    }
+
+    fn part1(elves_calories: &Self::Input) -> Result<Answer> {
+        // find elf with max calories
+        let mut max_calories: u32 = 0;
+        let mut max_calories_elf: usize = 0;
+        for (i, calories) in elves_calories.iter().enumerate() {
+          if *calories > max_calories {
+            max_calories = *calories;
+            max_calories_elf = i;
+          }
+        }
+
+        debug!("Elf {} has the most calories: {}", max_calories_elf+1, max_calories);
+        Ok(max_calories.into())
+    }
+
+    fn part2(elves_calories: &Self::Input) -> Result<Answer> {
+        // find the total of top 3 elves
+        let mut top_elves_calories: Vec<u32> = elves_calories.clone();
+        top_elves_calories.sort();
+        top_elves_calories.reverse();
+        let top_elves_calories: u32 = top_elves_calories[0..3].iter().sum();
+        debug!("The top 3 elves have a total of {} calories", top_elves_calories);
+        Ok(top_elves_calories.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([0-9]{0,10}\n){0,10}[0-9a -]{0,10}") {
+            prop_assert!(parse::error_points_into(&Day1::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(elves in prop::collection::vec(prop::collection::vec(0..100_000u32, 1..5), 1..10)) {
+            let input = elves.iter()
+                .map(|calories| calories.iter().map(|c| c.to_string()).collect::<Vec<_>>().join("\n"))
+                .collect::<Vec<_>>()
+                .join("\n\n");
+            let totals: Vec<u32> = elves.iter().map(|calories| calories.iter().sum()).collect();
+            prop_assert_eq!(Day1::parse(&input).unwrap(), totals);
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::{Day10, HEIGHT, WIDTH};
+
+impl Generator for Day10 {
+    // The program always runs for as many cycles as there are pixels on the screen,
+    // so the size is the largest addx argument
+    const DEFAULT_SIZE: usize = 20;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let max_arg = size.max(1) as i64;
+        let mut input = String::new();
+        let mut cycles = 0;
+        while cycles < WIDTH * HEIGHT {
+            if cycles + 2 <= WIDTH * HEIGHT && rng.random_bool(0.6) {
+                input += &format!("addx {}\n", rng.random_range(-max_arg..=max_arg));
+                cycles += 2;
+            } else {
+                input += "noop\n";
+                cycles += 1;
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let commands = Day10::parse(&generate::<Day10>(seed, 5)).unwrap();
+            Day10::part1(&commands).unwrap();
+            Day10::part2(&commands).unwrap();
+        }
+    }
+}
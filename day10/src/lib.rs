@@ -0,0 +1,152 @@
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, trace, Answer, ParseError, Solver};
+
+mod generate;
+
+#[derive(Debug, PartialEq)]
+pub enum Instruction {
+    Noop,
+    Addx(i64),
+}
+
+impl Instruction {
+    fn from_line(line: &Line) -> Result<Self, ParseError> {
+        if line.text == "noop" {
+            return Ok(Instruction::Noop);
+        }
+        match line.text.strip_prefix("addx ") {
+            Some(arg) => Ok(Instruction::Addx(line.parse(arg, "an addx argument")?)),
+            None => Err(line.error(1, "noop or addx")),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Computer {
+    sprite_center: i64,
+    pixel_pos: usize,
+    crt: Vec<bool>,
+    signal_strength: i64,
+}
+
+const WIDTH: usize = 40;
+const HEIGHT: usize = 6;
+
+impl Computer {
+    fn new() -> Self {
+        let mut c = Computer {
+            sprite_center: 1,
+            pixel_pos: 0,
+            crt: Vec::with_capacity(WIDTH * HEIGHT),
+            signal_strength: 0,
+        };
+        c.crt.resize(WIDTH * HEIGHT, false);
+        c
+    }
+
+    fn execute_command(&mut self, command: &Instruction) {
+        match command {
+            Instruction::Noop => self.color_pixel(),
+            Instruction::Addx(change) => {
+                self.color_pixel();
+                self.color_pixel();
+                self.sprite_center += change
+            }
+        }
+    }
+
+    fn pixel_char(&self, value: bool) -> char {
+        if value { '#' } else { ' ' }
+    }
+
+    fn color_pixel(&mut self) {
+        // Signal strength is sampled during the 20th cycle and every 40 cycles after that
+        let cycle = self.pixel_pos as i64 + 1;
+        if (cycle - 20) % 40 == 0 {
+            self.signal_strength += cycle * self.sprite_center;
+        }
+
+        let sprite = self.sprite_center-1..=self.sprite_center+1;
+        let pixel_pos = (self.pixel_pos % WIDTH) as i64;
+        let pixel = sprite.contains(&pixel_pos);
+        self.crt[self.pixel_pos] = pixel;
+        self.pixel_pos += 1;
+    }
+
+    fn screen(&self) -> String {
+        let mut screen = String::with_capacity((WIDTH + 1) * HEIGHT);
+        for row in 0..HEIGHT {
+            for col in 0..WIDTH {
+                let pos = row*WIDTH + col;
+                screen.push(self.pixel_char(self.crt[pos]));
+            }
+            screen.push('\n');
+        }
+        screen
+    }
+
+    fn print_screen(&self) {
+        trace!("{}", self.screen());
+    }
+}
+
+// Runs the whole program on a fresh computer
+fn run_program(commands: &[Instruction]) -> Computer {
+    let mut computer = Computer::new();
+    for command in commands {
+        computer.execute_command(command)
+    }
+    computer
+}
+
+pub struct Day10;
+
+impl Solver for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let commands: Result<Vec<_>, _> = parse::lines(input).map(|line| Instruction::from_line(&line)).collect();
+        Ok(commands?)
+    }
+
+    fn part1(commands: &Self::Input) -> Result<Answer> {
+        let computer = run_program(commands);
+        debug!("Signal strength: {}", computer.signal_strength);
+        Ok(computer.signal_strength.into())
+    }
+
+    fn part2(commands: &Self::Input) -> Result<Answer> {
+        let computer = run_program(commands);
+        computer.print_screen();
+        Ok(computer.screen().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "(noop\n|addx -?[0-9]{1,3}\n){0,5}[a-z0-9 -]{0,8}") {
+            prop_assert!(parse::error_points_into(&Day10::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(args in prop::collection::vec(prop::option::of(-100..100i64), 0..20)) {
+            let input: String = args.iter()
+                .map(|arg| match arg {
+                    Some(arg) => format!("addx {}\n", arg),
+                    None => "noop\n".to_string(),
+                })
+                .collect();
+            let expected: Vec<Instruction> = args.iter()
+                .map(|arg| arg.map_or(Instruction::Noop, Instruction::Addx))
+                .collect();
+            prop_assert_eq!(Day10::parse(&input).unwrap(), expected);
+        }
+    }
+}
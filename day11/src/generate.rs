@@ -0,0 +1,55 @@
+use aoc_common::generate::{Generator, IndexedRandom, Rng, StdRng};
+use crate::Day11;
+
+// Small enough for the product of all the divisors squared to fit into u64
+const DIVISORS: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+impl Generator for Day11 {
+    // Number of monkeys
+    const DEFAULT_SIZE: usize = 8;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // Like in the real input, one of the monkeys squares the worry levels
+        let count = size.max(2);
+        let squaring = rng.random_range(0..count);
+
+        let mut monkeys = Vec::new();
+        for i in 0..count {
+            let items: Vec<String> = (0..rng.random_range(1..=8)).map(|_| rng.random_range(50..100).to_string()).collect();
+            let operation = match i {
+                i if i == squaring => "* old".to_string(),
+                _ if rng.random_bool(0.3) => format!("* {}", rng.random_range(2..20)),
+                _ => format!("+ {}", rng.random_range(1..9)),
+            };
+
+            // Monkeys never throw to themselves, and never to the same monkey in both cases
+            let if_true = (i + rng.random_range(1..count)) % count;
+            let if_false = loop {
+                let monkey = (i + rng.random_range(1..count)) % count;
+                if monkey != if_true || count == 2 { break monkey }
+            };
+
+            monkeys.push(format!(
+                "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+                i, items.join(", "), operation, DIVISORS.choose(rng).unwrap(), if_true, if_false
+            ));
+        }
+        monkeys.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_parsed() {
+        for seed in 0..10 {
+            let monkeys = Day11::parse(&generate::<Day11>(seed, 5)).unwrap();
+            assert_eq!(monkeys.len(), 5);
+            assert!(monkeys.iter().enumerate().all(|(i, monkey)| monkey.test_pass_dst != i && monkey.test_fail_dst != i));
+        }
+    }
+}
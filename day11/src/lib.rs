@@ -0,0 +1,238 @@
+use std::collections::VecDeque;
+use anyhow::Result;
+use aoc_common::parse::{self, Line, Lines};
+use aoc_common::{debug, trace, Answer, ParseError, Solver};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Operation {
+    Add(u64),
+    Mul(u64),
+    Square,
+}
+
+impl Operation {
+    fn run(&self, operand: u64) -> u64 {
+        match self {
+            Operation::Add(x) => operand + x,
+            Operation::Mul(x) => operand * x,
+            Operation::Square => operand * operand
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monkey {
+    items: VecDeque<u64>,
+    op: Operation,
+    test_div: u64,
+    test_pass_dst: usize,
+    test_fail_dst: usize,
+}
+
+impl Monkey {
+    fn items_from_line(line: Line) -> Result<VecDeque<u64>, ParseError> {
+        let items = line.strip_prefix("Starting items: ")?;
+        items.split(", ").map(|i| line.parse(i, "a worry level")).collect()
+    }
+
+    fn operation_from_line(line: Line) -> Result<Operation, ParseError> {
+        let mut op_str = line.strip_prefix("Operation: new = old ")?.split_whitespace();
+        let operator = op_str.next().ok_or_else(|| line.error_at_end("an operator"))?;
+        let operand = op_str.next().ok_or_else(|| line.error_at_end("an operand"))?;
+
+        match operator {
+            "+" => Ok(Operation::Add(line.parse(operand, "an operand for addition")?)),
+            "*" => {
+                if operand.eq("old") {
+                    Ok(Operation::Square)
+                } else {
+                    Ok(Operation::Mul(line.parse(operand, "an operand for multiplication")?))
+                }
+            },
+            _ => Err(line.error_at(operator, "+ or *")),
+        }
+    }
+
+    fn test_div_from_line(line: Line) -> Result<u64, ParseError> {
+        let divisor = line.strip_prefix("Test: divisible by ")?;
+        line.parse(divisor, "a test divisor")
+    }
+
+    fn test_dst_from_line(line: Line, condition: &str) -> Result<usize, ParseError> {
+        let monkey = line.strip_prefix(&format!("If {}: throw to monkey ", condition))?;
+        line.parse(monkey, "a monkey number")
+    }
+
+    // Monkey 1:
+    // Starting items: 54, 65, 75, 74
+    // Operation: new = old + 6
+    // Test: divisible by 19
+    //   If true: throw to monkey 2
+    //   If false: throw to monkey 0
+    fn from_lines(lines: &mut Lines) -> Result<Self, ParseError> {
+        let items = Self::items_from_line(lines.next_line("starting items")?)?;
+        let op = Self::operation_from_line(lines.next_line("an operation")?)?;
+        let test_div = Self::test_div_from_line(lines.next_line("a test")?)?;
+        let test_pass_dst = Self::test_dst_from_line(lines.next_line("a monkey to throw to")?, "true")?;
+        let test_fail_dst = Self::test_dst_from_line(lines.next_line("a monkey to throw to")?, "false")?;
+
+        Ok(Monkey { items, op, test_div, test_pass_dst, test_fail_dst })
+    }
+
+    fn test(&self, value: u64) -> usize {
+        if value.is_multiple_of(self.test_div) {
+            self.test_pass_dst
+        } else {
+            self.test_fail_dst
+        }
+    }
+}
+
+struct Game {
+    monkeys: Vec<Monkey>,
+    activity: Vec<u64>,
+    modulus: u64,
+    relief: bool,
+}
+
+impl Game {
+    // With relief, worry level gets divided by 3 after each inspection
+    fn new(relief: bool) -> Self {
+        Game {
+            monkeys: Vec::new(),
+            activity: Vec::new(),
+            modulus: 1,
+            relief,
+        }
+    }
+
+    fn push(&mut self, monkey: Monkey) {
+        self.modulus *= monkey.test_div;
+        self.monkeys.push(monkey);
+        self.activity.resize(self.monkeys.len(), 0);
+    }
+
+    fn print_monkeys(&self) {
+        trace!("Monkeys:");
+        for monkey in self.monkeys.iter() {
+            trace!(" - {:?}", monkey);
+        }
+    }
+
+    fn process_item(&mut self, monkey_idx: usize, item: u64) {
+        self.activity[monkey_idx] += 1;
+        let monkey = &self.monkeys[monkey_idx];
+        let result = monkey.op.run(item);
+
+        // Without relief worry levels grow too fast, so we keep them modulo the product of all test divisors
+        let result = if self.relief { result / 3 } else { result % self.modulus };
+        let dst = monkey.test(result);
+        self.monkeys[dst].items.push_back(result);
+    }
+
+    fn round(&mut self) {
+        for m in 0..self.monkeys.len() {
+            let items = std::mem::take(&mut self.monkeys[m].items);
+            for item in items {
+                self.process_item(m, item);
+            }
+        }
+    }
+}
+
+// Plays the given number of rounds and returns the level of monkey business
+fn play(monkeys: &[Monkey], rounds: usize, relief: bool) -> u64 {
+    let mut game = Game::new(relief);
+    for monkey in monkeys {
+        game.push(monkey.clone());
+    }
+
+    game.print_monkeys();
+    for _round in 0..rounds {
+        game.round();
+    }
+
+    game.activity.sort();
+    game.activity.reverse();
+
+    debug!("Sorted activity: {:?}", game.activity);
+    let monkey_business = game.activity[0] * game.activity[1];
+    debug!("Monkey business: {monkey_business}");
+    monkey_business
+}
+
+pub struct Day11;
+
+impl Solver for Day11 {
+    const DAY: u8 = 11;
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = parse::lines(input);
+        let mut monkeys = Vec::new();
+
+        while lines.any(|l| l.text.starts_with("Monkey")) {
+            monkeys.push(Monkey::from_lines(&mut lines)?);
+        }
+
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<Answer> {
+        Ok(play(monkeys, 20, true).into())
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<Answer> {
+        Ok(play(monkeys, 10000, false).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    fn operation() -> impl Strategy<Value = Operation> {
+        prop_oneof![
+            (0..20u64).prop_map(Operation::Add),
+            (0..20u64).prop_map(Operation::Mul),
+            Just(Operation::Square),
+        ]
+    }
+
+    fn monkey() -> impl Strategy<Value = Monkey> {
+        (prop::collection::vec_deque(0..100u64, 1..5), operation(), 1..20u64, 0..8usize, 0..8usize)
+            .prop_map(|(items, op, test_div, test_pass_dst, test_fail_dst)| {
+                Monkey { items, op, test_div, test_pass_dst, test_fail_dst }
+            })
+    }
+
+    fn describe(number: usize, monkey: &Monkey) -> String {
+        let items: Vec<String> = monkey.items.iter().map(|item| item.to_string()).collect();
+        let operation = match monkey.op {
+            Operation::Add(x) => format!("+ {}", x),
+            Operation::Mul(x) => format!("* {}", x),
+            Operation::Square => "* old".to_string(),
+        };
+        format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = old {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            number, items.join(", "), operation, monkey.test_div, monkey.test_pass_dst, monkey.test_fail_dst
+        )
+    }
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "(Monkey 0:\n(  [A-Za-z]{2,9}: [a-z =+*]{0,8}[0-9, ]{0,6}\n){0,6}){0,2}[A-Za-z0-9: ]{0,8}") {
+            prop_assert!(parse::error_points_into(&Day11::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(monkeys in prop::collection::vec(monkey(), 1..5)) {
+            let descriptions: Vec<String> = monkeys.iter().enumerate().map(|(i, monkey)| describe(i, monkey)).collect();
+            let input = descriptions.join("\n");
+            prop_assert_eq!(Day11::parse(&input).unwrap(), monkeys);
+        }
+    }
+}
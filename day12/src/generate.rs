@@ -0,0 +1,59 @@
+use aoc_common::generate::{Generator, IndexedRandom, Rng, SliceRandom, StdRng};
+use crate::Day12;
+
+impl Generator for Day12 {
+    // Height of the map, which is four times as wide
+    const DEFAULT_SIZE: usize = 41;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let height = size.max(1);
+        let width = (4 * height).max(26);
+
+        // Elevation only grows along the diagonals, one step at a time, from a to z
+        let diagonals = width + height - 1;
+        let mut steps: Vec<usize> = (1..diagonals).collect();
+        steps.shuffle(rng);
+        let mut elevation = vec![0; diagonals];
+        for &diagonal in steps[..25].iter() {
+            elevation[diagonal] = 1;
+        }
+        for diagonal in 1..diagonals {
+            elevation[diagonal] += elevation[diagonal - 1];
+        }
+
+        // Pits that are hard to climb out of, never in the first row or the last column,
+        // so that there is always a way along them to the end
+        let mut map: Vec<Vec<char>> = (0..height)
+            .map(|y| (0..width).map(|x| (b'a' + elevation[x + y]) as char).collect())
+            .collect();
+        for row in map.iter_mut().skip(1) {
+            for tile in row[..width - 1].iter_mut() {
+                if rng.random_bool(0.1) {
+                    *tile = 'a';
+                }
+            }
+        }
+
+        // The start is at the lowest elevation, so that it can be left in any direction
+        let start_rows: Vec<usize> = (0..height).filter(|&y| elevation[y] == 0).collect();
+        map[*start_rows.choose(rng).unwrap()][0] = 'S';
+        map[height - 1][width - 1] = 'E';
+        map.into_iter().map(|row| row.into_iter().chain(Some('\n')).collect::<String>()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::{Answer, Solver};
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let map = Day12::parse(&generate::<Day12>(seed, 5)).unwrap();
+            assert_ne!(Day12::part1(&map).unwrap(), Answer::Number(0));
+            assert_ne!(Day12::part2(&map).unwrap(), Answer::Number(0));
+        }
+    }
+}
@@ -0,0 +1,154 @@
+use anyhow::{Context, Result};
+use aoc_common::{debug, trace, Answer, Grid, Point, Solver};
+
+mod generate;
+
+#[derive(Debug)]
+struct Step {
+    coord: Point<usize>,
+    path_len: usize,
+    src_height: char,
+}
+
+// Height map surrounded by a wall of '~' so that we never step outside of it
+pub struct HeightMap {
+    map: Grid<char>,
+    start: Point<usize>,
+    end: Point<usize>,
+}
+
+impl HeightMap {
+    // Returns the length of the shortest path from any of the starting points to the end
+    fn shortest_path(&self, starts: Vec<Point<usize>>) -> usize {
+        let map = &self.map;
+        let end = self.end;
+
+        // Create a map of visited places with distances from the start
+        let mut path_len = Grid::new(map.width(), map.height(), 0);
+
+        let mut steps_to_check: Vec<Step> = starts.into_iter()
+            .map(|coord| Step { coord, path_len: 0, src_height: 'a' })
+            .collect();
+
+        let mut shortest_len = 1000000000;
+        while let Some(step) = steps_to_check.pop() {
+            // Do not take steps if the new path length is longer than whatever we have already found
+            if step.path_len > shortest_len { continue }
+
+            let step_height = map[step.coord];
+            let step_gain = elevation_gain(step.src_height, step_height);
+
+            // Do not take steps with en elevation gain or drop that requires climbing
+            if step_gain > 1 { continue }
+
+            // This step would be a longer path to the given point than we have already found
+            if path_len[step.coord] > 0 && path_len[step.coord] <= step.path_len  { continue }
+
+            path_len[step.coord] = step.path_len;
+            if end == step.coord && shortest_len > step.path_len {
+                shortest_len = step.path_len;
+            }
+
+            for coord in step.coord.neighbors() {
+                steps_to_check.push(Step { coord, src_height: step_height, path_len: step.path_len + 1 });
+            }
+        }
+
+        debug!("Shortest path: {}", path_len[end]);
+        path_len[end]
+    }
+}
+
+pub struct Day12;
+
+impl Solver for Day12 {
+    const DAY: u8 = 12;
+    type Input = HeightMap;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let map = Grid::try_parse(input, '~', "an elevation (a-z, S or E)", |c| {
+            (c.is_ascii_lowercase() || c == 'S' || c == 'E').then_some(c)
+        })?;
+        let mut map = map.with_border(1, '~');
+
+        let start = map.position(|&c| c == 'S').context("looking for the start")?;
+        map[start] = 'a';
+
+        let end = map.position(|&c| c == 'E').context("looking for the end")?;
+        map[end] = 'z';
+
+        let (width, height) = (map.width(), map.height());
+
+        debug!("Map: {width}x{height}");
+        debug!("Start: {:?}", start);
+        debug!("End: {:?}", end);
+        for row in map.rows() {
+            trace!("{}", row.iter().collect::<String>());
+        }
+
+        Ok(HeightMap { map, start, end })
+    }
+
+    fn part1(map: &Self::Input) -> Result<Answer> {
+        Ok(map.shortest_path(vec![map.start]).into())
+    }
+
+    // Start from all the points at elevation 'a'
+    fn part2(map: &Self::Input) -> Result<Answer> {
+        let starts = map.map.points().filter(|&p| map.map[p] == 'a').collect();
+
+        Ok(map.shortest_path(starts).into())
+    }
+}
+
+fn elevation_gain(src: char, dst: char) -> i16 {
+    dst as i16 - src as i16
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse;
+    use proptest::prelude::*;
+    use super::*;
+
+    // A map with the start and the end in two different places
+    fn height_map() -> impl Strategy<Value = (Vec<Vec<char>>, Point<usize>, Point<usize>)> {
+        (2..10usize, 1..10usize)
+            .prop_flat_map(|(width, height)| {
+                let map = prop::collection::vec(prop::collection::vec(prop::char::range('a', 'z'), width), height);
+                (map, 0..width * height, 1..width * height)
+            })
+            .prop_map(|(map, start, distance)| {
+                let width = map[0].len();
+                let end = (start + distance) % (width * map.len());
+                (map, Point::new(start % width, start / width), Point::new(end % width, end / width))
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([a-zSE]{0,6}\n){0,5}[a-zSE0 ]{0,6}") {
+            prop_assert!(parse::error_points_into(&Day12::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip((mut map, start, end) in height_map()) {
+            map[start.y][start.x] = 'S';
+            map[end.y][end.x] = 'E';
+            let input: String = map.iter().map(|row| row.iter().collect::<String>() + "\n").collect();
+            map[start.y][start.x] = 'a';
+            map[end.y][end.x] = 'z';
+
+            // The parsed map has a border around it
+            let height_map = Day12::parse(&input).unwrap();
+            prop_assert_eq!(height_map.start, Point::new(start.x + 1, start.y + 1));
+            prop_assert_eq!(height_map.end, Point::new(end.x + 1, end.y + 1));
+            let rows: Vec<Vec<char>> = height_map.map.rows()
+                .skip(1)
+                .take(map.len())
+                .map(|row| row[1..row.len() - 1].to_vec())
+                .collect();
+            prop_assert_eq!(rows, map);
+        }
+    }
+}
@@ -0,0 +1,42 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::{Day13, Packet};
+
+fn list(rng: &mut StdRng, depth: usize) -> Packet {
+    let items = (0..rng.random_range(0..=5))
+        .map(|_| {
+            if depth < 4 && rng.random_bool(0.3) {
+                list(rng, depth + 1)
+            } else {
+                Packet::Single(rng.random_range(0..=10))
+            }
+        })
+        .collect();
+    Packet::List(items)
+}
+
+impl Generator for Day13 {
+    // Number of pairs of packets
+    const DEFAULT_SIZE: usize = 150;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let pairs: Vec<String> = (0..size).map(|_| format!("{}\n{}\n", list(rng, 0), list(rng, 0))).collect();
+        pairs.join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let packets = Day13::parse(&generate::<Day13>(seed, 10)).unwrap();
+            assert_eq!(packets.len(), 20);
+            Day13::part1(&packets).unwrap();
+            Day13::part2(&packets).unwrap();
+        }
+    }
+}
@@ -0,0 +1,213 @@
+use std::{str::CharIndices, iter::Peekable, cmp::Ordering, fmt::Write};
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, Answer, ParseError, Solver};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Packet {
+    Single(usize),
+    List(Vec<Packet>)
+}
+
+impl std::fmt::Display for Packet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Packet::List(items) => {
+                f.write_char('[')?;
+                let str = items.iter().map(|i| format!("{i}")).collect::<Vec<String>>().join(",");
+                write!(f, "{str}")?;
+                f.write_char(']')
+            },
+            Packet::Single(item) => write!(f, "{}", item),
+        }
+    }
+}
+
+impl Packet {
+    fn from_str(text: &str) -> Result<Packet, ParseError> {
+        Self::from_line(&Line { number: 1, text })
+    }
+
+    // A packet is a list, with nothing after the closing bracket
+    fn from_line(line: &Line) -> Result<Packet, ParseError> {
+        let mut chars = line.text.char_indices().peekable();
+        if chars.next_if(|&(_, c)| c == '[').is_none() {
+            return Err(line.error(1, "a list"));
+        }
+        let packet = Self::list_from_str(line, &mut chars)?;
+        match chars.next() {
+            Some((pos, _)) => Err(line.error_at(&line.text[pos..], "the end of the packet")),
+            None => Ok(packet),
+        }
+    }
+
+    // Parses the items of a list up to its closing bracket (the opening one is already consumed)
+    fn list_from_str(line: &Line, chars: &mut Peekable<CharIndices>) -> Result<Packet, ParseError> {
+        let mut list_vals = Vec::default();
+        if chars.next_if(|&(_, c)| c == ']').is_some() {
+            return Ok(Packet::List(list_vals));
+        }
+        loop {
+            list_vals.push(Self::item_from_str(line, chars)?);
+            match chars.next() {
+                Some((_, ',')) => continue,
+                Some((_, ']')) => return Ok(Packet::List(list_vals)),
+                Some((pos, _)) => return Err(line.error_at(&line.text[pos..], "a comma or a closing bracket")),
+                None => return Err(line.error_at_end("a closing bracket")),
+            }
+        }
+    }
+
+    fn item_from_str(line: &Line, chars: &mut Peekable<CharIndices>) -> Result<Packet, ParseError> {
+        match chars.peek() {
+            Some(&(_, '[')) => {
+                chars.next();
+                Self::list_from_str(line, chars)
+            },
+            Some(&(_, c)) if c.is_ascii_digit() => Self::number_from_str(line, chars),
+            Some(&(pos, _)) => Err(line.error_at(&line.text[pos..], "a number or a list")),
+            None => Err(line.error_at_end("a number or a list")),
+        }
+    }
+
+    fn number_from_str(line: &Line, chars: &mut Peekable<CharIndices>) -> Result<Packet, ParseError> {
+        let start = chars.peek().map_or(line.text.len(), |&(pos, _)| pos);
+        let mut value = String::new();
+        while let Some((_, c)) = chars.next_if(|&(_, c)| c.is_ascii_digit()) {
+            value.push(c);
+        }
+        let value = value.parse().map_err(|_| line.error_at(&line.text[start..], "a number"))?;
+        Ok(Packet::Single(value))
+    }
+
+    fn cmp(&self, right: &Self) -> Ordering {
+        let left = self;
+        match (left, right) {
+            (Packet::Single(left), Packet::Single(right)) => {
+                left.cmp(right)
+            },
+            (Packet::List(left), Packet::List(right)) => {
+                Self::compare_lists(left, right)
+            },
+            (Packet::List(_), Packet::Single(right)) => {
+                let right = Packet::List(vec![Packet::Single(*right)]);
+                left.cmp(&right)
+            },
+            (Packet::Single(left), Packet::List(_)) => {
+                let left = Packet::List(vec![Packet::Single(*left)]);
+                left.cmp(right)
+            },
+        }
+    }
+
+    fn compare_lists(left: &[Packet], right: &[Packet]) -> Ordering {
+        for pos in 0..left.len() {
+            if pos >= right.len() {
+                return Ordering::Greater
+            }
+            let left = &left[pos];
+            let right = &right[pos];
+            let res = left.cmp(right);
+            if !res.is_eq() { return res }
+        }
+        if left.len() == right.len() {
+            Ordering::Equal
+        } else {
+            Ordering::Less
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solver for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Packet>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut packets: Vec<Packet> = Vec::default();
+        for line in parse::lines(input) {
+            if line.text.is_empty() { continue }
+            let packet = Packet::from_line(&line)?;
+            packets.push(packet);
+        }
+        Ok(packets)
+    }
+
+    // Sum of 1-based indices of the pairs that are in the right order
+    fn part1(packets: &Self::Input) -> Result<Answer> {
+        let mut indices_sum = 0;
+        for (idx, pair) in packets.chunks_exact(2).enumerate() {
+            if pair[0].cmp(&pair[1]).is_lt() {
+                indices_sum += idx + 1;
+            }
+        }
+
+        debug!("Sum of indices in the right order: {indices_sum}");
+        Ok(indices_sum.into())
+    }
+
+    fn part2(packets: &Self::Input) -> Result<Answer> {
+        let mut packets = packets.clone();
+
+        let divider2 = Packet::from_str("[[2]]")?;
+        let divider6 = Packet::from_str("[[6]]")?;
+        packets.push(divider2.clone());
+        packets.push(divider6.clone());
+
+        packets.sort_by(|a,b| a.cmp(b));
+
+        let pos2 = packets.iter().position(|p| p.eq(&divider2) ).unwrap() + 1;
+        let pos6 = packets.iter().position(|p| p.eq(&divider6) ).unwrap() + 1;
+
+        debug!("Positions for dividers: {pos2} and {pos6}");
+        debug!("Result: {}", pos2*pos6);
+        Ok((pos2*pos6).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    fn list() -> impl Strategy<Value = Packet> {
+        let leaf = (0..20usize).prop_map(Packet::Single);
+        let packet = leaf.prop_recursive(4, 32, 5, |inner| {
+            prop::collection::vec(inner, 0..5).prop_map(Packet::List)
+        });
+        prop::collection::vec(packet, 0..5).prop_map(Packet::List)
+    }
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([\\[\\],0-9]{0,12}\n){0,5}[\\[\\],0-9a é]{0,12}") {
+            prop_assert!(parse::error_points_into(&Day13::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(packets in prop::collection::vec(list(), 0..6)) {
+            let pairs: Vec<String> = packets.chunks(2)
+                .map(|pair| pair.iter().map(|packet| format!("{}\n", packet)).collect())
+                .collect();
+            let input = pairs.join("\n");
+            prop_assert_eq!(Day13::parse(&input).unwrap(), packets);
+        }
+    }
+
+    #[test]
+    fn parses_nested_lists() {
+        let packet = Packet::from_str("[[1],[2,[]],3]").unwrap();
+        let list = Packet::List;
+        let expected = list(vec![
+            list(vec![Packet::Single(1)]),
+            list(vec![Packet::Single(2), list(vec![])]),
+            Packet::Single(3),
+        ]);
+        assert_eq!(packet, expected);
+        assert_eq!(Packet::from_str("[1,2]]").unwrap_err().column, 6);
+        assert_eq!(Packet::from_str("[1,,2]").unwrap_err().column, 4);
+    }
+}
@@ -0,0 +1,65 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day14;
+
+impl Generator for Day14 {
+    // Number of rock paths
+    const DEFAULT_SIZE: usize = 150;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            let points = loop {
+                let points = rock_path(rng);
+                if !crosses_the_diagonal(&points) { break points }
+            };
+            let points: Vec<String> = points.iter().map(|(x, y)| format!("{},{}", x, y)).collect();
+            input += &format!("{}\n", points.join(" -> "));
+        }
+        input
+    }
+}
+
+// Below the source of the sand (500,0), and not too deep for the floor to fit into the map
+fn rock_path(rng: &mut StdRng) -> Vec<(usize, usize)> {
+    let (mut x, mut y): (usize, usize) = (rng.random_range(450..550), rng.random_range(10..150));
+    let mut points = vec![(x, y)];
+    for segment in 0..rng.random_range(1..=5) {
+        let length = rng.random_range(1..=10);
+        let forward = rng.random_bool(0.5);
+        match (segment % 2 == 0, forward) {
+            (true, true) => x += length,
+            (true, false) => x -= length,
+            (false, true) => y = (y + length).min(160),
+            (false, false) => y = y.saturating_sub(length).max(1),
+        }
+        points.push((x, y));
+    }
+    points
+}
+
+// Sand only comes to rest on something down and to the left of it, so sand at the source would need
+// a column of sand or rock all along the diagonal x + y = 500 below it. With no rock there the cave
+// never fills up to the source, and part 1 always ends with the sand falling into the abyss.
+fn crosses_the_diagonal(points: &[(usize, usize)]) -> bool {
+    points.windows(2).any(|segment| {
+        let (start, end) = (segment[0].0 + segment[0].1, segment[1].0 + segment[1].1);
+        start.min(end) <= 500 && 500 <= start.max(end)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let cave = Day14::parse(&generate::<Day14>(seed, 10)).unwrap();
+            assert_eq!(cave.rock_paths.len(), 10);
+            Day14::part1(&cave).unwrap();
+            Day14::part2(&cave).unwrap();
+        }
+    }
+}
@@ -0,0 +1,203 @@
+use std::cmp::{min, max};
+use anyhow::Result;
+use aoc_common::{debug, parse, Answer, Grid, Point, Solver};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+struct Map {
+    tiles: Grid<Tile>,
+}
+
+impl Map {
+    fn new(width: usize, height: usize) -> Self {
+        Map {
+            tiles: Grid::new(width, height, Tile::Air)
+        }
+    }
+
+    fn draw_rock_paths(&mut self, rock_line: &[Point<usize>]) {
+        let mut segment_start = &rock_line[0];
+        for segment_end in &rock_line[1..] {
+            self.draw_rock_path(segment_start, segment_end);
+            segment_start = segment_end;
+        }
+    }
+
+    fn add_floor(&mut self) {
+        self.tiles.push_row(Tile::Rock);
+    }
+
+    fn draw_rock_path(&mut self, segment_start: &Point<usize>, segment_end: &Point<usize>) {
+        if segment_start.x == segment_end.x {
+            self.draw_vertical_path(segment_start, segment_end);
+        } else if segment_start.y == segment_end.y {
+            self.draw_horizontal_path(segment_start, segment_end);
+        } else {
+            panic!("Unknown type of line: {:?} to {:?}", segment_start, segment_end);
+        }
+    }
+
+    fn draw_vertical_path(&mut self, segment_start: &Point<usize>, segment_end: &Point<usize>) {
+        let start_y = min(segment_start.y, segment_end.y);
+        let end_y = max(segment_start.y, segment_end.y);
+        for y in start_y..=end_y {
+            self.tiles[y][segment_start.x] = Tile::Rock;
+        }
+    }
+
+    fn draw_horizontal_path(&mut self, segment_start: &Point<usize>, segment_end: &Point<usize>) {
+        let start_x = min(segment_start.x, segment_end.x);
+        let end_x = max(segment_start.x, segment_end.x);
+        for x in start_x..=end_x {
+            self.tiles[segment_start.y][x] = Tile::Rock;
+        }
+    }
+
+    // Returns the position where the sand came to rest or None if it fell into the abyss
+    fn simulate_sand(&mut self) -> Option<Point<usize>> {
+        let mut sand = Point::new(500, 0);
+        loop {
+            if sand.y + 1 >= self.tiles.height() {
+                return None;
+            } else if self.tiles[sand.y+1][sand.x] == Tile::Air {
+                sand.y += 1;
+            } else if self.tiles[sand.y+1][sand.x-1] == Tile::Air {
+                sand.x -= 1;
+                sand.y += 1;
+            } else if self.tiles[sand.y+1][sand.x+1] == Tile::Air {
+                sand.x += 1;
+                sand.y += 1;
+            } else {
+                self.tiles[sand] = Tile::Sand;
+                return Some(sand);
+            }
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Cave {
+    rock_paths: Vec<Vec<Point<usize>>>,
+    width: usize,
+    height: usize,
+}
+
+impl Cave {
+    fn map(&self) -> Map {
+        let mut map = Map::new(self.width * 2, self.height + 2);
+        for rock_line in self.rock_paths.iter() {
+            map.draw_rock_paths(rock_line);
+        }
+        map
+    }
+}
+
+pub struct Day14;
+
+impl Solver for Day14 {
+    const DAY: u8 = 14;
+    type Input = Cave;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut rock_paths: Vec<Vec<Point<usize>>> = Vec::default();
+
+        let mut map_width: usize = 0;
+        let mut map_height: usize = 0;
+
+        for line in parse::lines(input) {
+            let mut rock_path: Vec<Point<usize>> = Vec::default();
+            for coords in line.text.split(" -> ") {
+                let (x, y) = coords.split_once(',').ok_or_else(|| line.error_at(coords, "a point (like 498,4)"))?;
+
+                let x = line.parse(x, "an x coordinate")?;
+                if map_width < x { map_width = x };
+
+                let y = line.parse(y, "a y coordinate")?;
+                if map_height < y { map_height = y };
+
+                // Rock paths are made of horizontal and vertical segments only
+                if rock_path.last().is_some_and(|prev: &Point<usize>| prev.x != x && prev.y != y) {
+                    return Err(line.error_at(coords, "a point in line with the previous one").into());
+                }
+
+                rock_path.push(Point::new(x, y));
+            }
+
+            rock_paths.push(rock_path);
+        }
+
+        Ok(Cave { rock_paths, width: map_width, height: map_height })
+    }
+
+    // No floor, count the sand units at rest before it starts falling into the abyss
+    fn part1(cave: &Self::Input) -> Result<Answer> {
+        let mut map = cave.map();
+
+        let mut sand_count = 0;
+        while map.simulate_sand().is_some() {
+            sand_count += 1;
+        }
+
+        debug!("Sand starts falling into the abyss after {sand_count} units");
+        Ok(sand_count.into())
+    }
+
+    fn part2(cave: &Self::Input) -> Result<Answer> {
+        let mut map = cave.map();
+        map.add_floor();
+
+        let start = Point::new(500, 0);
+        let mut sand_count = 0;
+        loop {
+            sand_count += 1;
+            if map.simulate_sand() == Some(start) { break }
+        }
+
+        debug!("Finished after {sand_count} iterations");
+        Ok(sand_count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    // Each step of a path either moves horizontally or vertically
+    fn rock_path() -> impl Strategy<Value = Vec<Point<usize>>> {
+        (400..600usize, 0..100usize, prop::collection::vec((any::<bool>(), 0..100usize), 1..6))
+            .prop_map(|(x, y, steps)| {
+                let mut path = vec![Point::new(x, y)];
+                for (horizontal, to) in steps {
+                    let last = path[path.len() - 1];
+                    path.push(if horizontal { Point::new(last.x - 50 + to, last.y) } else { Point::new(last.x, to) });
+                }
+                path
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "(([0-9]{1,3},[0-9]{1,3}( -> )?){1,3}\n){0,4}[0-9, >a-]{0,10}") {
+            prop_assert!(parse::error_points_into(&Day14::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(rock_paths in prop::collection::vec(rock_path(), 1..5)) {
+            let input: String = rock_paths.iter()
+                .map(|path| path.iter().map(|p| format!("{},{}", p.x, p.y)).collect::<Vec<_>>().join(" -> ") + "\n")
+                .collect();
+            let points = rock_paths.iter().flatten();
+            let width = points.clone().map(|p| p.x).max().unwrap();
+            let height = points.map(|p| p.y).max().unwrap();
+            prop_assert_eq!(Day14::parse(&input).unwrap(), Cave { rock_paths, width, height });
+        }
+    }
+}
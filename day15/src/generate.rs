@@ -0,0 +1,65 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use aoc_common::Point;
+use crate::Day15;
+
+// Search area of the real input
+const MAX_COORD: i32 = 4_000_000;
+
+fn sensor(sensor: Point, beacon: Point) -> String {
+    format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", sensor.x, sensor.y, beacon.x, beacon.y)
+}
+
+impl Generator for Day15 {
+    // Number of sensors, besides the four that leave the distress beacon uncovered
+    const DEFAULT_SIZE: usize = 20;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let distress = Point::new(rng.random_range(0..=MAX_COORD), rng.random_range(0..=MAX_COORD));
+
+        // Four sensors diagonally around the distress beacon cover the whole search area but the beacon itself:
+        // each of them covers its quarter of a square twice the size of the area, except for the center
+        let mut input = String::new();
+        for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+            let at = Point::new(distress.x + dx * MAX_COORD, distress.y + dy * MAX_COORD);
+            let beacon = Point::new(at.x - dx * (2 * MAX_COORD - 1), at.y);
+            input += &sensor(at, beacon);
+        }
+
+        // The rest of them are too far away to reach the distress beacon
+        for _ in 0..size {
+            let at = Point::new(rng.random_range(0..=MAX_COORD), rng.random_range(0..=MAX_COORD));
+            let range = rng.random_range(0..at.manhattan_distance(&distress).min(1_000_000));
+            let dx = rng.random_range(0..=range);
+            let dy = range - dx;
+            let beacon = Point::new(at.x + if rng.random_bool(0.5) { dx } else { -dx }, at.y + if rng.random_bool(0.5) { dy } else { -dy });
+            input += &sensor(at, beacon);
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::{Answer, Solver};
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_parsed() {
+        for seed in 0..10 {
+            let input = generate::<Day15>(seed, 10);
+            let sensors = Day15::parse(&input).unwrap();
+            assert_eq!(sensors.len(), 14);
+            assert!(!crate::is_demo(&sensors));
+        }
+    }
+
+    #[test]
+    fn finds_the_distress_beacon() {
+        let sensors = Day15::parse(&generate::<Day15>(1, 5)).unwrap();
+        let mut rng = aoc_common::generate::rng(1);
+        let distress = Point::new(rng.random_range(0..=MAX_COORD), rng.random_range(0..=MAX_COORD));
+        let frequency = 4_000_000 * distress.x as i128 + distress.y as i128;
+        assert_eq!(Day15::part2(&sensors).unwrap(), Answer::Number(frequency));
+    }
+}
@@ -0,0 +1,188 @@
+use std::{collections::HashSet, ops::Range};
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, Answer, ParseError, Point, Solver};
+use regex::Regex;
+
+mod generate;
+
+#[derive(Debug, PartialEq)]
+pub struct Sensor {
+    coord: Point,
+    beacon: Point,
+    range: i32,
+}
+
+impl Sensor {
+    fn from_line(line: &Line) -> Result<Self, ParseError> {
+        let (sensor, beacon) = parse_sensor_and_beacon_locations(line)?;
+        let range = sensor.manhattan_distance(&beacon);
+        Ok(Sensor { coord: sensor, beacon, range })
+    }
+
+    fn find_blackouts(&self, row: i32, max_x: i32) -> Range<i32> {
+        let row_to_sensor = (row - self.coord.y).abs();
+        let blackout_range_start = (self.coord.x - self.range + row_to_sensor).clamp(0, max_x);
+        let blackout_range_end = (self.coord.x + self.range - row_to_sensor).clamp(0, max_x);
+        blackout_range_start..blackout_range_end+1
+    }
+
+    // Unlike find_blackouts, does not limit the range to the search area
+    fn coverage(&self, row: i32) -> Option<Range<i32>> {
+        let row_to_sensor = (row - self.coord.y).abs();
+        if row_to_sensor > self.range { return None }
+        Some(self.coord.x - self.range + row_to_sensor..self.coord.x + self.range - row_to_sensor + 1)
+    }
+}
+
+fn parse_sensor_and_beacon_locations(line: &Line) -> Result<(Point, Point), ParseError> {
+    let re = Regex::new(r"x=(-?\d+), y=(-?\d+)").expect("Failed to compile the regex pattern");
+    let mut coordinates = re.captures_iter(line.text);
+
+    let sensor_captures = coordinates.next().ok_or_else(|| line.error(1, "sensor coordinates (x=2, y=18)"))?;
+    let sensor_x = parse_coordinate(line, &sensor_captures[1], "sensor's x coordinate")?;
+    let sensor_y = parse_coordinate(line, &sensor_captures[2], "sensor's y coordinate")?;
+
+    let beacon_captures = coordinates.next().ok_or_else(|| line.error_at_end("beacon coordinates (x=-2, y=15)"))?;
+    let beacon_x = parse_coordinate(line, &beacon_captures[1], "beacon's x coordinate")?;
+    let beacon_y = parse_coordinate(line, &beacon_captures[2], "beacon's y coordinate")?;
+
+    Ok((Point::new(sensor_x, sensor_y), Point::new(beacon_x, beacon_y)))
+}
+
+// Keeps the distances between the points (and the ranges around the sensors) within i32
+const MAX_COORDINATE: i32 = 1 << 28;
+
+fn parse_coordinate(line: &Line, part: &str, expected: &str) -> Result<i32, ParseError> {
+    let expected = format!("{} (up to {} away from 0)", expected, MAX_COORDINATE);
+    let coordinate: i32 = line.parse(part, expected.as_str())?;
+    if coordinate.abs() > MAX_COORDINATE {
+        return Err(line.error_at(part, expected));
+    }
+    Ok(coordinate)
+}
+
+// The demo uses a much smaller search area than the real input,
+// so we tell them apart by the sensors all being within the demo area
+fn is_demo(sensors: &[Sensor]) -> bool {
+    sensors.iter().all(|s| (0..=20).contains(&s.coord.x) && (0..=20).contains(&s.coord.y))
+}
+
+pub struct Day15;
+
+impl Solver for Day15 {
+    const DAY: u8 = 15;
+    type Input = Vec<Sensor>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let sensors: Result<Vec<_>, _> = parse::lines(input).map(|line| Sensor::from_line(&line)).collect();
+        Ok(sensors?)
+    }
+
+    // Count the positions in a single row where a beacon cannot be present
+    fn part1(sensors: &Self::Input) -> Result<Answer> {
+        let row = if is_demo(sensors) { 10 } else { 2_000_000 };
+
+        let mut covered_ranges: Vec<Range<i32>> = sensors.iter().filter_map(|s| s.coverage(row)).collect();
+        let covered = total_values_in_ranges(&mut covered_ranges);
+
+        let beacons_in_row = sensors.iter()
+            .filter(|s| s.beacon.y == row)
+            .map(|s| &s.beacon)
+            .collect::<HashSet<&Point>>()
+            .len() as i32;
+
+        let no_beacon_positions = covered - beacons_in_row;
+        debug!("Positions without a beacon in row {}: {}", row, no_beacon_positions);
+        Ok(no_beacon_positions.into())
+    }
+
+    fn part2(sensors: &Self::Input) -> Result<Answer> {
+        let max_coord = if is_demo(sensors) { 20 } else { 4_000_000 };
+        let total_possible_x = max_coord + 1;
+
+        for row in 0..=max_coord {
+            // Find all sensors that can reach the given row
+            let sensors_in_range: Vec<&Sensor> = sensors.iter().filter(|sensor| {
+                (sensor.coord.y - row).abs() < sensor.range
+            }).collect();
+
+            let mut blackout_ranges = Vec::default();
+            for sensor in sensors_in_range {
+                let blackout_range = sensor.find_blackouts(row, max_coord);
+                blackout_ranges.push(blackout_range);
+            }
+
+            let blackout_vals = total_values_in_ranges(&mut blackout_ranges);
+            if blackout_vals >= total_possible_x { continue }
+
+            debug!("Total blacked out values: {}", blackout_vals);
+
+            for col in 0..=max_coord {
+                if !is_in_range(col, &blackout_ranges) {
+                    debug!("Found coordinate for the beacon: {}, {}", col, row);
+                    let tuning_frequency = 4_000_000 * col as u128 + row as u128;
+                    debug!("Tuning frequency: {}", tuning_frequency);
+                    return Ok(tuning_frequency.into());
+                }
+            }
+        }
+
+        anyhow::bail!("Didn't find the beacon")
+    }
+}
+
+fn is_in_range(col: i32, blackout: &[Range<i32>]) -> bool {
+    blackout.iter().any(|r| r.contains(&col))
+}
+
+// Courtesy of ChatGPT
+fn total_values_in_ranges(ranges: &mut [std::ops::Range<i32>]) -> i32 {
+    ranges.sort_unstable_by_key(|a| a.start);
+
+    let mut current_range = ranges[0].clone();
+    let mut total_length = 0;
+
+    for range in &ranges[1..] {
+        if range.start <= current_range.end {
+            // Ranges overlap, merge them
+            current_range.end = range.end.max(current_range.end);
+        } else {
+            // Ranges do not overlap, add the current range's length to the total
+            total_length += current_range.end - current_range.start;
+            current_range = range.clone();
+        }
+    }
+
+    // Don't forget to add the final range's length
+    total_length += current_range.end - current_range.start;
+
+    total_length
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "(Sensor at x=-?[0-9]{1,11}, y=-?[0-9]{1,11}: closest beacon is at x=-?[0-9]{1,11}, y=-?[0-9]{1,11}\n){0,3}[a-z =,:0-9-]{0,12}") {
+            prop_assert!(parse::error_points_into(&Day15::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(coordinates in prop::collection::vec(prop::array::uniform4(-5_000_000..5_000_000i32), 0..10)) {
+            let input: String = coordinates.iter()
+                .map(|[x, y, bx, by]| format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", x, y, bx, by))
+                .collect();
+            let expected: Vec<Sensor> = coordinates.iter()
+                .map(|&[x, y, bx, by]| {
+                    let (coord, beacon) = (Point::new(x, y), Point::new(bx, by));
+                    Sensor { coord, beacon, range: coord.manhattan_distance(&beacon) }
+                })
+                .collect();
+            prop_assert_eq!(Day15::parse(&input).unwrap(), expected);
+        }
+    }
+}
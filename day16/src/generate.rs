@@ -0,0 +1,70 @@
+use std::collections::BTreeSet;
+use aoc_common::generate::{Generator, Rng, SliceRandom, StdRng};
+use crate::Day16;
+
+fn valve_names(rng: &mut StdRng, count: usize) -> Vec<String> {
+    let mut names = vec!["AA".to_string()];
+    let mut all: Vec<String> = ('A'..='Z')
+        .flat_map(|a| ('A'..='Z').map(move |b| format!("{}{}", a, b)))
+        .filter(|name| name != "AA")
+        .collect();
+    all.shuffle(rng);
+    names.extend(all.into_iter().take(count - 1));
+    names
+}
+
+impl Generator for Day16 {
+    // Number of valves, about a quarter of them have a working flow rate
+    const DEFAULT_SIZE: usize = 58;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let count = size.clamp(2, 26 * 26);
+        let names = valve_names(rng, count);
+
+        // A random tree connecting all the valves, plus a few more tunnels to make loops
+        let mut tunnels: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); count];
+        let mut connect = |a: usize, b: usize| {
+            if a != b {
+                tunnels[a].insert(b);
+                tunnels[b].insert(a);
+            }
+        };
+        for valve in 1..count {
+            connect(valve, rng.random_range(0..valve));
+        }
+        for _ in 0..count / 4 {
+            connect(rng.random_range(0..count), rng.random_range(0..count));
+        }
+
+        // The valve we start at (AA) is always broken
+        let mut working: Vec<usize> = (1..count).collect();
+        working.shuffle(rng);
+        working.truncate((count / 4).max(1));
+
+        let mut input = String::new();
+        for (valve, name) in names.iter().enumerate() {
+            let flow_rate = if working.contains(&valve) { rng.random_range(1..=25) } else { 0 };
+            let valves: Vec<&str> = tunnels[valve].iter().map(|&other| names[other].as_str()).collect();
+            let tunnels = if valves.len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+            input += &format!("Valve {} has flow rate={}; {} {}\n", name, flow_rate, tunnels, valves.join(", "));
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..5 {
+            let volcano = Day16::parse(&generate::<Day16>(seed, 12)).unwrap();
+            assert_eq!(volcano.valves.len(), 12);
+            Day16::part1(&volcano).unwrap();
+            Day16::part2(&volcano).unwrap();
+        }
+    }
+}
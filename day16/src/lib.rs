@@ -0,0 +1,310 @@
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet};
+use anyhow::{bail, Result};
+use aoc_common::parse::{self, Line, Lines};
+use aoc_common::{debug, trace, Answer, ParseError, Solver};
+use regex::Regex;
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Valve {
+    flow_rate: usize,
+    connections: Vec<String>,
+}
+
+impl Valve {
+    fn from_line(line: &Line) -> Result<(String, Self), ParseError> {
+        let re = Regex::new(r"Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (.*)").expect("regex init");
+        let valve_cap = re.captures(line.text)
+            .ok_or_else(|| line.error(1, "a valve (Valve AA has flow rate=0; tunnels lead to valves DD, II)"))?;
+        let name = valve_cap[1].to_string();
+        let flow_rate = line.parse(&valve_cap[2], "a flow rate")?;
+        let connections = valve_cap[3].split(", ").map(|s| s.to_string()).collect();
+        Ok((name, Valve { flow_rate, connections }))
+    }
+}
+#[derive(Debug, Default)]
+pub struct Volcano {
+    valves: HashMap<String, Valve>,
+    distance_between: HashMap<String, HashMap<String, usize>>,
+}
+
+#[derive(Debug)]
+struct Invariant {
+    walkers: Vec<Walker>,
+    unopened_valves: HashSet<String>,
+    minute: usize,
+    flow_per_min: usize,
+    released: usize,
+}
+
+#[derive(Debug, Clone)]
+struct Walker {
+    name: String,
+    current_cave: String,
+    target_cave: Option<String>,
+    steps_remaining: usize,
+}
+
+impl Volcano {
+    fn from_lines(lines: Lines) -> Result<Self, ParseError> {
+        let mut volcano = Volcano::default();
+        let mut valve_lines = Vec::new();
+        for line in lines {
+            let (name, valve) = Valve::from_line(&line)?;
+            valve_lines.push((line, name.clone()));
+            volcano.valves.insert(name, valve);
+        }
+
+        // Tunnels can lead to valves described further down, so they are checked once all are known
+        for (line, name) in valve_lines.iter() {
+            for tunnel in volcano.valves[name].connections.iter() {
+                if !volcano.valves.contains_key(tunnel) {
+                    let tunnel = line.text.rfind(tunnel.as_str()).map_or(line.text, |pos| &line.text[pos..]);
+                    return Err(line.error_at(tunnel, "a known valve"));
+                }
+            }
+        }
+        Ok(volcano)
+    }
+
+    fn calculate_distances_between_all_caves(&mut self) -> Result<()> {
+        for start in self.valves.keys() {
+            let distance_from_start = self.distance_between.entry(start.to_owned()).or_default();
+            distance_from_start.insert(start.to_owned(), 0);
+
+            while self.valves.len() != distance_from_start.len() {
+                // A pass that finds no new valves means the rest of them are not connected
+                let known_valves = distance_from_start.len();
+                for (name, valve) in self.valves.iter() {
+                    let known_distance_len = distance_from_start.get(name).unwrap_or(&usize::MAX);
+                    let dist_from_start = valve.connections
+                        .iter()
+                        .filter_map(|v| distance_from_start.get(v))
+                        .min();
+                    if let Some(&min_dist_from_start) = dist_from_start {
+                        let new_min_dist = min_dist_from_start + 1;
+                        if new_min_dist < *known_distance_len {
+                            distance_from_start.insert(name.to_owned(),  new_min_dist);
+                        }
+                    }
+                }
+                if distance_from_start.len() == known_valves {
+                    let unreachable = self.valves.keys().find(|name| !distance_from_start.contains_key(*name));
+                    bail!("no tunnels lead between valves {} and {}", start, unreachable.expect("some valves are unreachable"));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn find_best_release(&self, walker_names: &[&str], time_limit: usize) -> usize {
+        let mut best_release = 0;
+        let start = "AA".to_string();
+        let working_valves: HashSet<String> = self.valves.iter()
+            .filter(|(_,v)| v.flow_rate > 0)
+            .map(|(k,_)| k.to_owned())
+            .collect();
+
+        let walkers = walker_names.iter().map(|name| Walker {
+            name: name.to_string(),
+            current_cave: start.to_owned(),
+            target_cave: None,
+            steps_remaining: 0,
+        }).collect();
+
+        let start_invariant = Invariant {
+            walkers,
+            unopened_valves: working_valves,
+            minute: 1,
+            flow_per_min: 0,
+            released: 0,
+        };
+
+        self.walk_the_caves(start_invariant, &mut best_release, time_limit);
+        debug!("Best release value found: {best_release}");
+        best_release
+    }
+
+    fn walk_the_caves(&self, i: Invariant, best_release: &mut usize, time_limit: usize) {
+        if i.minute > time_limit { return }
+
+        let mut still_unopened = i.unopened_valves;
+        let mut next_flow = i.flow_per_min;
+
+        // First, check if there are any walkers that have reached their target on this step
+        let mut walkers = i.walkers;
+        for walker in walkers.iter_mut() {
+            if walker.target_cave.is_none() { continue }
+            if walker.steps_remaining == 0 {
+                walker.current_cave = walker.target_cave.take().expect("target cave");
+                still_unopened.remove(&walker.current_cave);
+                next_flow += self.valves.get(&walker.current_cave).expect("valve fetch").flow_rate;
+            }
+        }
+
+        let total_release = i.released + (time_limit - i.minute + 1) * next_flow;
+        if total_release > *best_release {
+            trace!("Best new path with total release of {} and current flow of {}", total_release, next_flow);
+            *best_release = total_release;
+        }
+
+        // Get the list of unopened valves and their flow rates, then only pick those we can reach
+        let remaining_minutes = time_limit - i.minute;
+        let mut unopened_with_rates = still_unopened.iter()
+            .map(|v| (v, self.valves.get(v).expect("valve").flow_rate))
+            .collect::<Vec<(&String, usize)>>();
+        unopened_with_rates.sort_by_key(|b| Reverse(b.1));
+        unopened_with_rates.truncate(remaining_minutes);
+
+        // Project the value of remaining unopened valves in remaining time to see if it makes sense to continue
+        let total_potential_value = unopened_with_rates.iter().enumerate().map(|(i,v)| (remaining_minutes - i) * v.1).sum::<usize>();
+        if total_potential_value + total_release < *best_release { return }
+
+        // Now, for the first walker than needs a target, we iterate over all unopened valves
+        // and generate invariants for each one. When those invariants get processed, the next
+        // function call will take care of iterating over other walkers without a target.
+        if !still_unopened.is_empty() {
+            let active_targets = walkers.iter().filter_map(|w| w.target_cave.clone()).collect::<HashSet<String>>();
+            let walkers_without_target = walkers.iter().filter(|w| w.target_cave.is_none());
+            for walker in walkers_without_target {
+                for target in unopened_with_rates.iter() {
+                    let target = target.0;
+                    if active_targets.contains(target) { continue }
+
+                    let distances_from_cur = self.distance_between.get(&walker.current_cave).expect("dist from cur");
+                    let dist_to_target = distances_from_cur.get(target).expect("dist to dest");
+                    let time_to_target = dist_to_target + 1;
+
+                    // Cannot take this step, it will take more than TIME_LIMIT min to finish
+                    if i.minute + time_to_target >= time_limit { continue }
+
+                    let mut new_walkers = walkers.clone();
+                    let walker = new_walkers.iter_mut().find(|w| w.name == walker.name).expect("walker");
+                    walker.target_cave = Some(target.to_owned());
+                    walker.steps_remaining = time_to_target;
+
+                    let next_step = Invariant {
+                        walkers: new_walkers,
+                        unopened_valves: still_unopened.clone(),
+                        minute: i.minute,
+                        flow_per_min: next_flow,
+                        released: i.released,
+                    };
+                    self.walk_the_caves(next_step, best_release, time_limit);
+                }
+            }
+        }
+
+        // Now we can take a step forward in time
+        let mut step_minutes = 1;
+
+        // Calculate the step by finding the walker with the lowest number of steps remaining
+        let still_walking = walkers.iter().any(|w| w.steps_remaining > 0);
+        if still_walking {
+            step_minutes = walkers.iter().filter(|w| w.steps_remaining > 0)
+                .map(|w| w.steps_remaining)
+                .min()
+                .expect("min steps remaining");
+
+            for walker in walkers.iter_mut() {
+                if walker.steps_remaining > 0 {
+                    walker.steps_remaining -= step_minutes;
+                }
+            }
+        }
+
+        let next_step = Invariant {
+            walkers,
+            unopened_valves: still_unopened,
+            minute: i.minute + step_minutes,
+            flow_per_min: next_flow,
+            released: i.released + i.flow_per_min + (step_minutes - 1) * next_flow,
+        };
+        self.walk_the_caves(next_step, best_release, time_limit);
+    }
+}
+
+pub struct Day16;
+
+impl Solver for Day16 {
+    const DAY: u8 = 16;
+    type Input = Volcano;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut volcano = Volcano::from_lines(parse::lines(input))?;
+        volcano.calculate_distances_between_all_caves()?;
+        Ok(volcano)
+    }
+
+    fn part1(volcano: &Self::Input) -> Result<Answer> {
+        Ok(volcano.find_best_release(&["human"], 30).into())
+    }
+
+    // Spend 4 minutes teaching an elephant to help us
+    fn part2(volcano: &Self::Input) -> Result<Answer> {
+        Ok(volcano.find_best_release(&["human", "elephant"], 26).into())
+    }
+}
+
+// Demo result: 1651
+//
+// Real data:
+// part1: 1488
+// part2: 2111
+//   Walkers: [
+//     Walker { name: "human", path: ["YL", "EA", "JU", "TI", "RH", "PK", "VM"] },
+//     Walker { name: "elephant", path: ["IR", "JT", "SQ", "IP", "YD", "UX"] },
+//   ]
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    // Valves named AA, AB, ... connected into a ring (so that all of them can be reached),
+    // plus some random tunnels between them
+    fn valves() -> impl Strategy<Value = Vec<(String, Valve)>> {
+        (2..10usize)
+            .prop_flat_map(|count| {
+                let flow_rates = prop::collection::vec(0..30usize, count);
+                let tunnels = prop::collection::vec(prop::collection::vec(0..count, 0..3), count);
+                (flow_rates, tunnels)
+            })
+            .prop_map(|(flow_rates, tunnels)| {
+                let count = flow_rates.len();
+                let name = |i: usize| format!("A{}", (b'A' + i as u8) as char);
+                flow_rates.into_iter().zip(tunnels).enumerate()
+                    .map(|(i, (flow_rate, tunnels))| {
+                        let mut connections = vec![name((i + 1) % count)];
+                        connections.extend(tunnels.into_iter().map(name));
+                        (name(i), Valve { flow_rate, connections })
+                    })
+                    .collect()
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "(Valve [A-C]{2} has flow rate=[0-9]{1,2}; tunnels? leads? to valves? ([A-C]{2}(, )?){1,3}\n){0,4}[A-Za-z0-9=;, ]{0,10}") {
+            prop_assert!(parse::error_points_into(&Day16::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(valves in valves()) {
+            let input: String = valves.iter()
+                .map(|(name, valve)| {
+                    let tunnels = match valve.connections.len() {
+                        1 => "tunnel leads to valve",
+                        _ => "tunnels lead to valves",
+                    };
+                    format!("Valve {} has flow rate={}; {} {}\n", name, valve.flow_rate, tunnels, valve.connections.join(", "))
+                })
+                .collect();
+
+            let volcano = Day16::parse(&input).unwrap();
+            prop_assert_eq!(volcano.valves, valves.into_iter().collect::<HashMap<_, _>>());
+        }
+    }
+}
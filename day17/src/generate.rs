@@ -0,0 +1,28 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day17;
+
+impl Generator for Day17 {
+    // Number of jets
+    const DEFAULT_SIZE: usize = 10091;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size.max(1)).map(|_| if rng.random_bool(0.5) { '<' } else { '>' }).chain(Some('\n')).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..3 {
+            let jets = Day17::parse(&generate::<Day17>(seed, 40)).unwrap();
+            assert_eq!(jets.len(), 40);
+            Day17::part1(&jets).unwrap();
+            Day17::part2(&jets).unwrap();
+        }
+    }
+}
@@ -0,0 +1,319 @@
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, Answer, ParseError, Solver};
+
+mod generate;
+
+#[derive(Debug, PartialEq)]
+pub enum Jet {
+    Left,
+    Right,
+}
+
+impl Jet {
+    fn from_char(line: &Line, column: usize, c: char) -> Result<Self, ParseError> {
+        match c {
+            '<' => Ok(Jet::Left),
+            '>' => Ok(Jet::Right),
+            _ => Err(line.error(column, "a jet direction (< or >)")),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Rock {
+    shape: Vec<Vec<char>>,
+    width: usize,
+    height: usize,
+}
+
+#[derive(Debug)]
+struct FallingRock {
+    rock: Rock,
+    col: usize,
+    row: usize,
+}
+
+#[derive(Debug)]
+struct Chamber {
+    field: Vec<Vec<char>>,
+    highest_point: usize,
+    rock: Option<FallingRock>,
+}
+
+impl Chamber {
+    fn new() -> Self {
+        Chamber {
+            field: vec![vec!['.'; 7]; 10],
+            highest_point: 0,
+            rock: None
+        }
+    }
+
+    fn draw_rock(&mut self, c: char) {
+        let falling_rock = self.rock.as_ref().unwrap();
+        let rock = &falling_rock.rock;
+        for row in 0..rock.height {
+            for col in 0..rock.width {
+                if rock.shape[rock.height - row - 1][col] != '.' {
+                    self.field[falling_rock.row + row][falling_rock.col + col] = c;
+                }
+            }
+        }
+    }
+
+    fn drop_rock(&mut self, rock: &Rock) {
+        let falling_rock = FallingRock {
+            rock: rock.clone(),
+            col: 2,
+            row: self.highest_point + 3,
+        };
+        self.rock = Some(falling_rock);
+        self.draw_rock('@');
+    }
+
+    fn apply_jet(&mut self, jet: &Jet) {
+        // erase the rock
+        self.draw_rock('.');
+
+        match jet {
+            Jet::Left => {
+                self.maybe_move_rock(-1)
+            },
+            Jet::Right => {
+                self.maybe_move_rock(1)
+            },
+        }
+
+        // bring the rock back
+        self.draw_rock('@');
+    }
+
+    fn maybe_move_rock(&mut self, shift: i32) {
+        if let Some(falling_rock) = self.rock.as_ref() {
+            let new_col = falling_rock.col as i32 + shift;
+            if !self.no_collisions(new_col, falling_rock.row as i32) { return }
+            self.rock.as_mut().unwrap().col = new_col as usize;
+        }
+    }
+
+    fn maybe_move_rock_down(&mut self) {
+        if self.rock.is_none() { return }
+        // erase the rock
+        self.draw_rock('.');
+
+        let falling_rock = self.rock.as_ref().unwrap();
+        let height = falling_rock.rock.height;
+        let rock_row = falling_rock.row;
+
+        if self.no_collisions(falling_rock.col as i32, rock_row as i32 - 1) {
+            self.rock.as_mut().unwrap().row -= 1;
+            self.draw_rock('@'); // bring the rock back
+            return;
+        }
+
+        self.draw_rock('#'); // rock has come to rest
+        if self.highest_point < rock_row + height {
+            self.highest_point = rock_row + height;
+            let desired_field_height = self.highest_point + 10;
+            let need_rows = desired_field_height - self.field.len();
+            for _ in 0..need_rows { self.field.push(vec!['.';7]); }
+        }
+        self.rock = None;
+    }
+
+    fn no_collisions(&self, new_col: i32, new_row: i32) -> bool {
+        if let Some(falling_rock) = self.rock.as_ref() {
+            let rock = &falling_rock.rock;
+            if new_col < 0 || new_col + rock.width as i32 > 7 || new_row < 0 {
+                return false;
+            }
+
+            for row in 0..rock.height {
+                for col in 0..rock.width {
+                    if rock.shape[rock.height - row - 1][col] == '.' { continue }
+                    if self.field[new_row as usize + row][new_col as usize + col] != '.' {
+                        return false;
+                    }
+                }
+            }
+        }
+        true
+    }
+}
+
+fn rock_shapes() -> Vec<Rock> {
+    vec![
+        Rock {
+            width: 4,
+            height: 1,
+            shape: vec![
+                vec!['@','@','@','@']
+            ]
+        },
+        Rock {
+            width: 3,
+            height: 3,
+            shape: vec![
+                vec!['.','@','.'],
+                vec!['@','@','@'],
+                vec!['.','@','.'],
+            ]
+        },
+        Rock {
+            width: 3,
+            height: 3,
+            shape: vec![
+                vec!['.','.','@'],
+                vec!['.','.','@'],
+                vec!['@','@','@'],
+            ]
+        },
+        Rock {
+            width: 1,
+            height: 4,
+            shape: vec![
+                vec!['@'],
+                vec!['@'],
+                vec!['@'],
+                vec!['@'],
+            ]
+        },
+        Rock {
+            width: 2,
+            height: 2,
+            shape: vec![
+                vec!['@','@'],
+                vec!['@','@'],
+            ]
+        },
+    ]
+}
+
+const STEP1_ROCK_LIMIT: usize = 2022;
+
+// Drops 2022 rocks into the chamber, returns the chamber, the number of rocks dropped
+// and the increase of the tower height after each one of them
+fn drop_rocks(jets: &[Jet]) -> (Chamber, usize, Vec<usize>) {
+    let rocks = rock_shapes();
+    let mut chamber = Chamber::new();
+
+    let mut rocks_count = 0;
+    let mut rock_idx = 0;
+    let mut jet_idx = 0;
+    let mut last_highest_point = 0;
+    let mut height_steps: Vec<usize> = Vec::new();
+
+    loop {
+        // If there is no active rock, drop another one
+        if chamber.rock.is_none() {
+            chamber.drop_rock(&rocks[rock_idx]);
+            rocks_count += 1;
+            rock_idx = (rock_idx + 1) % rocks.len();
+            let step = chamber.highest_point - last_highest_point;
+            height_steps.push(step);
+            last_highest_point = chamber.highest_point;
+
+            if rocks_count > STEP1_ROCK_LIMIT {
+                debug!("Highest point after 2022 steps: {}", chamber.highest_point);
+                break;
+            }
+        }
+
+        // Apply jet to the falling rock, potentially moving it
+        let jet = &jets[jet_idx];
+        chamber.apply_jet(jet);
+        jet_idx = (jet_idx + 1) % jets.len();
+
+        // Try moving the rock down
+        chamber.maybe_move_rock_down();
+    }
+
+    (chamber, rocks_count, height_steps)
+}
+
+pub struct Day17;
+
+impl Solver for Day17 {
+    const DAY: u8 = 17;
+    type Input = Vec<Jet>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let line = parse::lines(input).next_line("jet directions")?;
+        let jets: Result<Vec<_>, _> = line.text.chars().enumerate().map(|(i, c)| Jet::from_char(&line, i + 1, c)).collect();
+        Ok(jets?)
+    }
+
+    fn part1(jets: &Self::Input) -> Result<Answer> {
+        let (chamber, _, _) = drop_rocks(jets);
+        Ok(chamber.highest_point.into())
+    }
+
+    // Simulating a trillion rocks is not an option, so we find a repeating pattern
+    // in the tower height increments and extrapolate
+    fn part2(jets: &Self::Input) -> Result<Answer> {
+        let (chamber, rocks_count, mut height_steps) = drop_rocks(jets);
+
+        let pattern = find_step_pattern(&mut height_steps);
+        let pattern_height: usize = pattern.iter().sum();
+        debug!("Pattern of {} items (total height: {}): {:?}", pattern.len(), pattern_height, pattern);
+
+        const STEP2_ROCK_LIMIT: usize = 1_000_000_000_000;
+        let remaining_rocks = STEP2_ROCK_LIMIT - rocks_count;
+        debug!("Remaining rocks: {}", remaining_rocks);
+
+        let remaining_pattern_count = remaining_rocks / pattern.len();
+        debug!("Need to repeat the pattern {} times", remaining_pattern_count);
+
+        let tail_len = remaining_rocks % pattern.len();
+        debug!("After repeating the pattern, we will need {} more rocks", tail_len);
+
+        let tail_height: usize = pattern[0..=tail_len].iter().sum();
+        debug!("Height of the additional {} rocks ({:?}) will be {}", tail_len, &pattern[0..=tail_len], tail_height);
+
+        let total_height = chamber.highest_point + remaining_pattern_count * pattern_height + tail_height;
+        debug!("Total height: {}", total_height);
+        Ok(total_height.into())
+    }
+}
+
+fn find_step_pattern(steps: &mut [usize]) -> &[usize] {
+    steps.reverse();
+
+    const CHECK_LEN: usize = 16;
+    let beginning_pattern = &steps[0..CHECK_LEN];
+    for i in CHECK_LEN..steps.len() {
+        let subset = &steps[i..i+CHECK_LEN];
+        if subset == beginning_pattern {
+            let final_pattern = &mut steps[0..i];
+            final_pattern.reverse();
+            return final_pattern;
+        }
+    }
+
+    panic!("Didn't find a pattern");
+}
+
+// Real checks
+// 1537175792497 - too high
+// 1537175792495 - correct
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "[<>]{0,20}[<>a ]{0,3}\n?[<>]{0,3}") {
+            prop_assert!(parse::error_points_into(&Day17::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(jets in prop::collection::vec(any::<bool>(), 1..50)) {
+            let input: String = jets.iter().map(|&right| if right { '>' } else { '<' }).collect();
+            let expected: Vec<Jet> = jets.iter().map(|&right| if right { Jet::Right } else { Jet::Left }).collect();
+            prop_assert_eq!(Day17::parse(&(input + "\n")).unwrap(), expected);
+        }
+    }
+}
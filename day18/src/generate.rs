@@ -0,0 +1,90 @@
+use std::collections::HashSet;
+use aoc_common::generate::{Generator, IndexedRandom, StdRng};
+use aoc_common::{Answer, Point3};
+use crate::{Cube, Day18, MAX_COORDINATE};
+
+impl Generator for Day18 {
+    // Number of cubes in the droplet
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // The droplet grows from its center by adding cubes next to the ones it already has,
+        // until it has the requested size or fills the whole space
+        let center = MAX_COORDINATE / 2;
+        let mut cubes = vec![Point3::new(center, center, center)];
+        let mut known: HashSet<Point3<usize>> = cubes.iter().copied().collect();
+        let capacity = (MAX_COORDINATE + 1).pow(3);
+
+        while cubes.len() < size.min(capacity) {
+            let cube = cubes.choose(rng).unwrap();
+            let (x, y, z) = (cube.x as isize, cube.y as isize, cube.z as isize);
+            let (dx, dy, dz) = *[(1, 0, 0), (-1, 0, 0), (0, 1, 0), (0, -1, 0), (0, 0, 1), (0, 0, -1)].choose(rng).unwrap();
+            let range = 0..=MAX_COORDINATE as isize;
+            if !range.contains(&(x + dx)) || !range.contains(&(y + dy)) || !range.contains(&(z + dz)) {
+                continue;
+            }
+
+            let next = Point3::new((x + dx) as usize, (y + dy) as usize, (z + dz) as usize);
+            if known.insert(next) {
+                cubes.push(next);
+            }
+        }
+
+        cubes.iter().map(|cube| format!("{},{},{}\n", cube.x, cube.y, cube.z)).collect()
+    }
+
+    // Counts the sides of every cube that touch no other cube, or for the outside surface only the
+    // ones that touch the air filling the droplet's bounding box from one of its corners
+    fn reference(cubes: &Vec<Cube>, part: u8) -> Option<Answer> {
+        let droplet: HashSet<Cube> = cubes.iter().copied().collect();
+        let open_sides = |open: &dyn Fn(&Cube) -> bool| -> usize {
+            droplet.iter().flat_map(|cube| cube.neighbors()).filter(|side| open(side)).count()
+        };
+        if part == 1 {
+            return Some(open_sides(&|side| !droplet.contains(side)).into());
+        }
+
+        // The parsed cubes start at 2, so there is always room for air below them
+        let (min, max) = droplet.iter().fold((usize::MAX, 0), |(min, max), cube| {
+            (min.min(cube.x).min(cube.y).min(cube.z), max.max(cube.x).max(cube.y).max(cube.z))
+        });
+        let in_box = |cube: &Cube| [cube.x, cube.y, cube.z].iter().all(|c| (min - 1..=max + 1).contains(c));
+        let corner = Point3::new(min - 1, min - 1, min - 1);
+        let mut outside = HashSet::from([corner]);
+        let mut to_visit = vec![corner];
+        while let Some(air) = to_visit.pop() {
+            for next in air.neighbors() {
+                if in_box(&next) && !droplet.contains(&next) && outside.insert(next) {
+                    to_visit.push(next);
+                }
+            }
+        }
+        Some(open_sides(&|side| outside.contains(side)).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let cubes = Day18::parse(&generate::<Day18>(seed, 200)).unwrap();
+            assert_eq!(cubes.len(), 200);
+            Day18::part1(&cubes).unwrap();
+            Day18::part2(&cubes).unwrap();
+        }
+    }
+
+    #[test]
+    fn generated_inputs_match_the_reference() {
+        for seed in 0..10 {
+            let cubes = Day18::parse(&generate::<Day18>(seed, 200)).unwrap();
+            assert_eq!(Day18::reference(&cubes, 1), Some(Day18::part1(&cubes).unwrap()));
+            assert_eq!(Day18::reference(&cubes, 2), Some(Day18::part2(&cubes).unwrap()));
+        }
+    }
+}
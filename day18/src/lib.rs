@@ -0,0 +1,129 @@
+use std::collections::HashSet;
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, Answer, ParseError, Point3, Solver};
+
+mod generate;
+
+#[derive(Clone, PartialEq)]
+enum Vol {
+    Air,
+    Rock,
+    Water
+}
+
+const VOL_LIMIT: usize = 25;
+
+type Volume = Vec<Vec<Vec<Vol>>>;
+
+// Cube coordinates, shifted away from zero to leave room for the water around the droplet
+type Cube = Point3<usize>;
+
+fn build_volume(cubes: &[Cube]) -> Volume {
+    let mut volume = vec![vec![vec![Vol::Air;VOL_LIMIT+1];VOL_LIMIT+1];VOL_LIMIT+1];
+    for cube in cubes {
+        volume[cube.x][cube.y][cube.z] = Vol::Rock;
+    }
+    volume
+}
+
+// Counts the sides of the given cubes that touch a volume of the given kind
+fn count_sides(cubes: impl Iterator<Item = Cube>, volume: &Volume, kind: Vol) -> usize {
+    let mut total_surface = 0;
+    for cube in cubes {
+        for side in cube.neighbors() {
+            if volume[side.x][side.y][side.z] == kind { total_surface += 1 }
+        }
+    }
+    total_surface
+}
+
+// The droplet has to fit into the volume with a layer of water all around it
+const MAX_COORDINATE: usize = VOL_LIMIT - 3;
+
+fn parse_coordinate(line: &Line, part: Option<&str>, expected: &str) -> Result<usize, ParseError> {
+    let expected = format!("{} (0-{})", expected, MAX_COORDINATE);
+    let coordinate = line.parse_some(part, expected.as_str())?;
+    match part {
+        Some(part) if coordinate > MAX_COORDINATE => Err(line.error_at(part, expected)),
+        _ => Ok(coordinate),
+    }
+}
+
+pub struct Day18;
+
+impl Solver for Day18 {
+    const DAY: u8 = 18;
+    type Input = Vec<Cube>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut cubes = Vec::new();
+        for line in parse::lines(input) {
+            let mut coords = line.text.split(',');
+            let x = parse_coordinate(&line, coords.next(), "an x coordinate")?;
+            let y = parse_coordinate(&line, coords.next(), "a y coordinate")?;
+            let z = parse_coordinate(&line, coords.next(), "a z coordinate")?;
+            cubes.push(Point3::new(x + 2, y + 2, z + 2));
+        }
+        Ok(cubes)
+    }
+
+    // Surface area, including the air pockets trapped inside the droplet
+    fn part1(cubes: &Self::Input) -> Result<Answer> {
+        let volume = build_volume(cubes);
+        let total_surface = count_sides(cubes.iter().copied(), &volume, Vol::Air);
+
+        debug!("Total surface: {}", total_surface);
+        Ok(total_surface.into())
+    }
+
+    fn part2(cubes: &Self::Input) -> Result<Answer> {
+        let mut volume = build_volume(cubes);
+
+        // Fill it with water starting with the origin
+        let mut to_fill = vec![Point3::new(1, 1, 1)];
+        let mut external_rocks = HashSet::new();
+        while let Some(cube) = to_fill.pop() {
+            let Point3 { x, y, z } = cube;
+            if x < 1 || y < 1 || z < 1 || x > VOL_LIMIT || y > VOL_LIMIT || z > VOL_LIMIT { continue }
+            match volume[x][y][z] {
+                Vol::Water => continue,
+                Vol::Rock => { external_rocks.insert(cube); },
+                Vol::Air => {
+                    volume[x][y][z] = Vol::Water;
+                    to_fill.extend(cube.neighbors());
+                }
+            }
+        }
+
+        let total_surface = count_sides(external_rocks.into_iter(), &volume, Vol::Water);
+
+        debug!("Total surface: {}", total_surface);
+        Ok(total_surface.into())
+    }
+}
+
+// Real checks:
+// 2069 - too low
+// 2072 - correct
+// 2173 - too high
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([0-9]{1,2},[0-9]{1,2},[0-9]{1,2}\n){0,4}[0-9, a]{0,8}") {
+            prop_assert!(parse::error_points_into(&Day18::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(cubes in prop::collection::vec(prop::array::uniform3(0..=MAX_COORDINATE), 0..20)) {
+            let input: String = cubes.iter().map(|[x, y, z]| format!("{},{},{}\n", x, y, z)).collect();
+            let expected: Vec<Cube> = cubes.iter().map(|&[x, y, z]| Point3::new(x + 2, y + 2, z + 2)).collect();
+            prop_assert_eq!(Day18::parse(&input).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day19;
+
+impl Generator for Day19 {
+    // Number of blueprints
+    const DEFAULT_SIZE: usize = 30;
+
+    // Costs are in the same ranges as in the real input
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (1..=size)
+            .map(|id| format!(
+                "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                id,
+                rng.random_range(2..=4),
+                rng.random_range(2..=4),
+                rng.random_range(2..=4),
+                rng.random_range(5..=20),
+                rng.random_range(2..=4),
+                rng.random_range(5..=20),
+            ))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        let blueprints = Day19::parse(&generate::<Day19>(1, 2)).unwrap();
+        assert_eq!(blueprints.len(), 2);
+        Day19::part1(&blueprints).unwrap();
+    }
+}
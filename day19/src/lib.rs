@@ -0,0 +1,262 @@
+use std::{collections::HashSet, time::Instant, cmp::max};
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, trace, Answer, ParseError, Solver};
+use regex::Regex;
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq)]
+struct RobotCost {
+    ore: usize,
+    clay: usize,
+    obsidian: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Blueprint {
+    id: usize,
+
+    ore_bot: RobotCost,
+    clay_bot: RobotCost,
+    obsidian_bot: RobotCost,
+    geode_bot: RobotCost,
+
+    max_ore_needed: usize,
+    max_clay_needed: usize,
+    max_obsidian_needed: usize,
+}
+
+enum BuildPlan {
+    Ore,
+    Clay,
+    Obsidian,
+    Geode,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Invariant {
+    minute: usize,
+    ore_stock: usize,
+    clay_stock: usize,
+    obsidian_stock: usize,
+    geodes_stock: usize,
+    ore_bots: usize,
+    clay_bots: usize,
+    obsidian_bots: usize,
+    geode_bots: usize,
+}
+
+impl Invariant {
+    fn can_build(&self, bot: &RobotCost) -> bool {
+        self.ore_stock >= bot.ore && self.clay_stock >= bot.clay && self.obsidian_stock >= bot.obsidian
+    }
+
+    fn build_step(&mut self, plan: BuildPlan, cost: &RobotCost) -> Invariant {
+        let mut next_step = self.collect_resources();
+        next_step.execute_plan(cost);
+        match plan {
+            BuildPlan::Ore => next_step.ore_bots += 1,
+            BuildPlan::Clay => next_step.clay_bots += 1,
+            BuildPlan::Obsidian => next_step.obsidian_bots += 1,
+            BuildPlan::Geode => next_step.geode_bots += 1,
+        }
+        next_step
+    }
+
+    fn execute_plan(&mut self, cost: &RobotCost) {
+        self.ore_stock -= cost.ore;
+        self.clay_stock -= cost.clay;
+        self.obsidian_stock -= cost.obsidian;
+    }
+
+    fn collect_resources(&mut self) -> Invariant {
+        Invariant {
+            minute: self.minute + 1,
+            ore_stock: self.ore_stock + self.ore_bots,
+            clay_stock: self.clay_stock + self.clay_bots,
+            obsidian_stock: self.obsidian_stock + self.obsidian_bots,
+            geodes_stock: self.geodes_stock + self.geode_bots,
+            ore_bots: self.ore_bots,
+            clay_bots: self.clay_bots,
+            obsidian_bots: self.obsidian_bots,
+            geode_bots: self.geode_bots,
+        }
+    }
+
+    fn optimistic_max_geodes(&self, minutes_remaining: usize) -> usize {
+        self.geodes_stock + minutes_remaining * self.geode_bots + minutes_remaining * (minutes_remaining.saturating_sub(1))/2
+    }
+}
+
+impl Blueprint {
+    fn from_line(line: &Line) -> Result<Self, ParseError> {
+        // Blueprint 1:
+        // Each ore robot costs 4 ore.
+        // Each clay robot costs 2 ore.
+        // Each obsidian robot costs 3 ore and 14 clay.
+        // Each geode robot costs 2 ore and 7 obsidian.
+        let re = Regex::new(r"\d+").unwrap();
+        let mut matches = re.find_iter(line.text);
+        let mut next_number = |expected: &str| -> Result<usize, ParseError> {
+            let number = matches.next().ok_or_else(|| line.error_at_end(expected))?;
+            line.parse(number.as_str(), expected)
+        };
+
+        let id = next_number("a blueprint id")?;
+        let ore_bot_ore = next_number("ore robot cost in ore")?;
+        let clay_bot_ore = next_number("clay robot cost in ore")?;
+        let obsidian_bot_ore = next_number("obsidian robot cost in ore")?;
+        let obsidian_bot_clay = next_number("obsidian robot cost in clay")?;
+        let geode_bot_ore = next_number("geode robot cost in ore")?;
+        let geode_bot_obsidian = next_number("geode robot cost in obsidian")?;
+
+        Ok(Blueprint {
+            id,
+
+            ore_bot: RobotCost { ore: ore_bot_ore, clay: 0, obsidian: 0 },
+            clay_bot: RobotCost { ore: clay_bot_ore, clay: 0, obsidian: 0 },
+            obsidian_bot: RobotCost { ore: obsidian_bot_ore, clay: obsidian_bot_clay, obsidian: 0 },
+            geode_bot: RobotCost { ore: geode_bot_ore, clay: 0, obsidian: geode_bot_obsidian },
+
+            max_ore_needed: max(ore_bot_ore, max(clay_bot_ore, obsidian_bot_ore)),
+            max_clay_needed: obsidian_bot_clay,
+            max_obsidian_needed: geode_bot_obsidian,
+        })
+    }
+
+    // Recursively look for an optimal plan to produce the largest number of geodes
+    fn find_optimal_plan(&self, max_minutes: usize) -> usize {
+        let mut best_result = 0;
+
+        let first_step = Invariant {
+            minute: 0,
+            ore_stock: 0,
+            clay_stock: 0,
+            obsidian_stock: 0,
+            geodes_stock: 0,
+            ore_bots: 1,
+            clay_bots: 0,
+            obsidian_bots: 0,
+            geode_bots: 0
+        };
+
+        let mut steps_to_check = vec![first_step];
+        let mut seen_steps = HashSet::new();
+
+        while let Some(mut step) = steps_to_check.pop() {
+            if seen_steps.contains(&step) { continue }
+            if step.minute > max_minutes { continue }
+
+            // Check how many geodes we may produce in the remaining minutes if we were to build more bots
+            let minutes_remaining = max_minutes - step.minute;
+            if step.optimistic_max_geodes(minutes_remaining) <= best_result { continue }
+
+            if step.geodes_stock > best_result {
+                best_result = step.geodes_stock;
+            }
+
+            steps_to_check.push(step.collect_resources());
+
+            if minutes_remaining >= 3 && step.can_build(&self.ore_bot) && self.max_ore_needed + step.ore_bots > step.ore_stock {
+                steps_to_check.push(step.build_step(BuildPlan::Ore, &self.ore_bot));
+            }
+            if minutes_remaining >= 4 && step.can_build(&self.clay_bot) && self.max_clay_needed + step.clay_bots > step.clay_stock {
+                steps_to_check.push(step.build_step(BuildPlan::Clay, &self.clay_bot));
+            }
+            if minutes_remaining >= 3 && step.can_build(&self.obsidian_bot) && self.max_obsidian_needed + step.obsidian_bots > step.obsidian_stock {
+                steps_to_check.push(step.build_step(BuildPlan::Obsidian, &self.obsidian_bot));
+            }
+            if minutes_remaining >= 2 && step.can_build(&self.geode_bot) {
+                steps_to_check.push(step.build_step(BuildPlan::Geode, &self.geode_bot));
+            }
+
+            seen_steps.insert(step.to_owned());
+        }
+
+        best_result
+    }
+}
+
+// Runs the simulation for each blueprint and returns the max number of geodes collected
+fn simulate_blueprints<'a>(blueprints: impl Iterator<Item = &'a Blueprint>, max_minutes: usize) -> Vec<(usize, usize)> {
+    let mut results = Vec::new();
+    for bp in blueprints {
+        trace!("Simulating blueprint {}", bp.id);
+        let time = Instant::now();
+        let max_geodes_collected = bp.find_optimal_plan(max_minutes);
+        let elapsed_ms = time.elapsed().as_nanos() as f64 / 1_000_000.0;
+
+        debug!("Max geodes {} collected for blueprint {} in {} ms.",
+            max_geodes_collected, bp.id, elapsed_ms);
+        results.push((bp.id, max_geodes_collected));
+    }
+    results
+}
+
+pub struct Day19;
+
+impl Solver for Day19 {
+    const DAY: u8 = 19;
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let blueprints: Result<Vec<_>, _> = parse::lines(input).map(|line| Blueprint::from_line(&line)).collect();
+        Ok(blueprints?)
+    }
+
+    // Sum of quality levels of all blueprints in 24 minutes
+    fn part1(blueprints: &Self::Input) -> Result<Answer> {
+        let quality_levels: usize = simulate_blueprints(blueprints.iter(), 24).iter()
+            .map(|(id, geodes)| id * geodes)
+            .sum();
+
+        debug!("Result: {}", quality_levels);
+        Ok(quality_levels.into())
+    }
+
+    // Only the first three blueprints survived, but we have 32 minutes now
+    fn part2(blueprints: &Self::Input) -> Result<Answer> {
+        let product_of_results: usize = simulate_blueprints(blueprints.iter().take(3), 32).iter()
+            .map(|(_, geodes)| geodes)
+            .product();
+
+        debug!("Result: {}", product_of_results);
+        Ok(product_of_results.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "(Blueprint [0-9]: Each ore robot costs [0-9] ore.( Each [a-z]{3,8} robot costs [0-9]{1,2} [a-z]{3,8}.){0,3}\n){0,3}[A-Za-z0-9:. ]{0,10}") {
+            prop_assert!(parse::error_points_into(&Day19::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(costs in prop::collection::vec(prop::array::uniform6(1..20usize), 0..5)) {
+            let input: String = costs.iter().enumerate()
+                .map(|(i, [ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian])| format!(
+                    "Blueprint {}: Each ore robot costs {} ore. Each clay robot costs {} ore. Each obsidian robot costs {} ore and {} clay. Each geode robot costs {} ore and {} obsidian.\n",
+                    i + 1, ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian
+                ))
+                .collect();
+
+            let blueprints = Day19::parse(&input).unwrap();
+            prop_assert_eq!(blueprints.len(), costs.len());
+            for (i, (blueprint, costs)) in blueprints.iter().zip(costs.iter()).enumerate() {
+                let [ore, clay, obsidian_ore, obsidian_clay, geode_ore, geode_obsidian] = *costs;
+                prop_assert_eq!(blueprint.id, i + 1);
+                prop_assert_eq!(&blueprint.ore_bot, &RobotCost { ore, clay: 0, obsidian: 0 });
+                prop_assert_eq!(&blueprint.clay_bot, &RobotCost { ore: clay, clay: 0, obsidian: 0 });
+                prop_assert_eq!(&blueprint.obsidian_bot, &RobotCost { ore: obsidian_ore, clay: obsidian_clay, obsidian: 0 });
+                prop_assert_eq!(&blueprint.geode_bot, &RobotCost { ore: geode_ore, clay: 0, obsidian: geode_obsidian });
+                prop_assert_eq!(blueprint.max_ore_needed, ore.max(clay).max(obsidian_ore));
+            }
+        }
+    }
+}
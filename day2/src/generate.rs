@@ -0,0 +1,30 @@
+use aoc_common::generate::{Generator, IndexedRandom, StdRng};
+use crate::Day2;
+
+impl Generator for Day2 {
+    // Number of rounds
+    const DEFAULT_SIZE: usize = 2500;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{} {}\n", ["A", "B", "C"].choose(rng).unwrap(), ["X", "Y", "Z"].choose(rng).unwrap()))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let rounds = Day2::parse(&generate::<Day2>(seed, 20)).unwrap();
+            assert_eq!(rounds.len(), 20);
+            Day2::part1(&rounds).unwrap();
+            Day2::part2(&rounds).unwrap();
+        }
+    }
+}
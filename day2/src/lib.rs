@@ -0,0 +1,163 @@
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, Answer, ParseError, Solver};
+
+mod generate;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Move {
+  Rock = 1,
+  Paper = 2,
+  Scissors = 3,
+}
+
+// The second column means different things in both parts, so it is only decoded there
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Column {
+  X,
+  Y,
+  Z,
+}
+
+fn parse_move(line: &Line, player_move: &str) -> Result<Move, ParseError> {
+  match player_move {
+    "A" => Ok(Move::Rock),
+    "B" => Ok(Move::Paper),
+    "C" => Ok(Move::Scissors),
+    _ => Err(line.error_at(player_move, "a move (A, B or C)")),
+  }
+}
+
+fn parse_second_column(line: &Line, column: &str) -> Result<Column, ParseError> {
+  match column {
+    "X" => Ok(Column::X),
+    "Y" => Ok(Column::Y),
+    "Z" => Ok(Column::Z),
+    _ => Err(line.error_at(column, "X, Y or Z")),
+  }
+}
+
+// Part 1 reading of the second column: X = rock, Y = paper, Z = scissors
+fn response_move(column: Column) -> Move {
+  match column {
+    Column::X => Move::Rock,
+    Column::Y => Move::Paper,
+    Column::Z => Move::Scissors,
+  }
+}
+
+// 0 = player 1 wins
+// 3 = tie
+// 6 = player 2 wins
+fn calculate_outcome(player1_move: Move, player2_move: Move) -> u32 {
+  match (player1_move, player2_move) {
+    (Move::Rock, Move::Rock) => 3,
+    (Move::Paper, Move::Paper) => 3,
+    (Move::Scissors, Move::Scissors) => 3,
+
+    (Move::Rock, Move::Paper) => 6,
+    (Move::Paper, Move::Scissors) => 6,
+    (Move::Scissors, Move::Rock) => 6,
+
+    (Move::Rock, Move::Scissors) => 0,
+    (Move::Paper, Move::Rock) => 0,
+    (Move::Scissors, Move::Paper) => 0,
+  }
+}
+
+fn pick_player2_move(player1_move: Move, expected_outcome: Column) -> Move {
+  match (player1_move, expected_outcome) {
+    // expected_outcome = X => Player 2 loses
+    (Move::Rock, Column::X) => Move::Scissors,
+    (Move::Paper, Column::X) => Move::Rock,
+    (Move::Scissors, Column::X) => Move::Paper,
+
+    // expected_outcome = Y => Player 2 ties
+    (Move::Rock, Column::Y) => Move::Rock,
+    (Move::Paper, Column::Y) => Move::Paper,
+    (Move::Scissors, Column::Y) => Move::Scissors,
+
+    // expected_outcome = Z => Player 2 wins
+    (Move::Rock, Column::Z) => Move::Paper,
+    (Move::Paper, Column::Z) => Move::Scissors,
+    (Move::Scissors, Column::Z) => Move::Rock,
+  }
+}
+
+fn round_score(player1_move: Move, player2_move: Move) -> u32 {
+  let move_score: u32 = player2_move as u32;
+  let outcome_score: u32 = calculate_outcome(player1_move, player2_move);
+  move_score + outcome_score
+}
+
+// Each line of the strategy guide: the opponent's move and the (still ambiguous) second column
+pub struct Round {
+  player1_move: Move,
+  second_column: Column,
+}
+
+pub struct Day2;
+
+impl Solver for Day2 {
+  const DAY: u8 = 2;
+  type Input = Vec<Round>;
+
+  fn parse(input: &str) -> Result<Self::Input> {
+    let mut rounds = Vec::new();
+    for line in parse::lines(input) {
+      let (player1_move, second_column) = line.text.split_once(' ').ok_or_else(|| line.error_at_end("two columns"))?;
+      let player1_move = parse_move(&line, player1_move)?;
+      let second_column = parse_second_column(&line, second_column)?;
+      rounds.push(Round { player1_move, second_column });
+    }
+    Ok(rounds)
+  }
+
+  fn part1(rounds: &Self::Input) -> Result<Answer> {
+    let mut total_score: u32 = 0;
+    for round in rounds {
+      let player2_move = response_move(round.second_column);
+      total_score += round_score(round.player1_move, player2_move);
+    }
+
+    debug!("Total score: {}", total_score);
+    Ok(total_score.into())
+  }
+
+  fn part2(rounds: &Self::Input) -> Result<Answer> {
+    let mut total_score: u32 = 0;
+    for round in rounds {
+      let player2_move = pick_player2_move(round.player1_move, round.second_column);
+      total_score += round_score(round.player1_move, player2_move);
+    }
+
+    debug!("Total score: {}", total_score);
+    Ok(total_score.into())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use proptest::prelude::*;
+  use super::*;
+
+  proptest! {
+    #[test]
+    fn parse_errors_point_into_the_input(input in "([ABCXYZ ]{0,4}\n){0,5}[ABCXYZ D]{0,4}") {
+      prop_assert!(parse::error_points_into(&Day2::parse(&input), &input));
+    }
+
+    #[test]
+    fn round_trip(rounds in prop::collection::vec((0..3usize, 0..3usize), 0..20)) {
+      let input: String = rounds.iter()
+        .map(|&(player1, column)| format!("{} {}\n", ["A", "B", "C"][player1], ["X", "Y", "Z"][column]))
+        .collect();
+      let parsed = Day2::parse(&input).unwrap();
+      prop_assert_eq!(parsed.len(), rounds.len());
+      for (round, &(player1, column)) in parsed.iter().zip(rounds.iter()) {
+        prop_assert_eq!(round.player1_move, [Move::Rock, Move::Paper, Move::Scissors][player1]);
+        prop_assert_eq!(round.second_column, [Column::X, Column::Y, Column::Z][column]);
+      }
+    }
+  }
+}
@@ -0,0 +1,42 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day20;
+
+impl Generator for Day20 {
+    // Number of numbers in the file
+    const DEFAULT_SIZE: usize = 5000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // The grove coordinates are counted from the only zero in the file
+        let count = size.max(1);
+        let zero = rng.random_range(0..count);
+        (0..count)
+            .map(|i| {
+                let number = match i {
+                    i if i == zero => 0,
+                    _ => loop {
+                        let number = rng.random_range(-10_000..=10_000);
+                        if number != 0 { break number }
+                    },
+                };
+                format!("{}\n", number)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let numbers = Day20::parse(&generate::<Day20>(seed, 50)).unwrap();
+            assert_eq!(numbers.iter().filter(|number| number.value == 0).count(), 1);
+            Day20::part1(&numbers).unwrap();
+            Day20::part2(&numbers).unwrap();
+        }
+    }
+}
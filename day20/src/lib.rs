@@ -0,0 +1,105 @@
+use anyhow::{Context, Result};
+use aoc_common::{debug, parse, trace, Answer, Solver};
+
+mod generate;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number {
+    value: i64,
+    org_pos: usize,
+}
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+pub struct Day20;
+
+impl Solver for Day20 {
+    const DAY: u8 = 20;
+    type Input = Vec<Number>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut numbers = Vec::new();
+        for (pos, line) in parse::lines(input).enumerate() {
+            numbers.push(Number { value: line.parse(line.text, "a number")?, org_pos: pos });
+        }
+        Ok(numbers)
+    }
+
+    fn part1(numbers: &Self::Input) -> Result<Answer> {
+        let results = mix(numbers, 1);
+        let sum = grove_coordinates(&results)?;
+
+        debug!("Sum of coordinates(1): {}", sum);
+        Ok(sum.into())
+    }
+
+    fn part2(numbers: &Self::Input) -> Result<Answer> {
+        let decrypted_numbers: Vec<Number> = numbers.iter().map(|num| Number {
+            value: num.value * DECRYPTION_KEY, org_pos: num.org_pos
+        }).collect();
+        let results = mix(&decrypted_numbers, 10);
+        let sum = grove_coordinates(&results)?;
+
+        debug!("Sum of coordinates (2): {}", sum);
+        Ok(sum.into())
+    }
+}
+
+// Coordinates x,y,z are found at positions 1000, 2000, 3000 after the 0 in the list
+fn grove_coordinates(results: &[Number]) -> Result<i64> {
+    let zero_pos = results.iter().position(|num| num.value == 0).context("looking for zero")?;
+    let x_pos = (zero_pos + 1000) % results.len();
+    let y_pos = (zero_pos + 2000) % results.len();
+    let z_pos = (zero_pos + 3000) % results.len();
+
+    trace!("x: {}", results[x_pos].value);
+    trace!("y: {}", results[y_pos].value);
+    trace!("z: {}", results[z_pos].value);
+
+    Ok(results[x_pos].value + results[y_pos].value + results[z_pos].value)
+}
+
+fn mix(numbers: &[Number], cycles: usize) -> Vec<Number> {
+    let mut results = numbers.to_vec();
+    let modulo = numbers.len() as i64 - 1;
+
+    for _ in 0..cycles {
+        for number in numbers.iter() {
+            let old_pos = results.iter().position(|num| num == number ).unwrap();
+            let new_pos = (old_pos as i64 + number.value).rem_euclid(modulo) as usize;
+
+            if old_pos < new_pos {
+                results[old_pos..=new_pos].rotate_left(1);
+            } else {
+                results[new_pos..=old_pos].rotate_right(1);
+            }
+        }
+    }
+    results
+}
+
+// Real checks:
+// - 3063 is too low
+// - 8302 is good
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "(-?[0-9]{1,5}\n){0,5}[0-9 a-]{0,6}") {
+            prop_assert!(parse::error_points_into(&Day20::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(values in prop::collection::vec(-10_000..10_000i64, 0..20)) {
+            let input: String = values.iter().map(|value| format!("{}\n", value)).collect();
+            let expected: Vec<Number> = values.iter().enumerate()
+                .map(|(org_pos, &value)| Number { value, org_pos })
+                .collect();
+            prop_assert_eq!(Day20::parse(&input).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,114 @@
+use std::collections::HashSet;
+use aoc_common::generate::{Generator, Rng, SliceRandom, StdRng};
+use crate::{Day21, HUMAN, ROOT};
+
+// Larger values are only split further by additions and subtractions, to stay far from overflowing
+const MAX_GROWING_VALUE: i64 = 1_000_000_000;
+
+struct Troop {
+    names: HashSet<String>,
+    jobs: Vec<String>,
+}
+
+impl Troop {
+    fn name(&mut self, rng: &mut StdRng, human: bool, count: usize) -> String {
+        if human && count == 1 {
+            return HUMAN.to_string();
+        }
+        loop {
+            let name: String = (0..4).map(|_| rng.random_range('a'..='z')).collect();
+            if name != ROOT && name != HUMAN && self.names.insert(name.clone()) {
+                return name;
+            }
+        }
+    }
+
+    // Adds the monkey yelling the given value and all the ones it waits for (the count includes all of them).
+    // The values are picked top-down, so that all the divisions are exact and the human's number
+    // can be found by reversing the operations.
+    fn monkey(&mut self, rng: &mut StdRng, name: String, value: i64, count: usize, human: bool) {
+        if count == 1 {
+            self.jobs.push(format!("{}: {}", name, value));
+            return;
+        }
+
+        let divisors: Vec<i64> = (2..=10).filter(|k| value > 0 && value % k == 0).collect();
+        let (op, left, right) = match rng.random_range(0..4) {
+            1 => {
+                let right = rng.random_range(0..=100);
+                ('-', value + right, right)
+            },
+            2 if value > 0 && value < MAX_GROWING_VALUE => {
+                let right = rng.random_range(1..=10);
+                ('/', value * right, right)
+            },
+            3 if !divisors.is_empty() => {
+                let factor = divisors[rng.random_range(0..divisors.len())];
+                if rng.random_bool(0.5) { ('*', value / factor, factor) } else { ('*', factor, value / factor) }
+            },
+            _ => {
+                let left = rng.random_range(0..=value);
+                ('+', left, value - left)
+            },
+        };
+
+        let (left_count, right_count) = split(rng, count - 1);
+        let human_left = human && rng.random_bool(0.5);
+        let left_name = self.name(rng, human_left, left_count);
+        let right_name = self.name(rng, human && !human_left, right_count);
+        self.jobs.push(format!("{}: {} {} {}", name, left_name, op, right_name));
+        self.monkey(rng, left_name, left, left_count, human_left);
+        self.monkey(rng, right_name, right, right_count, human && !human_left);
+    }
+}
+
+// Splits an even number of monkeys between two subtrees, keeping them roughly balanced.
+// Every monkey waits for two others or none, so there is an odd number of them in each subtree.
+fn split(rng: &mut StdRng, count: usize) -> (usize, usize) {
+    let pairs = count / 2 - 1;
+    let left = 2 * rng.random_range(pairs / 4..=pairs - pairs / 4) + 1;
+    (left, count - left)
+}
+
+impl Generator for Day21 {
+    // Number of monkeys, rounded up to an odd one
+    const DEFAULT_SIZE: usize = 2001;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut troop = Troop { names: HashSet::new(), jobs: Vec::new() };
+
+        // Both sides of the root yell the same number, one of them depends on the human
+        let value = rng.random_range(1000..1_000_000);
+        let (left_count, right_count) = split(rng, size.max(3) / 2 * 2);
+        let human_left = rng.random_bool(0.5);
+        let left = troop.name(rng, human_left, left_count);
+        let right = troop.name(rng, !human_left, right_count);
+        troop.jobs.push(format!("{}: {} + {}", ROOT, left, right));
+        troop.monkey(rng, left, value, left_count, human_left);
+        troop.monkey(rng, right, value, right_count, !human_left);
+
+        troop.jobs.shuffle(rng);
+        troop.jobs.into_iter().map(|job| job + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::{Answer, Solver};
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..20 {
+            let input = generate::<Day21>(seed, 51);
+            let monkeys = Day21::parse(&input).unwrap();
+            assert_eq!(monkeys.len(), 51);
+            Day21::part1(&monkeys).unwrap();
+
+            // The human already yells the right number
+            let human: i64 = input.lines().find_map(|line| line.strip_prefix("humn: ")).unwrap().parse().unwrap();
+            assert_eq!(Day21::part2(&monkeys).unwrap(), Answer::Number(human as i128));
+        }
+    }
+}
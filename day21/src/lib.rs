@@ -0,0 +1,264 @@
+use std::collections::HashMap;
+use anyhow::{bail, Result};
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, Answer, ParseError, Solver};
+
+mod generate;
+
+const ROOT: &str = "root";
+const HUMAN: &str = "humn";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Job {
+    Value(i64),
+    Add(String, String),
+    Sub(String, String),
+    Mul(String, String),
+    Div(String, String),
+}
+
+impl Job {
+    fn from_str(line: &Line, job: &str) -> Result<Job, ParseError> {
+        if job.chars().next().is_some_and(|c| c.is_numeric()) {
+            return Ok(Job::Value(line.parse(job, "a number")?))
+        }
+
+        let mut parts = job.split_whitespace();
+
+        let name1 = parts.next().ok_or_else(|| line.error_at_end("a number or a monkey name"))?.to_string();
+        let op = parts.next().ok_or_else(|| line.error_at_end("an operation"))?;
+        let name2 = parts.next().ok_or_else(|| line.error_at_end("a monkey name"))?.to_string();
+
+        match op {
+            "+" => Ok(Job::Add(name1, name2)),
+            "-" => Ok(Job::Sub(name1, name2)),
+            "*" => Ok(Job::Mul(name1, name2)),
+            "/" => Ok(Job::Div(name1, name2)),
+            _ => Err(line.error_at(op, "an operation (+, -, * or /)")),
+        }
+    }
+
+    fn operands(&self) -> (String, String) {
+        let (l, r) = match self {
+            Job::Add(l, r) => (l, r),
+            Job::Sub(l, r) => (l, r),
+            Job::Mul(l, r) => (l, r),
+            Job::Div(l, r) => (l, r),
+            Job::Value(_) => panic!("No operands in a value"),
+        };
+
+        (l.clone(), r.clone())
+    }
+
+    // Receives a Job f=self.run(op1, op2), returns a new Job, where op1 = new_job(f, op2)
+    fn equation_for(&self, f: &String, op1: &String) -> Job {
+        match self {
+            Job::Add(l, r) if l == op1 => Job::Sub(f.clone(), r.clone()), // f=op1+r => op1=f-r
+            Job::Add(l, r) if r == op1 => Job::Sub(f.clone(), l.clone()), // f=l+op1 => op1=f-l
+
+            Job::Sub(l, r) if l == op1 => Job::Add(f.clone(), r.clone()), // f=op1-r => op1=f+r
+            Job::Sub(l, r) if r == op1 => Job::Sub(l.clone(), f.clone()), // f=r-op1 => op1=r-f
+
+            Job::Mul(l, r) if l == op1 => Job::Div(f.clone(), r.clone()), // f=op1*r => op1=f/r
+            Job::Mul(l, r) if r == op1 => Job::Div(f.clone(), l.clone()), // f=l*op1 => op1=f/l
+
+            Job::Div(l, r) if l == op1 => Job::Mul(f.clone(), r.clone()), // f=op1/r => op1=f*r
+            Job::Div(l, r) if r == op1 => Job::Div(l.clone(), f.clone()), // f=r/op1 => op1=r/f
+
+            _ => panic!("Cannot calculate {} from {}={:?}", op1, f, self),
+        }
+    }
+}
+
+fn human_dependent_jobs(name: &String, monkeys: &HashMap<String, Job>, result: &mut Vec<String>) -> bool {
+    if name == HUMAN { return true }
+    let job = monkeys.get(name).expect("fetching a monkey");
+
+    let (l, r) = match job {
+        Job::Value(_) => return false,
+        Job::Add(l, r) => (l, r),
+        Job::Sub(l, r) => (l, r),
+        Job::Mul(l, r) => (l, r),
+        Job::Div(l, r) => (l, r),
+    };
+
+    if human_dependent_jobs(l, monkeys, result){
+        result.push(name.clone());
+        return true;
+    }
+
+    if human_dependent_jobs(r, monkeys, result) {
+        result.push(name.clone());
+        return true;
+    }
+
+    false
+}
+
+fn calculate_monkey(name: &String, monkeys: &HashMap<String, Job>) -> i64 {
+    let job = monkeys.get(name).expect("fetching a monkey");
+    match job {
+        Job::Value(v) => *v,
+        Job::Add(m1, m2) => calculate_monkey(m1, monkeys) + calculate_monkey(m2, monkeys),
+        Job::Sub(m1, m2) => calculate_monkey(m1, monkeys) - calculate_monkey(m2, monkeys),
+        Job::Mul(m1, m2) => calculate_monkey(m1, monkeys) * calculate_monkey(m2, monkeys),
+        Job::Div(m1, m2) => calculate_monkey(m1, monkeys) / calculate_monkey(m2, monkeys),
+    }
+}
+
+fn parse_line(line: &Line) -> Result<(String, Job), ParseError> {
+    let (name, job) = line.text.split_once(": ").ok_or_else(|| line.error_at_end("a monkey name followed by a colon"))?;
+    let job = Job::from_str(line, job)?;
+    Ok((name.to_string(), job))
+}
+
+pub struct Day21;
+
+impl Solver for Day21 {
+    const DAY: u8 = 21;
+    type Input = HashMap<String, Job>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut monkeys: HashMap<String, Job> = HashMap::new();
+        let mut job_lines = Vec::new();
+        for line in parse::lines(input) {
+            let (name, job) = parse_line(&line)?;
+            job_lines.push((line, name.clone()));
+            monkeys.insert(name, job);
+        }
+
+        // Monkeys can wait for the ones listed further down, so they are checked once all are known
+        for (line, name) in job_lines.iter() {
+            if let Job::Value(_) = monkeys[name] { continue }
+            let (_, job) = line.text.split_once(": ").expect("checked by parse_line");
+            for operand in job.split_whitespace().step_by(2).take(2) {
+                if !monkeys.contains_key(operand) {
+                    return Err(line.error_at(operand, "a known monkey").into());
+                }
+            }
+        }
+
+        // Part 1 asks the root monkey, part 2 the human
+        for name in [ROOT, HUMAN] {
+            if !monkeys.contains_key(name) {
+                return Err(ParseError::new(job_lines.len() + 1, 1, format!("a monkey named {}", name), "").into());
+            }
+        }
+        Ok(monkeys)
+    }
+
+    fn part1(monkeys: &Self::Input) -> Result<Answer> {
+        let root_name = String::from(ROOT);
+        let result = calculate_monkey(&root_name, monkeys);
+        debug!("Root monkey yells {}", result);
+        Ok(result.into())
+    }
+
+    fn part2(monkeys: &Self::Input) -> Result<Answer> {
+        let mut monkeys = monkeys.clone();
+        let root_name = String::from(ROOT);
+
+        // Get both sides of the root
+        if let Job::Value(_) = monkeys[&root_name] { bail!("the root monkey has to compare two monkeys") }
+        let (left, right) = monkeys[&root_name].operands();
+
+        // One of them will be a solvable side, the solution, and another one will have an unknown (human)
+        let mut human_dep_jobs: Vec<String> = Vec::new();
+        human_dependent_jobs(&right, &monkeys, &mut human_dep_jobs);
+        let (mut solution, mut equation) = (&left, &right);
+        if human_dep_jobs.is_empty() {
+            human_dependent_jobs(&left, &monkeys, &mut human_dep_jobs);
+            (solution, equation) = (&right, &left);
+        }
+
+        // Calculate the solution to get a value
+        let solution = calculate_monkey(solution, &monkeys);
+
+        // Walk all nodes dependent on humn and invert them
+        let mut eq_for = HUMAN.to_string();
+        for name in human_dep_jobs.iter() {
+            let job = monkeys.get(name).expect("fetching a monkey");
+            let eq = job.equation_for(name, &eq_for); // returns a new job to calculate the value of eq_for
+            monkeys.insert(eq_for.to_owned(), eq);
+            eq_for = name.clone();
+        }
+
+        // Inject the solution into the dataset
+        monkeys.insert(equation.clone(), Job::Value(solution));
+
+        // Solve for the human value
+        let human_val = calculate_monkey(&HUMAN.to_string(), &monkeys);
+        debug!("Human value: {}", human_val);
+        Ok(human_val.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    // Monkeys named aaaa, aaab, ... that can only wait for each other
+    fn monkeys() -> impl Strategy<Value = Vec<(String, Job)>> {
+        (1..10usize).prop_flat_map(|count| {
+            let name = move |i: usize| format!("aaa{}", (b'a' + i as u8) as char);
+            let job = prop_oneof![
+                (0..1000i64).prop_map(Job::Value),
+                (0..count, 0..4usize, 0..count).prop_map(move |(l, op, r)| {
+                    let operation = [Job::Add, Job::Sub, Job::Mul, Job::Div][op];
+                    operation(name(l), name(r))
+                }),
+            ];
+            // With the root and the human every input needs
+            prop::collection::vec(job, count).prop_map(move |jobs| {
+                let mut monkeys: Vec<(String, Job)> = jobs.into_iter().enumerate().map(|(i, job)| (name(i), job)).collect();
+                monkeys.push((ROOT.to_string(), Job::Add(name(0), HUMAN.to_string())));
+                monkeys.push((HUMAN.to_string(), Job::Value(5)));
+                monkeys
+            })
+        })
+    }
+
+    fn describe(job: &Job) -> String {
+        match job {
+            Job::Value(value) => value.to_string(),
+            Job::Add(l, r) => format!("{} + {}", l, r),
+            Job::Sub(l, r) => format!("{} - {}", l, r),
+            Job::Mul(l, r) => format!("{} * {}", l, r),
+            Job::Div(l, r) => format!("{} / {}", l, r),
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([a-c]{1,4}: ([0-9]{1,3}|[a-c]{1,4} [-+*/x] [a-c]{1,4})\n){0,5}[a-z0-9:+ ]{0,10}") {
+            prop_assert!(parse::error_points_into(&Day21::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(monkeys in monkeys()) {
+            let input: String = monkeys.iter().map(|(name, job)| format!("{}: {}\n", name, describe(job))).collect();
+            prop_assert_eq!(Day21::parse(&input).unwrap(), monkeys.into_iter().collect::<HashMap<_, _>>());
+        }
+    }
+
+    fn error(input: &str) -> ParseError {
+        Day21::parse(input).unwrap_err().downcast().unwrap()
+    }
+
+    #[test]
+    fn needs_the_root_and_the_human() {
+        let err = error("a: b + b\nb: 5\n");
+        assert_eq!((err.line, err.column, err.expected.as_str()), (3, 1, "a monkey named root"));
+        let err = error("root: b + b\nb: 5\n");
+        assert_eq!((err.line, err.expected.as_str()), (3, "a monkey named humn"));
+    }
+
+    #[test]
+    fn points_at_the_unknown_monkey() {
+        let err = error("root: humn + bb\nhumn: 5\nb: 1\n");
+        assert_eq!((err.line, err.column), (1, 14));
+        let err = error("root: bb + b\nhumn: 5\nb: 1\n");
+        assert_eq!((err.line, err.column), (1, 7));
+    }
+}
@@ -0,0 +1,57 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day22;
+
+// Positions of the cube faces on the map (in face sizes), same as in the real input
+const FACES: [(usize, usize); 6] = [(1, 0), (2, 0), (1, 1), (0, 2), (1, 2), (0, 3)];
+
+impl Generator for Day22 {
+    // Side of a cube face
+    const DEFAULT_SIZE: usize = 50;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let size = size.max(1);
+        let mut input = String::new();
+        for y in 0..4 * size {
+            let columns: Vec<usize> = FACES.iter().filter(|face| face.1 == y / size).map(|face| face.0).collect();
+            let first = *columns.iter().min().unwrap();
+            let last = *columns.iter().max().unwrap();
+            for x in 0..(last + 1) * size {
+                let tile = match x {
+                    x if x < first * size => ' ',
+                    // The path starts in the top left corner of the first face
+                    x if y == 0 && x == first * size => '.',
+                    _ if rng.random_bool(0.1) => '#',
+                    _ => '.',
+                };
+                input.push(tile);
+            }
+            input.push('\n');
+        }
+
+        // The path starts and ends with a number of steps
+        input.push('\n');
+        input.push_str(&rng.random_range(1..=50).to_string());
+        for _ in 0..10 * size {
+            input.push(if rng.random_bool(0.5) { 'R' } else { 'L' });
+            input.push_str(&rng.random_range(1..=50).to_string());
+        }
+        input.push('\n');
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let notes = Day22::parse(&generate::<Day22>(seed, 10)).unwrap();
+            assert_eq!(notes.instructions.len(), 201);
+            Day22::part1(&notes).unwrap();
+        }
+    }
+}
@@ -0,0 +1,631 @@
+use std::cmp::max;
+use anyhow::{Context, Result};
+use aoc_common::{debug, parse, trace, Answer, Direction, Grid, Solver};
+use Direction::*;
+
+mod generate;
+
+type FlatMap = Grid<char>;
+
+// All the positions are indexes into the map (or into a cube face)
+type Point = aoc_common::Point<usize>;
+
+fn password_coefficient(dir: Direction) -> usize {
+    match dir {
+        Right => 0,
+        Down => 1,
+        Left => 2,
+        Up => 3,
+    }
+}
+
+#[derive(Debug)]
+struct Cube {
+    size: usize,
+    faces: Vec<FlatMap>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Face {
+    Top = 0,
+    Right = 1,
+    Front = 2,
+    Left = 3,
+    Bottom = 4,
+    Rear = 5,
+}
+
+impl Face {
+    fn take_step(&self, dir: &Direction) -> Face {
+        match self {
+            Face::Top => match dir {
+                Right => Face::Right,
+                Down => Face::Front,
+                Left => Face::Left,
+                Up => Face::Rear,
+            },
+            Face::Front => match dir {
+                Right => Face::Right,
+                Down => Face::Bottom,
+                Left => Face::Left,
+                Up => Face::Top,
+            },
+            Face::Bottom => match dir {
+                Right => Face::Right,
+                Down => Face::Rear,
+                Left => Face::Left,
+                Up => Face::Front,
+            },
+            Face::Rear => match dir {
+                Right => Face::Right,
+                Down => Face::Top,
+                Left => Face::Left,
+                Up => Face::Bottom,
+            },
+            Face::Right => match dir {
+                Right => Face::Rear,
+                Down => Face::Bottom,
+                Left => Face::Front,
+                Up => Face::Top,
+            },
+            Face::Left => match dir {
+                Right => Face::Front,
+                Down => Face::Bottom,
+                Left => Face::Rear,
+                Up => Face::Top,
+            },
+        }
+    }
+}
+
+fn demo_faces()-> Vec<(usize, usize)> {
+    vec![
+        (2, 0), // Top
+        (2, 1), // Front
+        (2, 2), // Bottom
+        (0, 1), // Rear
+        (3, 2), // Right
+        (1, 1), // Left
+    ]
+}
+
+// .01
+// .2.
+// 34.
+// 5..
+//
+// .Tr
+// .F.
+// lB.
+// R..
+//
+// 0 - top
+// 1 - right
+// 2 - front
+// 3 - left
+// 4 - bottom
+// 5 - rear
+
+
+fn real_faces()-> Vec<(usize, usize)> {
+    vec![
+        (1, 0), // top
+        (2, 0), // right
+        (1, 1), // front
+        (0, 2), // left
+        (1, 2), // bottom
+        (0, 3), // rear
+    ]
+}
+
+type PositionTransformer = fn(Point, usize) -> Point;
+type FaceTransition = (Direction, PositionTransformer);
+
+// Returns an array of adjacent faces, directions after transition, and a function to get the new position
+/*
+    ..T.
+    RlF.
+    ..Br
+*/
+// for a given src_face, returns:
+// - the dst_face
+// - the the direction of movement on the destination face
+// - a function that takes the current position on src_face and returns the new position on the dst_face
+
+fn demo_transition_for_face(src_face: &Face, dst_face: &Face) -> FaceTransition {
+    match src_face {
+        Face::Top => match dst_face {
+            Face::Front => (Down, |pos, ____| Point::new(pos.x, 0)),
+            Face::Rear => (Down, |pos, size| Point::new(size - pos.x - 1, 0)),
+            Face::Right => (Down, |pos, ____| Point::new(pos.y, 0)),
+            Face::Left => (Left, |pos, size| Point::new(size - 1, size - pos.y - 1)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Front => match dst_face  {
+            Face::Top    => (Up,   |pos, size| Point::new(pos.x, size - 1)),
+            Face::Bottom => (Down, |pos, ____| Point::new(pos.x, 0)),
+            Face::Left   => (Left, |pos, size| Point::new(size - 1, pos.y)),
+            Face::Right  => (Down, |pos, ____| Point::new(pos.y, 0)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Bottom => match dst_face {
+            Face::Front => (Up,    |pos, size| Point::new(pos.x, size - 1)),
+            Face::Right => (Right, |pos, ____| Point::new(0, pos.y)),
+            Face::Rear  => (Down,  |pos, size| Point::new(size - pos.x - 1, size - 1)),
+            Face::Left  => (Up,    |pos, size| Point::new(size - pos.y - 1, size - 1)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Rear => match dst_face {
+            Face::Top => (Down, |pos, size| Point::new(size - pos.x - 1, 0)),
+            Face::Bottom => (Up, |pos, size| Point::new(pos.x, size - 1)),
+            Face::Right => todo!(),
+            Face::Left => (Right, |pos, ____| Point::new(0, pos.y)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Right => match dst_face {
+            Face::Top => todo!(),
+            Face::Front => todo!(),
+            Face::Bottom => todo!(),
+            Face::Rear => todo!(),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Left => match dst_face {
+            Face::Top    => (Right, |pos, ____| Point::new(0, pos.x)),
+            Face::Front  => (Right, |pos, ____| Point::new(0, pos.y)),
+            Face::Bottom => (Right, |pos, size| Point::new(0, size - pos.x - 1)),
+            Face::Rear   => (Left,  |pos, size| Point::new(size - 1, pos.y)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+    }
+}
+
+
+/*
+            0 => [
+                (1, RIGHT,  |pos, ___| (0, pos.y).into()),
+                (2, DOWN,   |pos, ___| (pos.x, 0).into()),
+                (3, RIGHT,  |pos, max| (0, max - pos.y).into()),
+                (5, RIGHT,  |pos, ___| (0, pos.x).into()),
+            ],
+            1 => [
+                (4, LEFT,  |pos, max| (max, max - pos.y).into()),
+                (2, LEFT,  |pos, max| (max, pos.x).into()),
+                (0, LEFT,  |pos, max| (max, pos.y).into()),
+                (5, UP,    |pos, max| (pos.x, max).into()),
+            ],
+            2 => [
+                (1, UP,    |pos, max| (pos.y, max).into()),
+                (4, DOWN,  |pos, ___| (pos.x, 0).into()),
+                (3, DOWN,  |pos, ___| (pos.y, 0).into()),
+                (0, UP,    |pos, max| (pos.x, max).into()),
+            ],
+            3 => [
+                (4, RIGHT, |pos, ___| (0, pos.y).into()),
+                (5, DOWN,  |pos, ___| (pos.x, 0).into()),
+                (0, RIGHT, |pos, max| (0, max - pos.y).into()),
+                (2, RIGHT, |pos, ___| (0, pos.x).into()),
+            ],
+            4 => [
+                (1, LEFT,  |pos, max| (max, max - pos.y).into()),
+                (5, LEFT,  |pos, max| (max, pos.x).into()),
+                (3, LEFT,  |pos, max| (max, pos.y).into()),
+                (2, UP,    |pos, max| (pos.x, max).into()),
+            ],
+            5 => [
+                (4, UP,    |pos, max| (pos.y, max).into()),
+                (1, DOWN,  |pos, ___| (pos.x, 0).into()),
+                (0, DOWN,  |pos, ___| (pos.y, 0).into()),
+                (3, UP,    |pos, max| (pos.x, max).into()),
+            ],
+
+// .01
+// .2.
+// 34.
+// 5..
+//
+// .Tr
+// .F.
+// lB.
+// R..
+
+// 0 - top
+// 1 - right
+// 2 - front
+// 3 - left
+// 4 - bottom
+// 5 - rear
+            Face::Front => match dir {
+                Right => Face::Right,
+                Down => Face::Bottom,
+                Left => Face::Left,
+                Up => Face::Top,
+            },
+            Face::Bottom => match dir {
+                Right => Face::Right,
+                Down => Face::Rear,
+                Left => Face::Left,
+                Up => Face::Front,
+            },
+            Face::Rear => match dir {
+                Right => Face::Right,
+                Down => Face::Top,
+                Left => Face::Left,
+                Up => Face::Bottom,
+            },
+            Face::Right => match dir {
+                Right => Face::Rear,
+                Down => Face::Bottom,
+                Left => Face::Front,
+                Up => Face::Top,
+            },
+            Face::Left => match dir {
+                Right => Face::Front,
+                Down => Face::Bottom,
+                Left => Face::Rear,
+                Up => Face::Top,
+            },
+
+
+ */
+
+fn real_transitions_for_face(src_face: &Face, dst_face: &Face) -> FaceTransition {
+    match src_face {
+        Face::Top => match dst_face {
+            Face::Right => (Right, |pos, ___| Point::new(0, pos.y)),
+            Face::Front => (Down,  |pos, ___| Point::new(pos.x, 0)),
+            Face::Left  => (Right, |pos, max| Point::new(0, max - pos.y - 1)),
+            Face::Rear  => (Right, |pos, ___| Point::new(0, pos.x)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Right => match dst_face {
+            Face::Bottom => (Left, |pos, max| Point::new(max - 1, max - pos.y - 1)),
+            Face::Front  => (Left, |pos, max| Point::new(max - 1, pos.x)),
+            Face::Top    => (Left, |pos, max| Point::new(max - 1, pos.y)),
+            Face::Rear   => (Up,   |pos, max| Point::new(pos.x, max - 1)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Front => match dst_face  {
+            Face::Right  => (Up,    |pos, max| Point::new(pos.y, max - 1)),
+            Face::Bottom => (Down,  |pos, ___| Point::new(pos.x, 0)),
+            Face::Left   => (Down,  |pos, ___| Point::new(pos.y, 0)),
+            Face::Top    => (Up,    |pos, max| Point::new(pos.x, max - 1)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Left => match dst_face {
+            Face::Bottom => (Right, |pos, ___| Point::new(0, pos.y)),
+            Face::Rear   => (Down,  |pos, ___| Point::new(pos.x, 0)),
+            Face::Top    => (Right, |pos, max| Point::new(0, max - pos.y - 1)),
+            Face::Front  => (Right, |pos, ___| Point::new(0, pos.x)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Bottom => match dst_face {
+            Face::Right => (Left, |pos, max| Point::new(max - 1, max - pos.y - 1)),
+            Face::Rear  => (Left, |pos, max| Point::new(max - 1, pos.x)),
+            Face::Left  => (Left, |pos, max| Point::new(max - 1, pos.y)),
+            Face::Front => (Up,   |pos, max| Point::new(pos.x, max - 1)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+        Face::Rear => match dst_face {
+            Face::Bottom => (Up,   |pos, max| Point::new(pos.y, max - 1)),
+            Face::Right  => (Down, |pos, ___| Point::new(pos.x, 0)),
+            Face::Top    => (Down, |pos, ___| Point::new(pos.y, 0)),
+            Face::Left   => (Up,   |pos, max| Point::new(pos.x, max - 1)),
+            _ => panic!("Unexpected destination face: {:?}", dst_face),
+        },
+    }
+}
+
+impl Cube {
+    fn from_flat_map(map: &FlatMap) -> Self {
+        let face_size = max(map.height(), map.width()) / 4;
+
+        let mut cube = Cube {
+            size: face_size,
+            faces: vec![Grid::new(face_size, face_size, ' '); 6],
+        };
+
+        match face_size {
+            4 => cube.load_map(map, &demo_faces()),
+            50 => cube.load_map(map, &real_faces()),
+            _ => panic!("Unexpected face size: {}", face_size),
+        }
+
+        cube
+    }
+
+    fn load_map(&mut self, map: &FlatMap, face_positions: &[(usize, usize)]) {
+        for (face, &(face_x, face_y)) in face_positions.iter().enumerate().take(Face::Rear as usize + 1) {
+            self.load_face(face, map, face_x, face_y);
+        }
+    }
+
+    fn load_face(&mut self, face_id: usize, map: &FlatMap, x: usize, y: usize) {
+        let size = self.size;
+        let face_map: &mut FlatMap = &mut self.faces[face_id];
+        for row in 0..size {
+            for col in 0..size {
+                face_map[Point::new(col, row)] = map[Point::new(x * size + col + 1, y * size + row + 1)];
+            }
+        }
+    }
+
+    fn flat_coordinates(&self, face: Face, pos: Point) -> Point {
+        match self.size {
+            4 => self.flat_coordinates_internal(face, pos, &demo_faces()),
+            50 => self.flat_coordinates_internal(face, pos, &real_faces()),
+            _ => panic!("Unexpected face size: {}", self.size),
+        }
+    }
+
+    fn flat_coordinates_internal(&self, face: Face, pos: Point, face_positions: &[(usize, usize)]) -> Point {
+        let (face_x, face_y) = face_positions[face as usize];
+        let flat_x = face_x * self.size + pos.x;
+        let flat_y = face_y * self.size + pos.y;
+        Point::new(flat_x, flat_y)
+    }
+}
+
+// The flat map is surrounded by a border of void (' ') to make wrapping around easier
+#[derive(Debug, PartialEq)]
+enum Instruction {
+    Forward(usize),
+    TurnRight,
+    TurnLeft,
+}
+
+pub struct Notes {
+    map: FlatMap,
+    instructions: Vec<Instruction>,
+}
+
+pub struct Day22;
+
+impl Solver for Day22 {
+    const DAY: u8 = 22;
+    type Input = Notes;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let map_text = input.split_once("\n\n").map_or(input, |(map_text, _)| map_text);
+        let map = Grid::try_parse(map_text, ' ', "a tile (., # or space)", |c| matches!(c, ' ' | '.' | '#').then_some(c))?;
+        let map = map.with_border(1, ' ');
+
+        // The path follows the map after an empty line
+        let mut lines = parse::lines(input);
+        lines.by_ref().find(|line| line.text.is_empty());
+        let path = lines.next_line("the path (like 10R5L5)")?;
+
+        // Parse algorithm into a list of instructions
+        // 10R5L5R10L4R5L5 means:
+        // - Go forward 10 spaces
+        // - Turn right
+        // - Go forward 5 spaces
+        // ...
+        // - Turn left
+        // - Go forward 5 spaces
+        let mut instructions = Vec::new();
+        let mut steps_start = 0;
+        for (pos, c) in path.text.char_indices() {
+            if c.is_ascii_digit() {
+                continue;
+            }
+            if steps_start < pos {
+                instructions.push(Instruction::Forward(path.parse(&path.text[steps_start..pos], "a number of steps")?));
+            }
+            match c {
+                'R' => instructions.push(Instruction::TurnRight),
+                'L' => instructions.push(Instruction::TurnLeft),
+                _ => return Err(path.error_at(&path.text[pos..], "a number of steps or a turn (R or L)").into()),
+            }
+            steps_start = pos + 1;
+        }
+        if steps_start < path.text.len() {
+            instructions.push(Instruction::Forward(path.parse(&path.text[steps_start..], "a number of steps")?));
+        }
+
+        Ok(Notes { map, instructions })
+    }
+
+    // The map is flat
+    fn part1(notes: &Self::Input) -> Result<Answer> {
+        let map = &notes.map;
+
+        // Find the starting position
+        let start_pos = Point {
+            x: map[1].iter().position(|&c| c == '.').context("looking for the start")?,
+            y: 1
+        };
+
+        let mut pos = start_pos;
+        let mut dir = Right;
+
+        // Execute the instructions
+        for instruction in notes.instructions.iter() {
+            match instruction {
+                Instruction::TurnRight => { dir = dir.turn_right() },
+                Instruction::TurnLeft => { dir = dir.turn_left() },
+                Instruction::Forward(num_steps) => {
+                    for _ in 0..*num_steps {
+                        if !flat_go_forward(&dir, &mut pos, map) { break }
+                    }
+                }
+            }
+        }
+        Ok(print_results("part 1", &pos, &dir).into())
+    }
+
+    // The map is a fucking cube 🤯
+    fn part2(notes: &Self::Input) -> Result<Answer> {
+        // We start in the corner of a specific face
+        let mut pos = Point::new(0, 0);
+        let mut face = Face::Top;
+        let mut dir = Right;
+
+        // Parse the flat map into a cube
+        let cube = Cube::from_flat_map(&notes.map);
+        debug!("Cube loaded with face size: {}", cube.size);
+
+        for instruction in notes.instructions.iter() {
+            match instruction {
+                Instruction::TurnRight => { dir = dir.turn_right() },
+                Instruction::TurnLeft => { dir = dir.turn_left() },
+                Instruction::Forward(num_steps) => {
+                    for _ in 0..*num_steps {
+                        if !cube_go_forward(&mut dir, &mut pos, &mut face,  &cube) { break }
+                    }
+                }
+            }
+        }
+
+        debug!("Final cube position: pos = {:?}, face = {:?}, direction = {:?}", pos, face, dir);
+
+        let flat_pos = cube.flat_coordinates(face, pos);
+        Ok(print_results("part 2", &flat_pos, &dir).into())
+    }
+}
+
+fn print_results(part: &str, pos: &Point, dir: &Direction) -> usize {
+    debug!("Final position for {}: {},{} with dir={:?}", part, pos.x, pos.y, dir);
+
+    // Calculate the password:
+    let dir_coeff = password_coefficient(*dir);
+    let password = 1000 * pos.y + 4 * pos.x + dir_coeff;
+    debug!("Password for {} = 1000 * {} + 4 * {} + {} = {}", part, pos.y, pos.x, dir_coeff, password);
+    password
+}
+
+fn flat_go_forward(dir: &Direction, pos: &mut Point, map: &FlatMap) -> bool {
+    // Try to take a step
+    let mut new_pos = pos.step(*dir);
+
+    // If we hit a void space (outside of the map), we need to wrap around to the other side of the map
+    if map[new_pos.y][new_pos.x] == ' ' {
+        new_pos = flat_wraparound_position(dir, pos, map);
+    }
+
+    // Step into the empty space
+    if map[new_pos.y][new_pos.x] == '.' {
+        *pos = new_pos;
+        return true;
+    }
+
+    // If we hit a wall, stop
+    if map[new_pos.y][new_pos.x] == '#' {
+        return false
+    }
+
+    panic!("Unexpected character: {} at {},{}", map[new_pos.y][new_pos.x], new_pos.x, new_pos.y);
+}
+
+fn flat_wraparound_position(dir: &Direction, pos: &Point, map: &FlatMap) -> Point {
+    let mut new_pos = *pos;
+
+    // Go in the opposite direction until you hit a ' ', that is your new position
+    let opposite_dir = dir.opposite();
+    loop {
+        let pos = new_pos.step(opposite_dir);
+        if map[pos.y][pos.x] == ' ' { return new_pos }
+        new_pos = pos;
+    }
+}
+
+fn cube_go_forward(dir: &mut Direction, pos: &mut Point, face: &mut Face, cube: &Cube) -> bool {
+    // If we are on the edge of the face, so we need to transition to a new face
+    let (new_face, new_pos, new_dir) = if pos.x == 0 || pos.y == 0 || pos.x >= cube.size-1 || pos.y >= cube.size-1 {
+        cube_take_step(dir, pos, face, cube)
+    } else {
+        (*face, pos.step(*dir), *dir)
+    };
+
+    // Step into the empty space
+    match cube.faces[new_face as usize][new_pos.y][new_pos.x] {
+        '.' => {
+            *face = new_face;
+            *pos = new_pos;
+            *dir = new_dir;
+            true
+        },
+        '#' => false,
+        _ => {
+            // print the face
+            trace!("Face {}:", new_face as usize);
+            for row in cube.faces[new_face as usize].rows() {
+                trace!("{}", row.iter().collect::<String>());
+            }
+
+            panic!("Unexpected character: '{}' at {},{} on face {:?} ({})",
+            cube.faces[new_face as usize][new_pos.y][new_pos.x], new_pos.x, new_pos.y, new_face, new_face as usize)
+        },
+    }
+}
+
+// Note: this is only called when the requested step is off the edge of the current face
+fn cube_take_step(dir: &Direction, pos: &Point, src_face: &Face, cube: &Cube) -> (Face, Point, Direction) {
+    let dst_face = src_face.take_step(dir);
+    let (new_dir, f_transform) = match cube.size {
+        4 => demo_transition_for_face(src_face, &dst_face),
+        50 => real_transitions_for_face(src_face, &dst_face),
+        _ => panic!("Unexpected face size: {}", cube.size),
+    };
+
+    // Find the transition that will take us to the new face
+    let new_pos = f_transform(*pos, cube.size);
+    (dst_face, new_pos, new_dir)
+}
+
+// Final position for part 1: 3,164 with dir=Left
+// Password for part 1 = 1000 * 164 + 4 * 3 + 2 = 164014
+
+// Part 2: password 47525
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    // Rows of the map start with a tile, so that none of them is empty
+    fn map_rows() -> impl Strategy<Value = Vec<String>> {
+        prop::collection::vec("[.#][ .#]{0,8}", 1..8)
+    }
+
+    // Forward moves and turns alternate, the path starts and ends with a move
+    fn path() -> impl Strategy<Value = Vec<Instruction>> {
+        (0..50usize, prop::collection::vec((any::<bool>(), 0..50usize), 0..8)).prop_map(|(first, moves)| {
+            let mut instructions = vec![Instruction::Forward(first)];
+            for (right, steps) in moves {
+                instructions.push(if right { Instruction::TurnRight } else { Instruction::TurnLeft });
+                instructions.push(Instruction::Forward(steps));
+            }
+            instructions
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([ .#]{1,6}\n){0,4}\n?[0-9RLX ]{0,10}") {
+            prop_assert!(parse::error_points_into(&Day22::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(rows in map_rows(), instructions in path()) {
+            let path: String = instructions.iter()
+                .map(|instruction| match instruction {
+                    Instruction::Forward(steps) => steps.to_string(),
+                    Instruction::TurnRight => "R".to_string(),
+                    Instruction::TurnLeft => "L".to_string(),
+                })
+                .collect();
+            let input = format!("{}\n\n{}\n", rows.join("\n"), path);
+
+            // The map is padded with void to the longest row and surrounded with a border of it
+            let notes = Day22::parse(&input).unwrap();
+            let width = rows.iter().map(|row| row.len()).max().unwrap();
+            prop_assert_eq!(notes.map.width(), width + 2);
+            prop_assert_eq!(notes.map.height(), rows.len() + 2);
+            for (y, row) in rows.iter().enumerate() {
+                let parsed: String = notes.map.rows().nth(y + 1).unwrap()[1..=row.len()].iter().collect();
+                prop_assert_eq!(&parsed, row);
+            }
+            prop_assert_eq!(notes.instructions, instructions);
+        }
+    }
+}
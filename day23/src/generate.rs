@@ -0,0 +1,33 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day23;
+
+impl Generator for Day23 {
+    // Side of the square scan
+    const DEFAULT_SIZE: usize = 73;
+
+    // About half of the tiles have gnomes on them, like in the real input
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut input = String::new();
+        for _ in 0..size {
+            input.extend((0..size).map(|_| if rng.random_bool(0.5) { '#' } else { '.' }));
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..5 {
+            let gnomes = Day23::parse(&generate::<Day23>(seed, 12)).unwrap();
+            Day23::part1(&gnomes).unwrap();
+            Day23::part2(&gnomes).unwrap();
+        }
+    }
+}
@@ -0,0 +1,186 @@
+use anyhow::{Context, Result};
+use aoc_common::{debug, parse, trace, Answer, Direction, Solver};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+mod generate;
+
+// Gnomes can spread in any direction, so their positions can go negative
+type Point = aoc_common::Point<i64>;
+
+pub struct Day23;
+
+impl Solver for Day23 {
+    const DAY: u8 = 23;
+    type Input = Vec<Point>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        // Find all gnomes (encoded as '#') with their coords
+        let mut gnomes = Vec::new();
+        for (y, line) in parse::lines(input).enumerate() {
+            for (x, c) in line.text.chars().enumerate() {
+                match c {
+                    '#' => gnomes.push(Point::new(x as i64, y as i64)),
+                    '.' => {},
+                    _ => return Err(line.error(x + 1, "a gnome (#) or an empty tile (.)").into()),
+                }
+            }
+        }
+        Ok(gnomes)
+    }
+
+    fn part1(gnomes: &Self::Input) -> Result<Answer> {
+        let mut gnomes = gnomes.clone();
+        let mut proposed_dirs = initial_directions();
+        for _ in 0..10 {
+            play_round(&mut gnomes, &mut proposed_dirs);
+        }
+
+        // Find a bounding box for the gnomes
+        let (min, max) = Point::bounding_box(&gnomes).context("no gnomes")?;
+
+        // Count empty spaces in the bounding box
+        let gnomes_set = gnomes.iter().collect::<FxHashSet<&Point>>();
+        let mut empty_spaces = 0;
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                if !gnomes_set.contains(&Point::new(x, y)) {
+                    empty_spaces += 1;
+                }
+            }
+        }
+
+        debug!("Empty spaces on round 10: {}", empty_spaces);
+        Ok(empty_spaces.into())
+    }
+
+    fn part2(gnomes: &Self::Input) -> Result<Answer> {
+        let mut gnomes = gnomes.clone();
+        let mut proposed_dirs = initial_directions();
+
+        let mut round = 1;
+        while play_round(&mut gnomes, &mut proposed_dirs) {
+            round += 1;
+            if round % 10 == 0 {
+                trace!("Round {} complete...", round);
+            }
+        }
+
+        debug!("Finished after {} rounds", round);
+        Ok(round.into())
+    }
+}
+
+fn initial_directions() -> Vec<Direction> {
+    vec![
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ]
+}
+
+// Plays a single round, returns false if no gnome has moved
+fn play_round(gnomes: &mut [Point], proposed_dirs: &mut [Direction]) -> bool {
+    let gnomes_set = gnomes.iter().collect::<FxHashSet<&Point>>();
+
+    // Make a proposal for each gnome
+    let mut proposals: Vec<Option<Point>> = Vec::with_capacity(gnomes.len());
+    let mut proposal_count = 0;
+    for gnome in gnomes.iter() {
+        let proposal = make_proposal(gnome, &gnomes_set, proposed_dirs);
+        if proposal.is_some() {
+            proposal_count += 1;
+        }
+        proposals.push(proposal);
+    }
+
+    // If no gnome is moving, we're done
+    if proposal_count == 0 { return false }
+
+    let duplicate_proposals = find_duplicates(&proposals);
+    for (gnome_idx, proposal) in proposals.iter().enumerate() {
+        if let Some(p) = proposal {
+            if !duplicate_proposals.contains(p) {
+                gnomes[gnome_idx] = p.to_owned();
+            }
+        }
+    }
+
+    // Move the first proposed option to the end of the list
+    proposed_dirs.rotate_left(1);
+    true
+}
+
+fn find_duplicates(proposals: &[Option<Point>]) -> FxHashSet<&Point> {
+    let mut seen = FxHashSet::default();
+    let mut duplicates = FxHashSet::default();
+    for p in proposals.iter().flatten() {
+        if seen.contains(p) {
+            duplicates.insert(p);
+        } else {
+            seen.insert(p);
+        }
+    }
+    duplicates
+}
+
+// Checks the three positions in the given direction (straight ahead and both diagonals)
+fn any_neighbors(gnome: &Point, gnomes: &FxHashSet<&Point>, dir: &Direction) -> bool {
+    let ahead = gnome.step(*dir);
+    let side = dir.turn_right();
+    let neighbors_in_direction = [ahead.step(side.opposite()), ahead, ahead.step(side)];
+    neighbors_in_direction.iter().any(|p| gnomes.contains(p))
+}
+
+fn make_proposal(gnome: &Point, gnomes: &FxHashSet<&Point>, proposed_dirs: &[Direction]) -> Option<Point> {
+    let mut neighbors_by_dir = FxHashMap::default();
+    let mut found_neighbors = false;
+
+    for dir in proposed_dirs.iter() {
+        let neighbors = any_neighbors(gnome, gnomes, dir);
+        neighbors_by_dir.insert(dir, neighbors);
+        found_neighbors = found_neighbors || neighbors;
+    }
+
+    // If there are no neighbors in any direction, stay put
+    if !found_neighbors { return None }
+
+    // Check if any proposed direction is free and propose that move
+    for dir in proposed_dirs.iter() {
+        if !neighbors_by_dir.get(dir).unwrap() {
+            return Some(gnome.step(*dir))
+        }
+    }
+
+    // We're surrounded, so no move is possible
+    None
+}
+
+// correct answer for step 1: 3812
+// correct answer for step 2: 1003
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([.#]{0,8}\n){0,5}[.#a ]{0,8}") {
+            prop_assert!(parse::error_points_into(&Day23::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(rows in prop::collection::vec(prop::collection::vec(any::<bool>(), 1..10), 1..10)) {
+            let input: String = rows.iter()
+                .map(|row| row.iter().map(|&gnome| if gnome { '#' } else { '.' }).collect::<String>() + "\n")
+                .collect();
+            let gnomes: Vec<Point> = rows.iter().enumerate()
+                .flat_map(|(y, row)| {
+                    row.iter().enumerate().filter(|(_, gnome)| **gnome).map(move |(x, _)| Point::new(x as i64, y as i64))
+                })
+                .collect();
+            prop_assert_eq!(Day23::parse(&input).unwrap(), gnomes);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use std::collections::HashSet;
+use aoc_common::generate::{Generator, IndexedRandom, Rng, StdRng};
+use aoc_common::{Answer, Direction, Point, Solver};
+use crate::{Day24, Valley};
+
+impl Generator for Day24 {
+    // Height of the valley (without the walls), it is five times wider than that
+    const DEFAULT_SIZE: usize = 25;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // Crowded valleys can be impassable, and the puzzle promises a way there, back and there again
+        loop {
+            let input = valley(rng, size);
+            let valley = Day24::parse(&input).expect("generated valleys are valid");
+            if brute_force_trips(&valley, 3).is_some() {
+                return input;
+            }
+        }
+    }
+
+    fn reference(valley: &Valley, part: u8) -> Option<Answer> {
+        brute_force_trips(valley, if part == 1 { 1 } else { 3 }).map(Answer::from)
+    }
+}
+
+// Moves the blizzards one tile at a time and keeps track of every tile we could be on at each minute,
+// for the given number of trips between the entrance and the exit (the first one starting at the
+// entrance). None when one of the trips can't be made.
+fn brute_force_trips(valley: &Valley, trips: usize) -> Option<i32> {
+    let (width, height) = (valley.width, valley.height);
+    let mut blizzards: Vec<(Point, Direction)> = valley.vortexes.iter().map(|v| (v.pos, v.direction)).collect();
+    // The blizzards are back where they started after this many minutes
+    let period = width * height;
+
+    let (mut from, mut to) = (valley.start, valley.end);
+    let mut minute = 0;
+    for _ in 0..trips {
+        let mut reachable = HashSet::from([from]);
+        // We can wait at the start, so a full period later we can at least be everywhere we could
+        // be a period earlier. Once that stops growing, the trip can't be made.
+        let mut a_period_ago = reachable.clone();
+        for waited in 1.. {
+            for (pos, direction) in blizzards.iter_mut() {
+                let next = pos.step(*direction);
+                *pos = Point::new(next.x.rem_euclid(width), next.y.rem_euclid(height));
+            }
+            minute += 1;
+
+            let blizzard_tiles: HashSet<Point> = blizzards.iter().map(|(pos, _)| *pos).collect();
+            let open = |pos: &Point| {
+                let in_valley = pos.x >= 0 && pos.x < width && pos.y >= 0 && pos.y < height;
+                (in_valley || *pos == from || *pos == to) && !blizzard_tiles.contains(pos)
+            };
+            reachable = reachable.iter()
+                .flat_map(|pos| pos.neighbors().into_iter().chain(Some(*pos)))
+                .filter(open)
+                .collect();
+
+            if reachable.contains(&to) {
+                break;
+            }
+            if waited % period == 0 {
+                if reachable == a_period_ago {
+                    return None;
+                }
+                a_period_ago = reachable.clone();
+            }
+        }
+        (from, to) = (to, from);
+    }
+    Some(minute)
+}
+
+fn valley(rng: &mut StdRng, size: usize) -> String {
+    let height = size.max(1);
+    let width = 5 * height;
+
+    // The entrance is in the first column and the exit is in the last one
+    let wall = |gap: usize| -> String {
+        (0..width + 2).map(|x| if x == gap { '.' } else { '#' }).chain(Some('\n')).collect()
+    };
+
+    let mut input = wall(1);
+    for _ in 0..height {
+        input.push('#');
+        for x in 1..=width {
+            // Vertical blizzards would leak out through the entrance and the exit
+            let blizzards: &[char] = if x == 1 || x == width { &['<', '>'] } else { &['<', '>', '^', 'v'] };
+            let tile = match rng.random_bool(0.5) {
+                true => *blizzards.choose(rng).unwrap(),
+                false => '.',
+            };
+            input.push(tile);
+        }
+        input.push_str("#\n");
+    }
+    input + &wall(width)
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..5 {
+            let valley = Day24::parse(&generate::<Day24>(seed, 10)).unwrap();
+            assert_eq!((valley.width, valley.height), (50, 10));
+            Day24::part1(&valley).unwrap();
+            Day24::part2(&valley).unwrap();
+        }
+    }
+
+    #[test]
+    fn generated_inputs_match_the_reference() {
+        for seed in 0..5 {
+            let valley = Day24::parse(&generate::<Day24>(seed, 10)).unwrap();
+            assert_eq!(Day24::reference(&valley, 1), Some(Day24::part1(&valley).unwrap()));
+            assert_eq!(Day24::reference(&valley, 2), Some(Day24::part2(&valley).unwrap()));
+        }
+    }
+
+    // The greedy depth-first search day24 used to have never finished on this one
+    #[test]
+    fn crowded_small_valleys_are_solved() {
+        let valley = Day24::parse(&generate::<Day24>(1, 5)).unwrap();
+        assert_eq!(Day24::reference(&valley, 2), Some(Day24::part2(&valley).unwrap()));
+    }
+
+    #[test]
+    fn reference_matches_the_demo() {
+        let valley = Day24::parse(include_str!("../demo-input.txt")).unwrap();
+        assert_eq!(Day24::reference(&valley, 1), Some(Answer::Number(18)));
+        assert_eq!(Day24::reference(&valley, 2), Some(Answer::Number(54)));
+    }
+
+    #[test]
+    fn walled_in_valleys_have_no_reference_answer() {
+        let valley = Day24::parse("#.###\n#>>>#\n###.#\n").unwrap();
+        assert_eq!(Day24::reference(&valley, 1), None);
+    }
+}
@@ -0,0 +1,281 @@
+use anyhow::{Context, Result};
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, trace, Answer, Direction, Point, Solver};
+use num_integer::lcm;
+use rustc_hash::FxHashSet;
+
+mod generate;
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Vortex {
+    pos: Point,
+    direction: Direction,
+    max: i32,
+}
+
+impl Vortex {
+    fn new(x: i32, y: i32, direction: Direction, max: i32) -> Vortex {
+        Vortex { pos: Point::new(x, y), direction, max }
+    }
+
+    fn position_at_minute(&self, minute: i32) -> Point {
+        let mut pos = self.pos;
+        match self.direction {
+            Direction::Up => pos.y = coord_sub(pos.y, minute, self.max),
+            Direction::Down => pos.y = coord_add(pos.y, minute, self.max),
+            Direction::Right => pos.x = coord_add(pos.x, minute, self.max),
+            Direction::Left => pos.x = coord_sub(pos.x, minute, self.max),
+        };
+        pos
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+struct Step {
+    pos: Point,
+    minute: i32,
+}
+
+impl Step {
+    fn neighbors(&self) -> Vec<Step> {
+        let minute = self.minute + 1;
+        vec![
+            Step { pos: self.pos.step(Direction::Right), minute },
+            Step { pos: self.pos.step(Direction::Down), minute },
+            Step { pos: self.pos.step(Direction::Left), minute },
+            Step { pos: self.pos.step(Direction::Up), minute },
+            Step { pos: self.pos, minute }, // wait
+        ]
+    }
+}
+
+pub struct Valley {
+    vortexes: Vec<Vortex>,
+    width: i32,
+    height: i32,
+    start: Point,
+    end: Point,
+}
+
+impl Valley {
+    fn trip(&self, start: &Point, goal: &Point, start_min: i32) -> Result<i32> {
+        fastest_trip_duration(start, goal, &self.vortexes, self.width, self.height, start_min)
+            .with_context(|| format!("no way through the valley from {:?} to {:?}", start, goal))
+    }
+}
+
+pub struct Day24;
+
+impl Solver for Day24 {
+    const DAY: u8 = 24;
+    type Input = Valley;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let lines: Vec<Line> = parse::lines(input).collect();
+        let first_line = parse::lines(input).next_line("a map of the valley")?;
+        let last_line = lines.last().unwrap_or(&first_line);
+
+        let map_width = first_line.text.chars().count() as i32 - 2;
+        let map_height = lines.len() as i32 - 2;
+
+        let mut vortexes = Vec::new();
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.text.chars().enumerate() {
+                let column = x + 1;
+                let x = x as i32 - 1;
+                let y = y as i32 - 1;
+                if let Some(direction) = Direction::from_char(c) {
+                    let max = if direction.is_horizontal() { map_width } else { map_height };
+                    vortexes.push(Vortex::new(x, y, direction, max));
+                } else if c != '.' && c != '#' {
+                    return Err(line.error(column, "a wall (#), an empty tile (.) or a blizzard (<, >, ^ or v)").into());
+                }
+            }
+        }
+
+        // Find start and stop positions (empty cells in first and last rows)
+        let start_x = first_line.text.chars().position(|c| c == '.').ok_or_else(|| first_line.error(1, "an entrance (.)"))? as i32 - 1;
+        let start = Point::new(start_x, -1);
+
+        let end_x = last_line.text.chars().position(|c| c == '.').ok_or_else(|| last_line.error(1, "an exit (.)"))? as i32 - 1;
+        let end = Point::new(end_x, map_height);
+
+        debug!("Map size: {}x{}", map_width, map_height);
+        debug!("Start: {:?}", start);
+        debug!("End: {:?}", end);
+
+        Ok(Valley { vortexes, width: map_width, height: map_height, start, end })
+    }
+
+    fn part1(valley: &Self::Input) -> Result<Answer> {
+        let entrance_to_exit = valley.trip(&valley.start, &valley.end, 0)?;
+        debug!("Path duration (entrance to exit): {}", entrance_to_exit);
+        Ok(entrance_to_exit.into())
+    }
+
+    fn part2(valley: &Self::Input) -> Result<Answer> {
+        let entrance_to_exit = valley.trip(&valley.start, &valley.end, 0)?;
+        debug!("Path duration (entrance to exit): {}", entrance_to_exit);
+
+        let exit_to_entrance = valley.trip(&valley.end, &valley.start, entrance_to_exit)?;
+        debug!("Path duration (to pick up snacks): {}", exit_to_entrance);
+
+        let final_exit = valley.trip(&valley.start, &valley.end, exit_to_entrance)?;
+        debug!("Total duration: {}", final_exit);
+        Ok(final_exit.into())
+    }
+}
+
+fn fastest_trip_duration(start: &Point, goal: &Point, vortexes: &[Vortex], map_width: i32, map_height: i32, start_min: i32) -> Option<i32> {
+    // Vortex positions repeat every vortex_cycle minutes, so being at the same position
+    // at the same point of the cycle again can not lead anywhere new
+    let vortex_cycle = lcm(map_height, map_width);
+    let mut visited = FxHashSet::default();
+
+    // All the positions we could reach by the current minute and have not been at before
+    let mut positions = vec![*start];
+    let mut minute = start_min;
+
+    while !positions.is_empty() {
+        minute += 1;
+        let vortexes_now: FxHashSet<Point> = vortexes.iter().map(|v| v.position_at_minute(minute)).collect();
+
+        let mut next_positions = Vec::new();
+        for pos in positions.iter() {
+            let step = Step { pos: *pos, minute: minute - 1 };
+            for next in step.neighbors() {
+                if next.pos == *goal {
+                    trace!("Reached the end {:?} in {} minutes", next.pos, next.minute);
+                    debug!("Considered steps: {}", visited.len());
+                    return Some(next.minute);
+                }
+
+                // The start is the only place outside of the valley where we can wait
+                let in_valley = next.pos.x >= 0 && next.pos.x < map_width && next.pos.y >= 0 && next.pos.y < map_height;
+                if !in_valley && next.pos != *start { continue }
+                if vortexes_now.contains(&next.pos) { continue }
+
+                if visited.insert((next.pos, minute % vortex_cycle)) {
+                    next_positions.push(next.pos);
+                }
+            }
+        }
+        positions = next_positions;
+    }
+
+    None
+}
+
+// Substracts change from coord, wrapping around at 0 and going to dimension_limit-1
+fn coord_sub(coord: i32, change: i32, dimension_limit: i32) -> i32 {
+    let change = change % dimension_limit;
+    if coord >= change {
+        coord - change
+    } else {
+        dimension_limit - (change - coord)
+    }
+}
+
+// Adds change to coord, wrapping around at dimension_limit
+fn coord_add(coord: i32, change: i32, dimension_limit: i32) -> i32 {
+    (coord + change) % dimension_limit
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    // The inside of the valley and the columns of its entrance and exit
+    fn valley() -> impl Strategy<Value = (Vec<String>, usize, usize)> {
+        (1..10usize, 1..8usize).prop_flat_map(|(width, height)| {
+            let rows = prop::collection::vec(prop::collection::vec(prop::sample::select(vec!['.', '<', '>', '^', 'v']), width), height)
+                .prop_map(|rows| rows.into_iter().map(|row| row.into_iter().collect()).collect());
+            (rows, 0..width, 0..width)
+        })
+    }
+
+    fn wall(width: usize, opening: usize) -> String {
+        (0..width + 2).map(|x| if x == opening + 1 { '.' } else { '#' }).collect()
+    }
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "(#[.#<>^v]{0,6}#\n){0,5}[.#<>^va ]{0,6}") {
+            prop_assert!(parse::error_points_into(&Day24::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip((rows, entrance, exit) in valley()) {
+            let width = rows[0].len();
+            let mut input = wall(width, entrance) + "\n";
+            for row in rows.iter() {
+                input += &format!("#{}#\n", row);
+            }
+            input += &(wall(width, exit) + "\n");
+
+            let valley = Day24::parse(&input).unwrap();
+            prop_assert_eq!(valley.width, width as i32);
+            prop_assert_eq!(valley.height, rows.len() as i32);
+            prop_assert_eq!(valley.start, Point::new(entrance as i32, -1));
+            prop_assert_eq!(valley.end, Point::new(exit as i32, rows.len() as i32));
+
+            let blizzards: Vec<(Point, Direction)> = rows.iter().enumerate()
+                .flat_map(|(y, row)| {
+                    row.chars().enumerate().filter_map(move |(x, c)| {
+                        Direction::from_char(c).map(|direction| (Point::new(x as i32, y as i32), direction))
+                    })
+                })
+                .collect();
+            let parsed: Vec<(Point, Direction)> = valley.vortexes.iter().map(|vortex| (vortex.pos, vortex.direction)).collect();
+            prop_assert_eq!(parsed, blizzards);
+        }
+    }
+
+    #[test]
+    fn walled_in_valleys_are_an_error() {
+        let valley = Day24::parse("#.###\n#>>>#\n###.#\n").unwrap();
+        assert!(Day24::part1(&valley).is_err());
+    }
+
+    // #..x.....#
+    //  01234567
+    // limit = 8
+
+    #[test]
+    fn coord_sub() {
+        assert_eq!(super::coord_sub(3, 1, 8), 2);
+        assert_eq!(super::coord_sub(3, 2, 8), 1);
+        assert_eq!(super::coord_sub(3, 3, 8), 0);
+        assert_eq!(super::coord_sub(3, 4, 8), 7);
+        assert_eq!(super::coord_sub(3, 5, 8), 6);
+        assert_eq!(super::coord_sub(3, 6, 8), 5);
+        assert_eq!(super::coord_sub(3, 7, 8), 4);
+        assert_eq!(super::coord_sub(3, 8, 8), 3);
+        assert_eq!(super::coord_sub(3, 9, 8), 2);
+        assert_eq!(super::coord_sub(3, 10, 8), 1);
+        assert_eq!(super::coord_sub(3, 11, 8), 0);
+    }
+
+    #[test]
+    fn coord_add() {
+        assert_eq!(super::coord_add(3, 1, 8), 4);
+        assert_eq!(super::coord_add(3, 2, 8), 5);
+        assert_eq!(super::coord_add(3, 3, 8), 6);
+        assert_eq!(super::coord_add(3, 4, 8), 7);
+        assert_eq!(super::coord_add(3, 5, 8), 0);
+        assert_eq!(super::coord_add(3, 6, 8), 1);
+        assert_eq!(super::coord_add(3, 7, 8), 2);
+        assert_eq!(super::coord_add(3, 8, 8), 3);
+        assert_eq!(super::coord_add(3, 9, 8), 4);
+        assert_eq!(super::coord_add(3, 10, 8), 5);
+        assert_eq!(super::coord_add(3, 11, 8), 6);
+    }
+}
+
+// Real tests:
+// 217 is too low
+// 218 incorrect
+// 292 should be correct
+//
+// Stage2: 816
@@ -0,0 +1,27 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::{int_to_snafu, Day25};
+
+impl Generator for Day25 {
+    // Number of fuel requirements
+    const DEFAULT_SIZE: usize = 120;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size).map(|_| int_to_snafu(rng.random_range(1..1_000_000_000_000)) + "\n").collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let numbers = Day25::parse(&generate::<Day25>(seed, 100)).unwrap();
+            assert_eq!(numbers.len(), 100);
+            Day25::part1(&numbers).unwrap();
+        }
+    }
+}
@@ -0,0 +1,171 @@
+use anyhow::{bail, Context, Result};
+use aoc_common::{debug, parse, trace, Answer, Solver};
+
+mod generate;
+
+fn snafu_to_int(s: &str) -> i64 {
+    let mut res: i64 = 0;
+    for (pos, c) in s.chars().rev().enumerate() {
+        res += snafu_digit_to_int(c) * 5_i64.pow(pos as u32);
+    }
+    res
+}
+
+fn snafu_digit_to_int(c: char) -> i64 {
+    match c {
+        '2' => 2,
+        '1' => 1,
+        '0' => 0,
+        '-' => -1,
+        '=' => -2,
+        _ => panic!("Invalid snafu digit: {}", c),
+    }
+}
+
+fn int_to_snafu(mut n: i64) -> String {
+    let mut res = String::new();
+    let mut overflow = 0;
+    let mut snafu_digit: char;
+
+    while n != 0 {
+        (overflow, snafu_digit) = int_to_snafu_digit(n % 5);
+        res.push(snafu_digit);
+        n = n / 5 + overflow;
+    }
+    if overflow != 0 { res.push('1')  }
+    res.chars().rev().collect()
+}
+
+fn int_to_snafu_digit(digit: i64) -> (i64, char) {
+    match digit {
+        0 => (0, '0'),
+        1 => (0, '1'),
+        2 => (0, '2'),
+        3 => (1, '='),
+        4 => (1, '-'),
+        _ => panic!("Invalid integer for converting to snafu: {}", digit),
+    }
+}
+
+// Longer numbers do not fit into i64
+const MAX_DIGITS: usize = 27;
+
+pub struct Day25;
+
+impl Solver for Day25 {
+    const DAY: u8 = 25;
+    // The second star is given for completing all the other days
+    const PARTS: &'static [u8] = &[1];
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut numbers = Vec::new();
+        for line in parse::lines(input) {
+            if let Some(pos) = line.text.find(|c| !"210-=".contains(c)) {
+                return Err(line.error_at(&line.text[pos..], "a SNAFU digit (2, 1, 0, - or =)").into());
+            }
+            // Fuel requirements are positive, and so is a number starting with 1 or 2 whatever follows
+            if !line.text.starts_with(['1', '2']) {
+                return Err(line.error(1, "a positive SNAFU number (starting with 1 or 2)").into());
+            }
+            if line.text.len() > MAX_DIGITS {
+                return Err(line.error(MAX_DIGITS + 1, format!("at most {} SNAFU digits", MAX_DIGITS)).into());
+            }
+            numbers.push(line.text.to_string());
+        }
+        Ok(numbers)
+    }
+
+    fn part1(lines: &Self::Input) -> Result<Answer> {
+        let mut sum = 0_i64;
+        for line in lines {
+            let n = snafu_to_int(line);
+            trace!("{} -> {}", line, n);
+            sum = sum.checked_add(n).context("the sum of the fuel requirements does not fit into i64")?;
+        }
+        debug!("Sum: {}", sum);
+        Ok(int_to_snafu(sum).into())
+    }
+
+    // There is no puzzle for the second part on the last day
+    fn part2(_lines: &Self::Input) -> Result<Answer> {
+        bail!("day 25 has no second part")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use crate::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([210=-]{0,30}\n){0,5}[210=3a é-]{0,8}") {
+            prop_assert!(parse::error_points_into(&Day25::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(numbers in prop::collection::vec("[12][210=-]{0,26}", 0..10)) {
+            let input: String = numbers.iter().map(|number| format!("{}\n", number)).collect();
+            prop_assert_eq!(Day25::parse(&input).unwrap(), numbers);
+        }
+
+        #[test]
+        fn snafu_round_trip(n in 1..i64::MAX / 4) {
+            prop_assert_eq!(snafu_to_int(&int_to_snafu(n)), n);
+        }
+    }
+
+    #[test]
+    fn rejects_negative_numbers() {
+        for (input, line) in [("1\n-\n", 2), ("=1\n", 1), ("0-\n", 1), ("2\n\n", 2)] {
+            let err = Day25::parse(input).unwrap_err().downcast::<parse::ParseError>().unwrap();
+            assert_eq!((err.line, err.column), (line, 1));
+        }
+    }
+
+    #[test]
+    fn sum_overflow_is_an_error() {
+        let numbers = Day25::parse(&format!("{}\n", "2".repeat(MAX_DIGITS)).repeat(3)).unwrap();
+        assert!(Day25::part1(&numbers).is_err());
+    }
+
+    #[test]
+    fn snafu_to_int_test() {
+        assert_eq!(snafu_to_int("1"), 1);
+        assert_eq!(snafu_to_int("2"), 2);
+        assert_eq!(snafu_to_int("1="), 3);
+        assert_eq!(snafu_to_int("1-"), 4);
+        assert_eq!(snafu_to_int("10"), 5);
+        assert_eq!(snafu_to_int("11"), 6);
+        assert_eq!(snafu_to_int("12"), 7);
+        assert_eq!(snafu_to_int("2="), 8);
+        assert_eq!(snafu_to_int("2-"), 9);
+        assert_eq!(snafu_to_int("20"), 10);
+        assert_eq!(snafu_to_int("1=0"), 15);
+        assert_eq!(snafu_to_int("1-0"), 20);
+        assert_eq!(snafu_to_int("1=11-2"), 2022);
+        assert_eq!(snafu_to_int("1-0---0"), 12345);
+        assert_eq!(snafu_to_int("1121-1110-1=0"), 314159265);
+    }
+
+    #[test]
+    fn int_to_snafu_test() {
+        assert_eq!(int_to_snafu(1), "1");
+        assert_eq!(int_to_snafu(2), "2");
+        assert_eq!(int_to_snafu(3), "1=");
+        assert_eq!(int_to_snafu(4), "1-");
+        assert_eq!(int_to_snafu(5), "10");
+        assert_eq!(int_to_snafu(6), "11");
+        assert_eq!(int_to_snafu(7), "12");
+        assert_eq!(int_to_snafu(8), "2=");
+        assert_eq!(int_to_snafu(9), "2-");
+        assert_eq!(int_to_snafu(10), "20");
+        assert_eq!(int_to_snafu(15), "1=0");
+        assert_eq!(int_to_snafu(20), "1-0");
+        assert_eq!(int_to_snafu(2022), "1=11-2");
+        assert_eq!(int_to_snafu(12345), "1-0---0");
+        assert_eq!(int_to_snafu(314159265), "1121-1110-1=0");
+    }
+
+}
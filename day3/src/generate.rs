@@ -0,0 +1,62 @@
+use aoc_common::generate::{Generator, IndexedRandom, Rng, SliceRandom, StdRng};
+use crate::Day3;
+
+const ITEMS: &str = "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+// Both compartments of the bag share exactly one item, and the badge is in the first one
+fn bag(rng: &mut StdRng, badge: char, pool: &[char]) -> String {
+    let mut pool = pool.to_vec();
+    pool.shuffle(rng);
+    let (shared, rest) = pool.split_first().unwrap();
+    let (first_items, second_items) = rest.split_at(rest.len() / 2);
+
+    let len = rng.random_range(4..=16);
+    let mut first = vec![*shared, badge];
+    first.extend((2..len).map(|_| *first_items.choose(rng).unwrap()));
+    let mut second = vec![*shared];
+    second.extend((1..len).map(|_| *second_items.choose(rng).unwrap()));
+    first.shuffle(rng);
+    second.shuffle(rng);
+
+    first.into_iter().chain(second).collect()
+}
+
+impl Generator for Day3 {
+    // Number of groups of three elves
+    const DEFAULT_SIZE: usize = 100;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let items: Vec<char> = ITEMS.chars().collect();
+        let mut input = String::new();
+        for _ in 0..size {
+            let badge = *items.choose(rng).unwrap();
+            for elf in 0..3 {
+                // Every other item is missing from one of the bags, so the badge is the only one all three have
+                let pool: Vec<char> = items.iter().enumerate()
+                    .filter(|&(i, &item)| item != badge && i % 3 != elf)
+                    .map(|(_, &item)| item)
+                    .collect();
+                input += &bag(rng, badge, &pool);
+                input.push('\n');
+            }
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let bags = Day3::parse(&generate::<Day3>(seed, 5)).unwrap();
+            assert_eq!(bags.len(), 15);
+            Day3::part1(&bags).unwrap();
+            Day3::part2(&bags).unwrap();
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use anyhow::{bail, Result};
+use aoc_common::{debug, parse, trace, Answer, Solver};
+
+mod generate;
+
+pub struct Day3;
+
+impl Solver for Day3 {
+  const DAY: u8 = 3;
+  type Input = Vec<String>;
+
+  fn parse(input: &str) -> Result<Self::Input> {
+    let mut bags = Vec::new();
+    for line in parse::lines(input) {
+      // Item priorities are only defined for letters
+      if let Some(pos) = line.text.find(|c: char| !c.is_ascii_alphabetic()) {
+        return Err(line.error(pos + 1, "an item (a-z or A-Z)").into());
+      }
+      bags.push(line.text.to_string());
+    }
+    Ok(bags)
+  }
+
+  // Each bag has two equally sized compartments, find the item present in both of them
+  fn part1(bags: &Self::Input) -> Result<Answer> {
+    let mut misplaced_items: Vec<char> = Vec::new();
+
+    for bag in bags {
+      let (compartment1, compartment2) = bag.split_at(bag.len() / 2);
+      match compartment1.chars().find(|c| compartment2.contains(*c)) {
+        Some(item) => misplaced_items.push(item),
+        None => bail!("Found no common item in compartments of bag: {}", bag),
+      }
+    }
+
+    Ok(sum_priorities(misplaced_items).into())
+  }
+
+  fn part2(bags: &Self::Input) -> Result<Answer> {
+    let mut misplaced_items: Vec<char> = Vec::new();
+
+    for bags in bags.chunks_exact(3) {
+      // Check for items that exist in all 3 bags
+      let mut common_item: Option<char> = None;
+      for c1 in bags[0].chars() {
+        for c2 in bags[1].chars() {
+          for c3 in bags[2].chars() {
+            if c1 == c2 && c2 == c3 {
+              common_item = Some(c1);
+            }
+          }
+        }
+      }
+
+      // Add the common item to the misplaced items list
+      match common_item {
+        Some(item) => misplaced_items.push(item),
+        None => bail!("Found no common item in bags: {}, {}, {}", bags[0], bags[1], bags[2]),
+      }
+    }
+
+    Ok(sum_priorities(misplaced_items).into())
+  }
+}
+
+fn sum_priorities(items: Vec<char>) -> u32 {
+  let mut priorities_sum: u32 = 0;
+  for item in items {
+    let item_priority = calculate_item_priority(item);
+    trace!("{}: {}", item, item_priority);
+    priorities_sum += item_priority;
+  }
+  debug!("Sum of priorities: {}", priorities_sum);
+  priorities_sum
+}
+
+// a-z priorities are 1-26
+// A-Z priorities are 27-52
+fn calculate_item_priority(item: char) -> u32 {
+  if item.is_uppercase() {
+    item as u32 - 38
+  } else {
+    item as u32 - 96
+  }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+      #[test]
+      fn parse_errors_point_into_the_input(input in "([a-zA-Z]{0,8}\n){0,5}[a-zA-Z0-9 é]{0,8}") {
+        prop_assert!(parse::error_points_into(&Day3::parse(&input), &input));
+      }
+
+      #[test]
+      fn round_trip(bags in prop::collection::vec("[a-zA-Z]{1,20}", 0..10)) {
+        let input: String = bags.iter().map(|bag| format!("{}\n", bag)).collect();
+        prop_assert_eq!(Day3::parse(&input).unwrap(), bags);
+      }
+    }
+
+    #[test]
+    fn item_priority() {
+      assert_eq!(super::calculate_item_priority('a'), 1);
+      assert_eq!(super::calculate_item_priority('z'), 26);
+      assert_eq!(super::calculate_item_priority('A'), 27);
+      assert_eq!(super::calculate_item_priority('Z'), 52);
+    }
+}
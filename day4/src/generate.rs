@@ -0,0 +1,32 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day4;
+
+fn range(rng: &mut StdRng) -> String {
+    let start = rng.random_range(1..100);
+    format!("{}-{}", start, rng.random_range(start..100))
+}
+
+impl Generator for Day4 {
+    // Number of pairs of elves
+    const DEFAULT_SIZE: usize = 1000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size).map(|_| format!("{},{}\n", range(rng), range(rng))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let pairs = Day4::parse(&generate::<Day4>(seed, 20)).unwrap();
+            assert_eq!(pairs.len(), 20);
+            assert!(pairs.iter().all(|(a, b)| a.start <= a.end && b.start <= b.end));
+        }
+    }
+}
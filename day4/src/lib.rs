@@ -0,0 +1,90 @@
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, Answer, ParseError, Solver};
+
+mod generate;
+
+#[derive(Debug, PartialEq)]
+pub struct Range {
+    start: u32,
+    end: u32
+}
+
+impl Range {
+    fn from_str(line: &Line, range_str: &str) -> Result<Self, ParseError> {
+        let (begin_str, end_str) = range_str.split_once('-').ok_or_else(|| line.error_at(range_str, "a range (like 2-4)"))?;
+
+        Ok(Range {
+            start: line.parse(begin_str, "a section number")?,
+            end: line.parse(end_str, "a section number")?
+        })
+    }
+
+    fn contains(&self, other: &Range) -> bool {
+        (self.start <= other.start && self.end >= other.end) || (other.start <= self.start && other.end >= self.end)
+    }
+
+    fn intersects(&self, other: &Range) -> bool {
+        other.contains(self) ||
+        (self.start <= other.start && self.end >= other.start) ||
+        (self.start <= other.end && self.end >= other.end)
+    }
+}
+
+pub struct Day4;
+
+impl Solver for Day4 {
+    const DAY: u8 = 4;
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut pairs = Vec::new();
+        for line in parse::lines(input) {
+            let (range1, range2) = line.text.split_once(',').ok_or_else(|| line.error_at_end("a pair of ranges"))?;
+            let range1 = Range::from_str(&line, range1)?;
+            let range2 = Range::from_str(&line, range2)?;
+            pairs.push((range1, range2));
+        }
+        Ok(pairs)
+    }
+
+    fn part1(pairs: &Self::Input) -> Result<Answer> {
+        let fully_contained = pairs.iter()
+            .filter(|(range1, range2)| range1.contains(range2) || range2.contains(range1))
+            .count();
+
+        debug!("Fully contained pairs: {}", fully_contained);
+        Ok(fully_contained.into())
+    }
+
+    fn part2(pairs: &Self::Input) -> Result<Answer> {
+        let intersecting = pairs.iter()
+            .filter(|(range1, range2)| range1.intersects(range2))
+            .count();
+
+        debug!("Intersecting pairs: {}", intersecting);
+        Ok(intersecting.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([0-9,-]{0,10}\n){0,5}[0-9,x -]{0,10}") {
+            prop_assert!(parse::error_points_into(&Day4::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(pairs in prop::collection::vec((0..100u32, 0..100u32, 0..100u32, 0..100u32), 0..10)) {
+            let input: String = pairs.iter().map(|(a, b, c, d)| format!("{}-{},{}-{}\n", a, b, c, d)).collect();
+            let expected: Vec<(Range, Range)> = pairs.iter()
+                .map(|&(a, b, c, d)| (Range { start: a, end: b }, Range { start: c, end: d }))
+                .collect();
+            prop_assert_eq!(Day4::parse(&input).unwrap(), expected);
+        }
+    }
+}
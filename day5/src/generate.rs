@@ -0,0 +1,59 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day5;
+
+const STACK_COUNT: usize = 9;
+
+impl Generator for Day5 {
+    // Number of moves
+    const DEFAULT_SIZE: usize = 500;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let mut stacks: Vec<Vec<char>> = (0..STACK_COUNT)
+            .map(|_| (0..rng.random_range(1..=8)).map(|_| rng.random_range('A'..='Z')).collect())
+            .collect();
+
+        let height = stacks.iter().map(|stack| stack.len()).max().unwrap();
+        let mut input = String::new();
+        for level in (0..height).rev() {
+            let crates: Vec<String> = stacks.iter()
+                .map(|stack| stack.get(level).map_or("   ".to_string(), |name| format!("[{}]", name)))
+                .collect();
+            input += &format!("{}\n", crates.join(" "));
+        }
+        let numbers: Vec<String> = (1..=STACK_COUNT).map(|n| format!(" {} ", n)).collect();
+        input += &format!("{}\n\n", numbers.join(" "));
+
+        // The moves are simulated, so that the crane never takes more crates than there are on a stack
+        for _ in 0..size {
+            let src = loop {
+                let src = rng.random_range(0..STACK_COUNT);
+                if !stacks[src].is_empty() { break src }
+            };
+            let dst = (src + rng.random_range(1..STACK_COUNT)) % STACK_COUNT;
+            let count = rng.random_range(1..=stacks[src].len());
+
+            let remaining = stacks[src].len() - count;
+            let moved = stacks[src].split_off(remaining);
+            stacks[dst].extend(moved);
+            input += &format!("move {} from {} to {}\n", count, src + 1, dst + 1);
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let supplies = Day5::parse(&generate::<Day5>(seed, 30)).unwrap();
+            assert_eq!(supplies.moves.len(), 30);
+            Day5::part1(&supplies).unwrap();
+            Day5::part2(&supplies).unwrap();
+        }
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, trace, Answer, ParseError, Solver};
+
+mod generate;
+
+type Stack = Vec<char>;
+
+#[derive(Debug, PartialEq)]
+pub struct Move {
+    count: usize,
+    src_idx: usize,
+    dst_idx: usize,
+}
+
+pub struct Supplies {
+    stacks: Vec<Stack>,
+    moves: Vec<Move>,
+}
+
+impl Supplies {
+    // Executes all the moves, picking up multiple crates at once if the crane can do it
+    fn rearrange(&self, crates_at_once: bool) -> String {
+        let mut stacks = self.stacks.clone();
+
+        for command in self.moves.iter() {
+            let src = &mut stacks[command.src_idx-1];
+            let mut items_to_move = src.split_off(src.len() - command.count);
+            if !crates_at_once {
+                items_to_move.reverse();
+            }
+            stacks[command.dst_idx-1].append(&mut items_to_move);
+        }
+
+        let mut top_items: Vec<char> = Vec::new();
+        for stack in stacks {
+            top_items.extend(stack.last());
+        }
+
+        let result: String = top_items.iter().collect();
+        debug!("Result: {}", result);
+        result
+    }
+}
+
+pub struct Day5;
+
+impl Solver for Day5 {
+    const DAY: u8 = 5;
+    type Input = Supplies;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut lines = parse::lines(input);
+
+        let mut diagram_lines: Vec<Line> = Vec::new();
+        loop {
+            let line = lines.next_line("an empty line after the diagram")?;
+            if line.text.is_empty() {
+                break
+            }
+            diagram_lines.push(line);
+        }
+
+        let stacks = parse_diagram(&mut diagram_lines)?;
+
+        let mut moves = Vec::new();
+        for line in lines {
+            let mut command_parts = line.text.split(' ');
+            let count: usize = line.parse_some(command_parts.nth(1), "a crate count")?;
+            let src_idx = parse_stack_number(&line, command_parts.nth(1), stacks.len())?;
+            let dst_idx = parse_stack_number(&line, command_parts.nth(1), stacks.len())?;
+            moves.push(Move { count, src_idx, dst_idx });
+        }
+
+        Ok(Supplies { stacks, moves })
+    }
+
+    // CrateMover 9000 moves crates one by one
+    fn part1(supplies: &Self::Input) -> Result<Answer> {
+        Ok(supplies.rearrange(false).into())
+    }
+
+    // CrateMover 9001 moves multiple crates at once
+    fn part2(supplies: &Self::Input) -> Result<Answer> {
+        Ok(supplies.rearrange(true).into())
+    }
+}
+
+fn parse_stack_number(line: &Line, part: Option<&str>, stack_count: usize) -> Result<usize, ParseError> {
+    let expected = format!("a stack number (1-{})", stack_count);
+    let part = part.ok_or_else(|| line.error_at_end(expected.as_str()))?;
+    let stack: usize = line.parse(part, expected.as_str())?;
+    if !(1..=stack_count).contains(&stack) {
+        return Err(line.error_at(part, expected));
+    }
+    Ok(stack)
+}
+
+fn parse_diagram(diagram_lines: &mut Vec<Line>) -> Result<Vec<Stack>, ParseError> {
+    let stack_numbers = diagram_lines.pop().ok_or_else(|| ParseError::new(1, 1, "a diagram of the stacks", ""))?;
+    let numbers: Vec<&str> = stack_numbers.text.split_whitespace().collect();
+    let stack_count: usize = stack_numbers.parse_some(numbers.last().copied(), "stack numbers")?;
+    // The stacks are numbered 1 to N, the last number tells how many of them there are
+    if stack_count != numbers.len() {
+        return Err(stack_numbers.error_at(numbers[numbers.len() - 1], format!("stack numbers 1-{}", numbers.len())));
+    }
+
+    diagram_lines.reverse();
+    trace!("Diagram:");
+    for line in diagram_lines.iter() {
+        trace!("{}", line.text);
+    }
+
+    let mut stacks: Vec<Stack> = Vec::new();
+    stacks.resize(stack_count, Stack::new());
+
+    for line in diagram_lines.iter() {
+        let line_stack_count = line.text.len() / 4;
+        for (i, stack) in stacks.iter_mut().enumerate().take(line_stack_count+1) {
+            let crate_name = line.text.chars().nth(i * 4 + 1).ok_or_else(|| line.error_at_end("a crate name"))?;
+            if crate_name != ' ' {
+                stack.push(crate_name);
+            }
+        }
+    }
+
+    trace!("Parsed stacks:");
+    for stack in &stacks {
+        trace!("{:?}", stack);
+    }
+
+    Ok(stacks)
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    // Draws the stacks the way the puzzle does, the top crates on the first line
+    fn draw_diagram(stacks: &[Stack]) -> String {
+        let height = stacks.iter().map(|stack| stack.len()).max().unwrap_or(0);
+        let mut diagram = String::new();
+        for level in (0..height).rev() {
+            let crates: Vec<String> = stacks.iter()
+                .map(|stack| stack.get(level).map_or("   ".to_string(), |name| format!("[{}]", name)))
+                .collect();
+            diagram += &format!("{}\n", crates.join(" "));
+        }
+        let numbers: Vec<String> = (1..=stacks.len()).map(|n| format!(" {} ", n)).collect();
+        diagram + &numbers.join(" ") + "\n"
+    }
+
+    fn supplies() -> impl Strategy<Value = (Vec<Stack>, Vec<(usize, usize, usize)>)> {
+        prop::collection::vec(prop::collection::vec(prop::char::range('A', 'Z'), 0..6), 1..10)
+            .prop_flat_map(|stacks| {
+                let count = stacks.len();
+                let moves = prop::collection::vec((1..10usize, 1..=count, 1..=count), 0..10);
+                (Just(stacks), moves)
+            })
+    }
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([\\[\\]A-Z ]{0,12}\n){0,4}( [0-9] ){0,4}\n\n(move [0-9] from [0-9] to [0-9]\n){0,3}[a-z0-9 ]{0,10}") {
+            prop_assert!(parse::error_points_into(&Day5::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip((stacks, moves) in supplies()) {
+            let mut input = draw_diagram(&stacks) + "\n";
+            for (count, src, dst) in moves.iter() {
+                input += &format!("move {} from {} to {}\n", count, src, dst);
+            }
+
+            let supplies = Day5::parse(&input).unwrap();
+            prop_assert_eq!(supplies.stacks, stacks);
+            let expected: Vec<Move> = moves.iter()
+                .map(|&(count, src_idx, dst_idx)| Move { count, src_idx, dst_idx })
+                .collect();
+            prop_assert_eq!(supplies.moves, expected);
+        }
+    }
+}
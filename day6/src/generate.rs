@@ -0,0 +1,56 @@
+use std::collections::HashSet;
+use aoc_common::generate::{Generator, Rng, SliceRandom, StdRng};
+use aoc_common::Answer;
+use crate::{Day6, MESSAGE_LEN, PACKET_LEN};
+
+impl Generator for Day6 {
+    // Length of the signal
+    const DEFAULT_SIZE: usize = 4096;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        let len = size.max(MESSAGE_LEN);
+        let mut signal: Vec<char> = (0..len).map(|_| rng.random_range('a'..='z')).collect();
+
+        // Make sure there is a message marker
+        let mut marker: Vec<char> = ('a'..='z').collect();
+        marker.shuffle(rng);
+        let start = rng.random_range(0..=len - MESSAGE_LEN);
+        signal[start..start + MESSAGE_LEN].copy_from_slice(&marker[..MESSAGE_LEN]);
+
+        signal.into_iter().chain(Some('\n')).collect()
+    }
+
+    // Puts every window of the generated signal into a set until one has no repeated character
+    fn reference(signals: &Vec<String>, part: u8) -> Option<Answer> {
+        let len = if part == 1 { PACKET_LEN } else { MESSAGE_LEN };
+        let [signal] = signals.as_slice() else { return None };
+        let chars: Vec<char> = signal.chars().collect();
+        let start = chars.windows(len).position(|window| window.iter().collect::<HashSet<_>>().len() == len)?;
+        Some((start + len).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let signals = Day6::parse(&generate::<Day6>(seed, 100)).unwrap();
+            assert_ne!(Day6::part1(&signals).unwrap(), Answer::Number(0));
+            assert_ne!(Day6::part2(&signals).unwrap(), Answer::Number(0));
+        }
+    }
+
+    #[test]
+    fn generated_inputs_match_the_reference() {
+        for seed in 0..10 {
+            let signals = Day6::parse(&generate::<Day6>(seed, 100)).unwrap();
+            assert_eq!(Day6::reference(&signals, 1), Some(Day6::part1(&signals).unwrap()));
+            assert_eq!(Day6::reference(&signals, 2), Some(Day6::part2(&signals).unwrap()));
+        }
+    }
+}
@@ -0,0 +1,110 @@
+use anyhow::Result;
+use aoc_common::{debug, parse, trace, Answer, Solver};
+
+mod generate;
+
+// Lengths of the start-of-packet and the start-of-message markers
+const PACKET_LEN: usize = 4;
+const MESSAGE_LEN: usize = 14;
+
+pub struct Day6;
+
+impl Solver for Day6 {
+    const DAY: u8 = 6;
+    // Demo input contains multiple signals, one per line
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut signals = Vec::new();
+        for line in parse::lines(input) {
+            // Shorter signals can't hold a start-of-message marker
+            if line.text.len() < MESSAGE_LEN {
+                return Err(line.error_at_end(format!("a signal of at least {} characters", MESSAGE_LEN)).into());
+            }
+            signals.push(line.text.to_string());
+        }
+        Ok(signals)
+    }
+
+    fn part1(signals: &Self::Input) -> Result<Answer> {
+        Ok(find_markers(signals, "Packet", PACKET_LEN))
+    }
+
+    fn part2(signals: &Self::Input) -> Result<Answer> {
+        Ok(find_markers(signals, "Message", MESSAGE_LEN))
+    }
+}
+
+// Real input is a single signal, for multiple signals we return a comma-separated list of positions
+fn find_markers(signals: &[String], marker: &str, segment_len: usize) -> Answer {
+    let mut positions = Vec::new();
+    for line in signals {
+        trace!("Analyzing line: {}", line);
+
+        let start = detect_unique_segment_start(line, segment_len);
+        debug!("{} start: {}", marker, start);
+        positions.push(start);
+    }
+
+    match positions.as_slice() {
+        [start] => (*start).into(),
+        _ => positions.iter().map(|p| p.to_string()).collect::<Vec<String>>().join(",").into(),
+    }
+}
+
+fn detect_unique_segment_start(line: &str, segment_len: usize) -> usize {
+    let chars = line.as_bytes().to_vec();
+    if chars.len() < segment_len { return 0 }
+    for start in 0..=chars.len()-segment_len {
+        let pos = start + segment_len;
+        let mut segment = chars[start..pos].to_vec();
+        segment.sort();
+
+        let mut valid_segment = true;
+        for p in 1..segment_len {
+            if segment[p-1] == segment[p] {
+                valid_segment = false;
+                break;
+            }
+        }
+        if valid_segment {
+            return pos;
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn round_trip(signals in prop::collection::vec("[a-z]{14,30}", 0..5)) {
+            let input: String = signals.iter().map(|signal| format!("{}\n", signal)).collect();
+            prop_assert_eq!(Day6::parse(&input).unwrap(), signals);
+        }
+
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([a-z]{0,20}\n){0,4}") {
+            prop_assert!(parse::error_points_into(&Day6::parse(&input), &input));
+        }
+    }
+
+    #[test]
+    fn rejects_short_signals() {
+        for input in ["\n", "abcdefghijklmnop\nabcd\n"] {
+            let err = Day6::parse(input).unwrap_err().downcast::<parse::ParseError>().unwrap();
+            assert_eq!((err.line, err.column), (input.lines().count(), input.lines().last().unwrap().len() + 1));
+        }
+    }
+
+    #[test]
+    fn finds_markers_ending_the_signal() {
+        assert_eq!(detect_unique_segment_start("aaaabcd", 4), 7);
+        assert_eq!(detect_unique_segment_start("abcd", 4), 4);
+        assert_eq!(detect_unique_segment_start("aaaa", 4), 0);
+        assert_eq!(detect_unique_segment_start("ab", 4), 0);
+    }
+}
@@ -0,0 +1,94 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::{Day7, SPACE_NEEDED, TOTAL_DISK_SIZE};
+
+struct Dir {
+    name: String,
+    files: Vec<(String, usize)>,
+    sub_dirs: Vec<Dir>,
+}
+
+fn name(rng: &mut StdRng, index: usize) -> String {
+    let len = rng.random_range(1..=6);
+    let letters: String = (0..len).map(|_| rng.random_range('a'..='z')).collect();
+    // The index keeps the names unique within a directory
+    format!("{}{}", letters, index)
+}
+
+// Spreads the given number of directories randomly below the new one
+fn dir(rng: &mut StdRng, name: String, dir_count: usize, max_file_size: usize) -> Dir {
+    let files = (0..rng.random_range(0..=4))
+        .map(|i| {
+            let extension = if rng.random_bool(0.5) { ".txt" } else { "" };
+            (format!("{}{}", self::name(rng, i), extension), rng.random_range(1000..=max_file_size))
+        })
+        .collect();
+
+    let mut sub_dirs = Vec::new();
+    let mut remaining = dir_count;
+    while remaining > 0 {
+        let below = rng.random_range(0..remaining);
+        let sub_dir_name = self::name(rng, sub_dirs.len());
+        sub_dirs.push(dir(rng, sub_dir_name, below, max_file_size));
+        remaining -= below + 1;
+    }
+    Dir { name, files, sub_dirs }
+}
+
+fn total_size(dir: &Dir) -> usize {
+    dir.files.iter().map(|(_, size)| size).sum::<usize>() + dir.sub_dirs.iter().map(total_size).sum::<usize>()
+}
+
+// Lists the directory and then walks into each of its subdirectories
+fn write_session(dir: &Dir, output: &mut String) {
+    *output += "$ ls\n";
+    for sub_dir in dir.sub_dirs.iter() {
+        *output += &format!("dir {}\n", sub_dir.name);
+    }
+    for (name, size) in dir.files.iter() {
+        *output += &format!("{} {}\n", size, name);
+    }
+    for sub_dir in dir.sub_dirs.iter() {
+        *output += &format!("$ cd {}\n", sub_dir.name);
+        write_session(sub_dir, output);
+        *output += "$ cd ..\n";
+    }
+}
+
+impl Generator for Day7 {
+    // Number of directories
+    const DEFAULT_SIZE: usize = 200;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        // The disk has to be full enough to need some space freed, but not overflowing
+        let min_used = TOTAL_DISK_SIZE - SPACE_NEEDED + 1;
+        let max_file_size = (min_used / 2 / (size + 1) / 4).clamp(1000, 300_000);
+        let mut root = dir(rng, "/".to_string(), size, max_file_size);
+
+        let used = total_size(&root);
+        if used < min_used {
+            root.files.push(("filler.dat".to_string(), min_used - used + rng.random_range(0..1_000_000)));
+        }
+
+        let mut input = String::from("$ cd /\n");
+        write_session(&root, &mut input);
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let fs = Day7::parse(&generate::<Day7>(seed, 20)).unwrap();
+            assert_eq!(fs.into_iter().count(), 21);
+            assert!(fs.total_size() > TOTAL_DISK_SIZE - SPACE_NEEDED && fs.total_size() < TOTAL_DISK_SIZE);
+            Day7::part1(&fs).unwrap();
+            Day7::part2(&fs).unwrap();
+        }
+    }
+}
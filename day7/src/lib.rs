@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::cell::RefCell;
+use anyhow::Result;
+use aoc_common::parse::{self, Line};
+use aoc_common::{debug, Answer, ParseError, Solver};
+
+mod generate;
+
+type DirRef = Rc<RefCell<Dir>>;
+
+struct Dir {
+    parent: Option<DirRef>,
+    sub_dirs: HashMap<String, DirRef>,
+    files_size: usize,
+    total_size: usize,
+}
+
+impl Dir {
+    fn new(parent: Option<DirRef>) -> Self {
+        Dir {
+            parent,
+            sub_dirs: HashMap::new(),
+            files_size: 0,
+            total_size: 0,
+        }
+    }
+
+    fn new_ref(parent: Option<DirRef>) -> DirRef {
+        Rc::new(RefCell::new(Dir::new(parent)))
+    }
+
+    fn calculate_size(&mut self) -> usize {
+        self.total_size = self.files_size;
+        self.total_size += self.sub_dirs.values().map(|d| d.borrow_mut().calculate_size() ).sum::<usize>();
+        self.total_size
+    }
+}
+
+pub struct Filesystem {
+    root: DirRef,
+    cwd: DirRef,
+}
+
+impl Filesystem {
+    fn new() -> Self {
+        let root_dir = Dir::new_ref(None);
+        Filesystem {
+            root: Rc::clone(&root_dir),
+            cwd: root_dir
+        }
+    }
+
+    fn parse_line(&mut self, line: &Line) -> Result<(), ParseError> {
+        match line.text.chars().next() {
+            Some('$') => self.parse_command(line),
+            Some('d') => self.parse_dir(line),
+            _ => self.parse_file(line),
+        }
+    }
+
+    fn parse_command(&mut self, line: &Line) -> Result<(), ParseError> {
+        let command = line.strip_prefix("$ ")?;
+        if let Some(dir_name) = command.strip_prefix("cd ") {
+            return self.cd(line, dir_name);
+        }
+        if command != "ls" {
+            return Err(line.error_at(command, "a cd or ls command"));
+        }
+        Ok(())
+    }
+
+    fn parse_dir(&mut self, line: &Line) -> Result<(), ParseError> {
+        let dir_name = line.strip_prefix("dir ")?.to_string();
+        let dir = Dir::new_ref(Some(Rc::clone(&self.cwd)));
+        self.cwd.borrow_mut().sub_dirs.insert(dir_name, dir);
+        Ok(())
+    }
+
+    fn parse_file(&self, line: &Line) -> Result<(), ParseError> {
+        let mut file_parts = line.text.split_whitespace();
+        let size: usize = line.parse_some(file_parts.next(), "a file size")?;
+        self.cwd.borrow_mut().files_size += size;
+        Ok(())
+    }
+
+    fn cwd_parent(&self) -> Option<DirRef> {
+        let cwd = self.cwd.borrow();
+        cwd.parent.clone()
+    }
+
+    fn cd(&mut self, line: &Line, dir_name: &str) -> Result<(), ParseError> {
+        if dir_name.eq("/") {
+            self.cwd = Rc::clone(&self.root);
+            return Ok(())
+        }
+
+        if dir_name.eq("..") {
+            self.cwd = self.cwd_parent().ok_or_else(|| line.error_at(dir_name, "a directory below the root"))?;
+            return Ok(())
+        }
+
+        // Directories are only known after they have been listed
+        let dir_ref = self.cwd.borrow().sub_dirs.get(dir_name).map(Rc::clone);
+        self.cwd = dir_ref.ok_or_else(|| line.error_at(dir_name, "a directory listed by ls"))?;
+        Ok(())
+    }
+
+    fn calculate_total_sizes(&self) {
+        self.root.borrow_mut().calculate_size();
+    }
+
+    fn total_size(&self) -> usize {
+        self.root.borrow().total_size
+    }
+
+}
+
+pub struct DirIterator {
+    dirs_to_walk: Vec<DirRef>
+}
+
+impl IntoIterator for &Filesystem {
+    type Item = usize;
+    type IntoIter = DirIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DirIterator { dirs_to_walk: [self.root.clone()].to_vec() }
+    }
+}
+
+impl Iterator for DirIterator {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.dirs_to_walk.pop() {
+            None => None,
+            Some(dir) => {
+                let dir = dir.borrow();
+                self.dirs_to_walk.extend(dir.sub_dirs.values().map(Rc::clone));
+                Some(dir.total_size)
+            }
+        }
+    }
+}
+
+const TOTAL_DISK_SIZE: usize = 70000000;
+const SPACE_NEEDED: usize = 30000000;
+
+pub struct Day7;
+
+impl Solver for Day7 {
+    const DAY: u8 = 7;
+    type Input = Filesystem;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut fs = Filesystem::new();
+        for line in parse::lines(input) {
+            fs.parse_line(&line)?;
+        }
+        fs.calculate_total_sizes();
+        Ok(fs)
+    }
+
+    fn part1(fs: &Self::Input) -> Result<Answer> {
+        let total_sub100k: usize = fs.into_iter().filter(|d| *d < 100_000).sum();
+        debug!("Total sub-100k dirs size: {}", total_sub100k);
+        Ok(total_sub100k.into())
+    }
+
+    fn part2(fs: &Self::Input) -> Result<Answer> {
+        let total_used = fs.total_size();
+        let unused_space = TOTAL_DISK_SIZE - total_used;
+        let space_to_free = SPACE_NEEDED - unused_space;
+
+        let mut candidate_size = total_used;
+        fs.into_iter().for_each(|dir_size| {
+            if dir_size > space_to_free && dir_size < candidate_size {
+                candidate_size = dir_size
+            }
+        });
+        debug!("Dir to delete size: {}", candidate_size);
+        Ok(candidate_size.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "(\\$ cd [a/.]{1,2}\n|\\$ ls\n|dir [ab]\n|[0-9]{1,5} f\n){0,8}[$a-z0-9 ./]{0,8}") {
+            prop_assert!(parse::error_points_into(&Day7::parse(&input), &input));
+        }
+
+        // A root directory with files and subdirectories that only have files in them
+        #[test]
+        fn round_trip(
+            root_files in prop::collection::vec(1..100_000usize, 0..5),
+            dirs in prop::collection::vec(prop::collection::vec(1..100_000usize, 0..5), 0..5),
+        ) {
+            let mut input = String::from("$ cd /\n$ ls\n");
+            for (i, size) in root_files.iter().enumerate() {
+                input += &format!("{} file{}.txt\n", size, i);
+            }
+            for i in 0..dirs.len() {
+                input += &format!("dir d{}\n", i);
+            }
+            for (i, files) in dirs.iter().enumerate() {
+                input += &format!("$ cd d{}\n$ ls\n", i);
+                for (j, size) in files.iter().enumerate() {
+                    input += &format!("{} file{}\n", size, j);
+                }
+                input += "$ cd ..\n";
+            }
+
+            let fs = Day7::parse(&input).unwrap();
+            let mut dir_sizes: Vec<usize> = dirs.iter().map(|files| files.iter().sum()).collect();
+            let total_size = root_files.iter().sum::<usize>() + dir_sizes.iter().sum::<usize>();
+            dir_sizes.push(total_size);
+            dir_sizes.sort();
+
+            let mut parsed_sizes: Vec<usize> = fs.into_iter().collect();
+            parsed_sizes.sort();
+            prop_assert_eq!(fs.total_size(), total_size);
+            prop_assert_eq!(parsed_sizes, dir_sizes);
+        }
+    }
+}
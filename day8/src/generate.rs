@@ -0,0 +1,30 @@
+use aoc_common::generate::{Generator, Rng, StdRng};
+use crate::Day8;
+
+impl Generator for Day8 {
+    // Side of the square forest
+    const DEFAULT_SIZE: usize = 99;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| (0..size).map(|_| char::from(b'0' + rng.random_range(0..10))).chain(Some('\n')).collect::<String>())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let height_map = Day8::parse(&generate::<Day8>(seed, 10)).unwrap();
+            assert_eq!((height_map.width(), height_map.height()), (10, 10));
+            Day8::part1(&height_map).unwrap();
+            Day8::part2(&height_map).unwrap();
+        }
+    }
+}
@@ -0,0 +1,166 @@
+use anyhow::Result;
+use aoc_common::{debug, trace, Answer, Grid, Solver};
+use colored::Colorize;
+
+mod generate;
+
+type Map = Grid<u8>;
+
+pub struct Day8;
+
+impl Solver for Day8 {
+    const DAY: u8 = 8;
+    type Input = Map;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        Ok(Grid::try_parse(input, 0, "a tree height (0-9)", |c| c.to_digit(10).map(|h| h as u8))?)
+    }
+
+    fn part1(height_map: &Self::Input) -> Result<Answer> {
+        let (width, height) = (height_map.width(), height_map.height());
+        let mut visibility_map: Map = Grid::new(width, height, 0);
+
+        // Check rows
+        for row in 0..height {
+            visibility_map[row][0] = 1;
+            visibility_map[row][width-1] = 1;
+
+            // Check from the left
+            let mut obstacle = height_map[row][0];
+            for col in 1..width {
+                if height_map[row][col] > obstacle {
+                    visibility_map[row][col] = 1;
+                    obstacle = height_map[row][col];
+                }
+            }
+
+            // Check from the right
+            obstacle = height_map[row][width-1];
+            for col in (1..width-1).rev() {
+                if height_map[row][col] > obstacle {
+                    visibility_map[row][col] = 1;
+                    obstacle = height_map[row][col];
+                }
+            }
+        }
+
+        // Check cols
+        for col in 0..width {
+            visibility_map[0][col] = 1;
+            visibility_map[height-1][col] = 1;
+
+            // Check from the left
+            let mut obstacle = height_map[0][col];
+            for row in 1..height {
+                if height_map[row][col] > obstacle {
+                    visibility_map[row][col] = 1;
+                    obstacle = height_map[row][col];
+                }
+            }
+
+            // Check from the right
+            obstacle = height_map[width-1][col];
+            for row in (1..height-1).rev() {
+                if height_map[row][col] > obstacle {
+                    visibility_map[row][col] = 1;
+                    obstacle = height_map[row][col];
+                }
+            }
+        }
+
+        trace!("--------------------------------------------------");
+        trace!("Visibility Map ({}x{}):", width, height);
+        let mut total_visible = 0;
+        for row in 0..width {
+            let mut map_line = format!("{}\t", row);
+            for col in 0..height {
+                let visible = visibility_map[row][col];
+                let h = height_map[row][col].to_string();
+
+                if visible == 1 {
+                    total_visible += 1;
+                    map_line.push_str(&h.green().to_string());
+                } else {
+                    map_line.push_str(&h.white().to_string());
+                }
+            }
+            trace!("{}", map_line);
+        }
+
+        debug!("Total visible: {}", total_visible);
+        Ok(total_visible.into())
+    }
+
+    fn part2(height_map: &Self::Input) -> Result<Answer> {
+        let (width, height) = (height_map.width(), height_map.height());
+
+        let mut highest_score = 0;
+        for row in 0..height {
+            for col in 0..width {
+                let tree = height_map[row][col];
+
+                // Visibility down
+                let mut visible_down = 0;
+                for tree_row in height_map.rows().skip(row+1) {
+                    visible_down += 1;
+                    if tree_row[col] >= tree { break }
+                }
+
+                // Visibility up
+                let mut visible_up = 0;
+                for row in (0..row).rev() {
+                    visible_up += 1;
+                    if height_map[row][col] >= tree { break }
+                }
+
+                // Visibility right
+                let mut visible_right = 0;
+                for &other in height_map[row][col+1..].iter() {
+                    visible_right += 1;
+                    if other >= tree { break }
+                }
+
+                // Visibility left
+                let mut visible_left = 0;
+                for col in (0..col).rev() {
+                    visible_left += 1;
+                    if height_map[row][col] >= tree { break }
+                }
+
+                let score = visible_down * visible_up * visible_left * visible_right;
+                if score > highest_score {
+                    highest_score = score;
+                }
+            }
+        }
+
+        debug!("Highest score: {}", highest_score);
+        Ok(highest_score.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::parse;
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([0-9]{0,6}\n){0,5}[0-9a ]{0,6}") {
+            prop_assert!(parse::error_points_into(&Day8::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(heights in (1..10usize, 1..10usize).prop_flat_map(|(width, height)| {
+            prop::collection::vec(prop::collection::vec(0..10u8, width), height)
+        })) {
+            let input: String = heights.iter()
+                .map(|row| row.iter().map(|h| h.to_string()).collect::<String>() + "\n")
+                .collect();
+
+            let height_map = Day8::parse(&input).unwrap();
+            prop_assert_eq!(height_map.rows().map(|row| row.to_vec()).collect::<Vec<_>>(), heights);
+        }
+    }
+}
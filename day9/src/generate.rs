@@ -0,0 +1,30 @@
+use aoc_common::generate::{Generator, IndexedRandom, Rng, StdRng};
+use crate::Day9;
+
+impl Generator for Day9 {
+    // Number of motions
+    const DEFAULT_SIZE: usize = 2000;
+
+    fn generate(rng: &mut StdRng, size: usize) -> String {
+        (0..size)
+            .map(|_| format!("{} {}\n", ["U", "D", "L", "R"].choose(rng).unwrap(), rng.random_range(1..20)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_common::generate::generate;
+    use aoc_common::Solver;
+    use super::*;
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for seed in 0..10 {
+            let motions = Day9::parse(&generate::<Day9>(seed, 50)).unwrap();
+            assert_eq!(motions.len(), 50);
+            Day9::part1(&motions).unwrap();
+            Day9::part2(&motions).unwrap();
+        }
+    }
+}
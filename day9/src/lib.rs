@@ -0,0 +1,137 @@
+use std::collections::HashSet;
+use anyhow::Result;
+use aoc_common::{debug, parse, Answer, Direction, Point, Solver};
+
+mod generate;
+
+// Moves the knot one step closer to the head if they are not touching anymore
+fn chase(knot: &mut Point, head: &Point) {
+    // Same position
+    if knot == head {
+        return;
+    }
+
+    // Head in the same column
+    if knot.x == head.x {
+        if knot.y - head.y >= 2 { knot.y -= 1 } // directly above
+        else if head.y - knot.y >= 2 { knot.y += 1 } // directly below
+        return
+    }
+
+    // Head in the same row
+    if knot.y == head.y {
+        if knot.x - head.x >= 2 { knot.x -= 1 }
+        else if head.x - knot.x >= 2 { knot.x += 1 }
+        return
+    }
+
+    // Move diagonally towards the head
+    if (head.x - knot.x).abs() > 1 || (head.y - knot.y).abs() > 1 {
+        let x_step = if head.x > knot.x { 1 } else { -1 };
+        let y_step = if head.y > knot.y { 1 } else { -1 };
+
+        knot.x += x_step;
+        knot.y += y_step;
+    }
+}
+
+#[derive(Debug)]
+struct Rope {
+    knots: Vec<Point>,
+    tail_positions: HashSet<Point>
+}
+
+impl Rope {
+    fn new(len: usize) -> Self {
+        Rope {
+            knots: vec![Point::default(); len],
+            tail_positions: HashSet::default(),
+        }
+    }
+
+    fn mov(&mut self, steps: u32, direction: Direction) {
+        for _ in 0..steps {
+            let rope_len = self.knots.len();
+
+            // Move the head
+            self.knots[0] = self.knots[0].step(direction);
+
+            // Calculate the movement of other knots
+            for i in 1..rope_len {
+                let previous_knot = self.knots[i-1];
+                chase(&mut self.knots[i], &previous_knot);
+            }
+
+            // Register position of the tail
+            self.tail_positions.insert(self.knots[rope_len-1]);
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Motion {
+    direction: Direction,
+    steps: u32,
+}
+
+// Moves a rope with the given number of knots and returns the number of unique tail positions
+fn simulate_rope(motions: &[Motion], len: usize) -> usize {
+    let mut rope = Rope::new(len);
+    for motion in motions {
+        rope.mov(motion.steps, motion.direction);
+    }
+
+    debug!("Unique positions count: {}", rope.tail_positions.len());
+    rope.tail_positions.len()
+}
+
+pub struct Day9;
+
+impl Solver for Day9 {
+    const DAY: u8 = 9;
+    type Input = Vec<Motion>;
+
+    fn parse(input: &str) -> Result<Self::Input> {
+        let mut motions = Vec::new();
+        for line in parse::lines(input) {
+            let (direction, steps) = line.text.split_once(' ').ok_or_else(|| line.error_at_end("a number of steps"))?;
+            let direction = direction.chars().next().and_then(Direction::from_char)
+                .ok_or_else(|| line.error(1, "a direction (U, D, L or R)"))?;
+            let steps = line.parse(steps, "a number of steps")?;
+            motions.push(Motion { direction, steps });
+        }
+        Ok(motions)
+    }
+
+    fn part1(motions: &Self::Input) -> Result<Answer> {
+        Ok(simulate_rope(motions, 2).into())
+    }
+
+    fn part2(motions: &Self::Input) -> Result<Answer> {
+        Ok(simulate_rope(motions, 10).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn parse_errors_point_into_the_input(input in "([UDLR] [0-9]{1,3}\n){0,5}[UDLRX0-9 -]{0,6}") {
+            prop_assert!(parse::error_points_into(&Day9::parse(&input), &input));
+        }
+
+        #[test]
+        fn round_trip(motions in prop::collection::vec((0..4usize, 0..100u32), 0..20)) {
+            let input: String = motions.iter()
+                .map(|&(direction, steps)| format!("{} {}\n", ["U", "R", "D", "L"][direction], steps))
+                .collect();
+            let expected: Vec<Motion> = motions.iter()
+                .map(|&(direction, steps)| Motion { direction: Direction::ALL[direction], steps })
+                .collect();
+            prop_assert_eq!(Day9::parse(&input).unwrap(), expected);
+        }
+    }
+}
@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::check;
+use day10::Day10;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| check::<Day10>(input));
@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::check;
+use day13::Day13;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| check::<Day13>(input));
@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::check;
+use day17::Day17;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| check::<Day17>(input));
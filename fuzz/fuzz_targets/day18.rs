@@ -0,0 +1,7 @@
+#![no_main]
+
+use aoc_fuzz::check;
+use day18::Day18;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| check::<Day18>(input));
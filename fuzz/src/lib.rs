@@ -0,0 +1,7 @@
+use aoc_common::{parse, Solver};
+
+// The parser must never panic, and its errors must point into the input
+pub fn check<S: Solver>(input: &str) {
+    let result = S::parse(input);
+    assert!(parse::error_points_into(&result, input));
+}
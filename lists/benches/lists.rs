@@ -0,0 +1,109 @@
+// Compares the arena-backed list with the boxed one and with VecDeque on stack workloads.
+// Run with `cargo bench -p lists`.
+use std::collections::VecDeque;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+use lists::{arena, second};
+
+const RUNS: usize = 20;
+const ELEMS: u64 = 100_000;
+
+// The operations every list under test has to support
+trait Stack: Default {
+    fn push(&mut self, elem: u64);
+    fn pop(&mut self) -> Option<u64>;
+    // Empties the stack and keeps the memory if the stack can do that
+    fn reset(&mut self);
+}
+
+impl Stack for second::List<u64> {
+    fn push(&mut self, elem: u64) { second::List::push(self, elem) }
+    fn pop(&mut self) -> Option<u64> { second::List::pop(self) }
+    fn reset(&mut self) { while second::List::pop(self).is_some() {} }
+}
+
+impl Stack for arena::List<u64> {
+    fn push(&mut self, elem: u64) { arena::List::push(self, elem) }
+    fn pop(&mut self) -> Option<u64> { arena::List::pop(self) }
+    fn reset(&mut self) { self.clear() }
+}
+
+impl Stack for VecDeque<u64> {
+    fn push(&mut self, elem: u64) { self.push_back(elem) }
+    fn pop(&mut self) -> Option<u64> { self.pop_back() }
+    fn reset(&mut self) { self.clear() }
+}
+
+// Fills a new stack and empties it again
+fn fill_and_drain<S: Stack>(_: &mut S) -> u64 {
+    let mut stack = S::default();
+    for i in 0..ELEMS {
+        stack.push(i);
+    }
+    let mut sum = 0;
+    while let Some(elem) = stack.pop() {
+        sum += elem;
+    }
+    sum
+}
+
+// Depth-first search over a tree of sub-problems, reusing the same stack for every search
+fn search<S: Stack>(stack: &mut S) -> u64 {
+    let mut visited = 0;
+    for root in 0..10 {
+        stack.reset();
+        stack.push(root);
+        while let Some(node) = stack.pop() {
+            visited += 1;
+            if node < ELEMS {
+                stack.push(node * 2 + 1);
+                stack.push(node * 2 + 2);
+            }
+        }
+    }
+    visited
+}
+
+fn bench<S: Stack>(workload: fn(&mut S) -> u64) -> (Duration, Duration) {
+    let mut stack = S::default();
+    let mut timings: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let start = Instant::now();
+            black_box(workload(&mut stack));
+            start.elapsed()
+        })
+        .collect();
+    timings.sort();
+    (timings[0], timings[RUNS / 2])
+}
+
+fn main() {
+    println!("{} runs of each workload:", RUNS);
+    println!("{:<16} {:<16} {:>12} {:>12}", "Workload", "List", "Min", "Median");
+
+    type Results = [(&'static str, (Duration, Duration)); 3];
+    let workloads: [(&str, Results); 2] = [
+        ("fill and drain", [
+            ("second::List", bench::<second::List<u64>>(fill_and_drain)),
+            ("arena::List", bench::<arena::List<u64>>(fill_and_drain)),
+            ("VecDeque", bench::<VecDeque<u64>>(fill_and_drain)),
+        ]),
+        ("search", [
+            ("second::List", bench::<second::List<u64>>(search)),
+            ("arena::List", bench::<arena::List<u64>>(search)),
+            ("VecDeque", bench::<VecDeque<u64>>(search)),
+        ]),
+    ];
+
+    for (workload, results) in workloads.iter() {
+        for (list, (min, median)) in results.iter() {
+            println!(
+                "{:<16} {:<16} {:>12} {:>12}",
+                workload,
+                list,
+                format!("{:.3}ms", min.as_secs_f64() * 1000.0),
+                format!("{:.3}ms", median.as_secs_f64() * 1000.0),
+            );
+        }
+    }
+}
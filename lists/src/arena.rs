@@ -0,0 +1,223 @@
+// A stack like second::List, but all the nodes live in a single Vec and link to each other by index.
+// Popped nodes are reused by the following pushes, so a busy list stops allocating altogether.
+pub struct List<T> {
+    slots: Vec<Slot<T>>,
+    head: Link,
+    // Chain of the slots left behind by popped nodes
+    free: Link,
+    len: usize,
+}
+
+type Link = Option<usize>;
+
+enum Slot<T> {
+    Used { elem: T, next: Link },
+    Free { next: Link },
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        Self::with_capacity(0)
+    }
+
+    pub fn with_capacity(capacity: usize) -> Self {
+        List { slots: Vec::with_capacity(capacity), head: None, free: None, len: 0 }
+    }
+
+    pub fn push(&mut self, elem: T) {
+        let node = Slot::Used { elem, next: self.head };
+        let index = match self.free {
+            Some(index) => {
+                let Slot::Free { next } = self.slots[index] else { unreachable!("used slot in the free chain") };
+                self.free = next;
+                self.slots[index] = node;
+                index
+            },
+            None => {
+                self.slots.push(node);
+                self.slots.len() - 1
+            },
+        };
+        self.head = Some(index);
+        self.len += 1;
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        let index = self.head?;
+        match std::mem::replace(&mut self.slots[index], Slot::Free { next: self.free }) {
+            Slot::Used { elem, next } => {
+                self.head = next;
+                self.free = Some(index);
+                self.len -= 1;
+                Some(elem)
+            },
+            Slot::Free { .. } => unreachable!("free slot in the list"),
+        }
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.map(|index| self.elem(index))
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        match self.head.map(|index| &mut self.slots[index]) {
+            Some(Slot::Used { elem, .. }) => Some(elem),
+            _ => None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Drops all the elements, but keeps the memory for the following pushes
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.head = None;
+        self.free = None;
+        self.len = 0;
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { list: self, next: self.head }
+    }
+
+    fn elem(&self, index: usize) -> &T {
+        match &self.slots[index] {
+            Slot::Used { elem, .. } => elem,
+            Slot::Free { .. } => unreachable!("free slot in the list"),
+        }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub struct Iter<'a, T> {
+    list: &'a List<T>,
+    next: Link,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|index| {
+            let Slot::Used { elem, next } = &self.list.slots[index] else { unreachable!("free slot in the list") };
+            self.next = *next;
+            elem
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+
+    #[test]
+    fn basics() {
+        let mut list = List::new();
+
+        assert_eq!(list.pop(), None);
+
+        list.push(1);
+        assert_eq!(list.pop(), Some(1));
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+
+        list.push(4);
+        assert_eq!(list.pop(), Some(4));
+
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn peek() {
+        let mut list = List::new();
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.peek_mut(), None);
+
+        list.push("hello");
+        list.push("banana");
+        assert_eq!(list.peek(), Some(&"banana"));
+
+        if let Some(value) = list.peek_mut() {
+            *value = "yo";
+        }
+        assert_eq!(list.peek(), Some(&"yo"));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[test]
+    fn iter() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+        list.pop();
+        list.push(4);
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn popped_slots_are_reused() {
+        let mut list = List::with_capacity(4);
+        for i in 0..4 {
+            list.push(i);
+        }
+        for _ in 0..100 {
+            list.pop();
+            list.pop();
+            list.push(10);
+            list.push(20);
+        }
+        assert_eq!(list.slots.len(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [20, 10, 1, 0]);
+    }
+
+    #[test]
+    fn clear_keeps_capacity() {
+        let mut list = List::new();
+        for i in 0..1000 {
+            list.push(i.to_string());
+        }
+        let capacity = list.capacity();
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.peek(), None);
+        assert_eq!(list.capacity(), capacity);
+
+        list.push("again".to_string());
+        assert_eq!(list.pop().as_deref(), Some("again"));
+    }
+}
@@ -0,0 +1,179 @@
+use std::mem::ManuallyDrop;
+use std::ptr;
+use std::sync::atomic::Ordering::{Acquire, Relaxed, Release};
+use crossbeam_epoch::{self as epoch, Atomic, Owned};
+
+// Treiber stack: threads race to swap the head with a compare-and-swap and retry when they lose.
+// A popped node can still be read by threads that loaded it as their head, so it is only freed
+// once every thread pinned at that time has moved on (epoch-based reclamation).
+pub struct List<T> {
+    head: Atomic<Node<T>>,
+}
+
+struct Node<T> {
+    // Moved out by the thread that pops the node, the node itself is dropped later
+    elem: ManuallyDrop<T>,
+    next: Atomic<Node<T>>,
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List { head: Atomic::null() }
+    }
+
+    pub fn push(&self, elem: T) {
+        let mut node = Owned::new(Node { elem: ManuallyDrop::new(elem), next: Atomic::null() });
+        let guard = epoch::pin();
+        loop {
+            let head = self.head.load(Relaxed, &guard);
+            node.next.store(head, Relaxed);
+            match self.head.compare_exchange(head, node, Release, Relaxed, &guard) {
+                Ok(_) => return,
+                Err(e) => node = e.new,
+            }
+        }
+    }
+
+    pub fn pop(&self) -> Option<T> {
+        let guard = epoch::pin();
+        loop {
+            let head = self.head.load(Acquire, &guard);
+            let node = unsafe { head.as_ref() }?;
+            let next = node.next.load(Relaxed, &guard);
+            if self.head.compare_exchange(head, next, Relaxed, Relaxed, &guard).is_ok() {
+                // Only the thread that unlinked the node gets to take the element out of it
+                unsafe {
+                    guard.defer_destroy(head);
+                    return Some(ManuallyDrop::into_inner(ptr::read(&node.elem)));
+                }
+            }
+        }
+    }
+
+    // Other threads can change that right after the check
+    pub fn is_empty(&self) -> bool {
+        let guard = epoch::pin();
+        self.head.load(Acquire, &guard).is_null()
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        while self.pop().is_some() {}
+    }
+}
+
+// Elements are only ever moved between threads, never shared
+unsafe impl<T: Send> Send for List<T> {}
+unsafe impl<T: Send> Sync for List<T> {}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::thread;
+    use super::List;
+
+    #[test]
+    fn basics() {
+        let list = List::new();
+        assert!(list.is_empty());
+        assert_eq!(list.pop(), None);
+
+        list.push(1);
+        list.push(2);
+        list.push(3);
+        assert!(!list.is_empty());
+
+        assert_eq!(list.pop(), Some(3));
+        assert_eq!(list.pop(), Some(2));
+        list.push(4);
+        assert_eq!(list.pop(), Some(4));
+        assert_eq!(list.pop(), Some(1));
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn drops_remaining_elements() {
+        let list = List::new();
+        for i in 0..1000 {
+            list.push(vec![i; 10]);
+        }
+        list.pop();
+        drop(list);
+    }
+
+    // Every pushed element is popped exactly once, no matter how the threads interleave
+    #[test]
+    fn threads_push_and_pop() {
+        const THREADS: usize = 8;
+        const PER_THREAD: usize = 10_000;
+
+        let list = List::new();
+        let popped: Vec<Vec<usize>> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|t| {
+                    let list = &list;
+                    scope.spawn(move || {
+                        let mut popped = Vec::new();
+                        for i in 0..PER_THREAD {
+                            list.push(t * PER_THREAD + i);
+                            if i % 3 == 0 {
+                                popped.extend(list.pop());
+                            }
+                        }
+                        popped
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|handle| handle.join().unwrap()).collect()
+        });
+
+        let mut all: Vec<usize> = popped.into_iter().flatten().collect();
+        while let Some(elem) = list.pop() {
+            all.push(elem);
+        }
+        all.sort_unstable();
+        assert_eq!(all, (0..THREADS * PER_THREAD).collect::<Vec<_>>());
+    }
+
+    // Threads share a stack of sub-problems the way a parallel branch-and-bound search would,
+    // here every sub-problem is a node of a full binary tree that branches until the given depth
+    #[test]
+    fn threads_share_work() {
+        const DEPTH: u32 = 14;
+
+        let work = List::new();
+        let pending = AtomicUsize::new(1);
+        let visited = AtomicUsize::new(0);
+        work.push(0);
+
+        thread::scope(|scope| {
+            for _ in 0..8 {
+                scope.spawn(|| {
+                    while pending.load(Ordering::Acquire) > 0 {
+                        let Some(depth) = work.pop() else {
+                            thread::yield_now();
+                            continue;
+                        };
+                        visited.fetch_add(1, Ordering::Relaxed);
+                        if depth < DEPTH {
+                            pending.fetch_add(2, Ordering::AcqRel);
+                            work.push(depth + 1);
+                            work.push(depth + 1);
+                        }
+                        pending.fetch_sub(1, Ordering::AcqRel);
+                    }
+                });
+            }
+        });
+
+        assert_eq!(visited.into_inner(), (1 << (DEPTH + 1)) - 1);
+        assert!(work.is_empty());
+    }
+}
@@ -0,0 +1,750 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+use std::ptr::NonNull;
+
+pub struct List<T> {
+    front: Link<T>,
+    back: Link<T>,
+    len: usize,
+    // We own the nodes (and their elements) even though we only hold raw pointers to them
+    _owns: PhantomData<Box<Node<T>>>,
+}
+
+type Link<T> = Option<NonNull<Node<T>>>;
+
+struct Node<T> {
+    front: Link<T>,
+    back: Link<T>,
+    elem: T,
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List { front: None, back: None, len: 0, _owns: PhantomData }
+    }
+
+    fn new_node(elem: T) -> NonNull<Node<T>> {
+        NonNull::from(Box::leak(Box::new(Node { front: None, back: None, elem })))
+    }
+
+    pub fn push_front(&mut self, elem: T) {
+        let new = Self::new_node(elem);
+        unsafe {
+            match self.front {
+                Some(old) => {
+                    (*old.as_ptr()).front = Some(new);
+                    (*new.as_ptr()).back = Some(old);
+                },
+                None => self.back = Some(new),
+            }
+        }
+        self.front = Some(new);
+        self.len += 1;
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let new = Self::new_node(elem);
+        unsafe {
+            match self.back {
+                Some(old) => {
+                    (*old.as_ptr()).back = Some(new);
+                    (*new.as_ptr()).front = Some(old);
+                },
+                None => self.front = Some(new),
+            }
+        }
+        self.back = Some(new);
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.front.map(|node| unsafe {
+            let boxed = Box::from_raw(node.as_ptr());
+            self.front = boxed.back;
+            match self.front {
+                Some(new) => (*new.as_ptr()).front = None,
+                None => self.back = None,
+            }
+            self.len -= 1;
+            boxed.elem
+        })
+    }
+
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.back.map(|node| unsafe {
+            let boxed = Box::from_raw(node.as_ptr());
+            self.back = boxed.front;
+            match self.back {
+                Some(new) => (*new.as_ptr()).back = None,
+                None => self.front = None,
+            }
+            self.len -= 1;
+            boxed.elem
+        })
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.front.map(|node| unsafe { &(*node.as_ptr()).elem })
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.front.map(|node| unsafe { &mut (*node.as_ptr()).elem })
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.back.map(|node| unsafe { &(*node.as_ptr()).elem })
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.back.map(|node| unsafe { &mut (*node.as_ptr()).elem })
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    // Moves all the elements of other to the back of this list in O(1)
+    pub fn append(&mut self, other: &mut Self) {
+        let (Some(other_front), Some(other_back)) = (other.front.take(), other.back.take()) else { return };
+        match self.back {
+            Some(back) => unsafe {
+                (*back.as_ptr()).back = Some(other_front);
+                (*other_front.as_ptr()).front = Some(back);
+            },
+            None => self.front = Some(other_front),
+        }
+        self.back = Some(other_back);
+        self.len += mem::take(&mut other.len);
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { front: self.front, back: self.back, len: self.len, _list: PhantomData }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { front: self.front, back: self.back, len: self.len, _list: PhantomData }
+    }
+
+    // The cursor starts at the "ghost" position between the back and the front of the list
+    pub fn cursor_mut(&mut self) -> CursorMut<'_, T> {
+        CursorMut { list: self, cur: None, index: None }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = Self::new();
+        list.extend(iter);
+        list
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+// The list only hands out its elements through the usual borrowing rules
+unsafe impl<T: Send> Send for List<T> {}
+unsafe impl<T: Sync> Sync for List<T> {}
+
+pub struct Iter<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    // Both ends walk towards each other, the length tells when they meet
+    len: usize,
+    _list: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None }
+        self.front.map(|node| unsafe {
+            self.len -= 1;
+            self.front = (*node.as_ptr()).back;
+            &(*node.as_ptr()).elem
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for Iter<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None }
+        self.back.map(|node| unsafe {
+            self.len -= 1;
+            self.back = (*node.as_ptr()).front;
+            &(*node.as_ptr()).elem
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct IterMut<'a, T> {
+    front: Link<T>,
+    back: Link<T>,
+    len: usize,
+    _list: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None }
+        self.front.map(|node| unsafe {
+            self.len -= 1;
+            self.front = (*node.as_ptr()).back;
+            &mut (*node.as_ptr()).elem
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IterMut<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 { return None }
+        self.back.map(|node| unsafe {
+            self.len -= 1;
+            self.back = (*node.as_ptr()).front;
+            &mut (*node.as_ptr()).elem
+        })
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+pub struct IntoIter<T>(List<T>);
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+impl<T> DoubleEndedIterator for IntoIter<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.pop_back()
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+// Points either at an element of the list or at the "ghost" position between its back and front,
+// moving past either end of the list goes through the ghost and wraps around
+pub struct CursorMut<'a, T> {
+    list: &'a mut List<T>,
+    cur: Link<T>,
+    index: Option<usize>,
+}
+
+impl<T> CursorMut<'_, T> {
+    // None when the cursor is at the ghost position
+    pub fn index(&self) -> Option<usize> {
+        self.index
+    }
+
+    pub fn move_next(&mut self) {
+        match self.cur {
+            Some(cur) => unsafe {
+                self.cur = (*cur.as_ptr()).back;
+                self.index = match self.cur {
+                    Some(_) => self.index.map(|index| index + 1),
+                    None => None,
+                };
+            },
+            None => {
+                self.cur = self.list.front;
+                self.index = self.cur.map(|_| 0);
+            },
+        }
+    }
+
+    pub fn move_prev(&mut self) {
+        match self.cur {
+            Some(cur) => unsafe {
+                self.cur = (*cur.as_ptr()).front;
+                self.index = match self.cur {
+                    Some(_) => self.index.map(|index| index - 1),
+                    None => None,
+                };
+            },
+            None => {
+                self.cur = self.list.back;
+                self.index = self.cur.map(|_| self.list.len - 1);
+            },
+        }
+    }
+
+    pub fn current(&mut self) -> Option<&mut T> {
+        self.cur.map(|node| unsafe { &mut (*node.as_ptr()).elem })
+    }
+
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next = match self.cur {
+            Some(cur) => unsafe { (*cur.as_ptr()).back },
+            None => self.list.front,
+        };
+        next.map(|node| unsafe { &mut (*node.as_ptr()).elem })
+    }
+
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let prev = match self.cur {
+            Some(cur) => unsafe { (*cur.as_ptr()).front },
+            None => self.list.back,
+        };
+        prev.map(|node| unsafe { &mut (*node.as_ptr()).elem })
+    }
+
+    // At the ghost position the element goes to the back of the list
+    pub fn insert_before(&mut self, elem: T) {
+        let Some(cur) = self.cur else { return self.list.push_back(elem) };
+        let new = List::new_node(elem);
+        unsafe {
+            let prev = (*cur.as_ptr()).front;
+            match prev {
+                Some(prev) => (*prev.as_ptr()).back = Some(new),
+                None => self.list.front = Some(new),
+            }
+            (*new.as_ptr()).front = prev;
+            (*new.as_ptr()).back = Some(cur);
+            (*cur.as_ptr()).front = Some(new);
+        }
+        self.list.len += 1;
+        self.index = self.index.map(|index| index + 1);
+    }
+
+    // At the ghost position the element goes to the front of the list
+    pub fn insert_after(&mut self, elem: T) {
+        let Some(cur) = self.cur else { return self.list.push_front(elem) };
+        let new = List::new_node(elem);
+        unsafe {
+            let next = (*cur.as_ptr()).back;
+            match next {
+                Some(next) => (*next.as_ptr()).front = Some(new),
+                None => self.list.back = Some(new),
+            }
+            (*new.as_ptr()).front = Some(cur);
+            (*new.as_ptr()).back = next;
+            (*cur.as_ptr()).back = Some(new);
+        }
+        self.list.len += 1;
+    }
+
+    // Removes the current element and moves the cursor to the next one
+    pub fn remove_current(&mut self) -> Option<T> {
+        let cur = self.cur?;
+        unsafe {
+            let boxed = Box::from_raw(cur.as_ptr());
+            match boxed.front {
+                Some(prev) => (*prev.as_ptr()).back = boxed.back,
+                None => self.list.front = boxed.back,
+            }
+            match boxed.back {
+                Some(next) => (*next.as_ptr()).front = boxed.front,
+                None => self.list.back = boxed.front,
+            }
+            self.list.len -= 1;
+            self.cur = boxed.back;
+            self.index = self.cur.and(self.index);
+            Some(boxed.elem)
+        }
+    }
+
+    // Returns all the elements before the current one, at the ghost position that is the whole list
+    pub fn split_before(&mut self) -> List<T> {
+        let Some(cur) = self.cur else { return mem::take(self.list) };
+        let index = self.index.unwrap_or_default();
+        let mut before = List::new();
+        unsafe {
+            if let Some(prev) = (*cur.as_ptr()).front {
+                (*cur.as_ptr()).front = None;
+                (*prev.as_ptr()).back = None;
+                before.front = self.list.front;
+                before.back = Some(prev);
+                before.len = index;
+            }
+        }
+        self.list.front = Some(cur);
+        self.list.len -= index;
+        self.index = Some(0);
+        before
+    }
+
+    // Returns all the elements after the current one, at the ghost position that is the whole list
+    pub fn split_after(&mut self) -> List<T> {
+        let Some(cur) = self.cur else { return mem::take(self.list) };
+        let index = self.index.unwrap_or_default();
+        let mut after = List::new();
+        unsafe {
+            if let Some(next) = (*cur.as_ptr()).back {
+                (*cur.as_ptr()).back = None;
+                (*next.as_ptr()).front = None;
+                after.front = Some(next);
+                after.back = self.list.back;
+                after.len = self.list.len - index - 1;
+            }
+        }
+        self.list.back = Some(cur);
+        self.list.len = index + 1;
+        after
+    }
+
+    // Inserts all the elements of the input before the current one, at the ghost position
+    // they go to the back of the list
+    pub fn splice_before(&mut self, mut input: List<T>) {
+        let Some(cur) = self.cur else { return self.list.append(&mut input) };
+        let (Some(input_front), Some(input_back)) = (input.front.take(), input.back.take()) else { return };
+        let input_len = mem::take(&mut input.len);
+        unsafe {
+            match (*cur.as_ptr()).front {
+                Some(prev) => {
+                    (*prev.as_ptr()).back = Some(input_front);
+                    (*input_front.as_ptr()).front = Some(prev);
+                },
+                None => self.list.front = Some(input_front),
+            }
+            (*cur.as_ptr()).front = Some(input_back);
+            (*input_back.as_ptr()).back = Some(cur);
+        }
+        self.list.len += input_len;
+        self.index = self.index.map(|index| index + input_len);
+    }
+
+    // Inserts all the elements of the input after the current one, at the ghost position
+    // they go to the front of the list
+    pub fn splice_after(&mut self, mut input: List<T>) {
+        let Some(cur) = self.cur else {
+            input.append(self.list);
+            return mem::swap(self.list, &mut input);
+        };
+        let (Some(input_front), Some(input_back)) = (input.front.take(), input.back.take()) else { return };
+        let input_len = mem::take(&mut input.len);
+        unsafe {
+            match (*cur.as_ptr()).back {
+                Some(next) => {
+                    (*next.as_ptr()).front = Some(input_back);
+                    (*input_back.as_ptr()).back = Some(next);
+                },
+                None => self.list.back = Some(input_back),
+            }
+            (*cur.as_ptr()).back = Some(input_front);
+            (*input_front.as_ptr()).front = Some(cur);
+        }
+        self.list.len += input_len;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+
+    fn list_from(elems: &[i32]) -> List<i32> {
+        elems.iter().copied().collect()
+    }
+
+    fn elems(list: &List<i32>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn basics() {
+        let mut list = List::new();
+        assert_eq!(list.len(), 0);
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+
+        list.push_front(2);
+        list.push_front(1);
+        list.push_back(3);
+        list.push_back(4);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.front(), Some(&1));
+        assert_eq!(list.back(), Some(&4));
+
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_back(), Some(4));
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_back(), Some(2));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+
+        list.push_back(5);
+        assert_eq!(list.front(), Some(&5));
+        assert_eq!(list.back(), Some(&5));
+    }
+
+    #[test]
+    fn peek_mut() {
+        let mut list = list_from(&[1, 2, 3]);
+        *list.front_mut().unwrap() *= 10;
+        *list.back_mut().unwrap() *= 100;
+        assert_eq!(elems(&list), [10, 2, 300]);
+    }
+
+    #[test]
+    fn iter_both_ends() {
+        let list = list_from(&[1, 2, 3, 4]);
+        let mut iter = list.iter();
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&4));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next_back(), Some(&3));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [4, 3, 2, 1]);
+        assert_eq!(list.into_iter().rev().collect::<Vec<_>>(), [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = list_from(&[1, 2, 3]);
+        for elem in &mut list {
+            *elem *= 2;
+        }
+        if let Some(last) = list.iter_mut().next_back() {
+            *last = 0;
+        }
+        assert_eq!(elems(&list), [2, 4, 0]);
+    }
+
+    #[test]
+    fn traits() {
+        let list = list_from(&[1, 2, 3]);
+        let mut copy = list.clone();
+        assert_eq!(list, copy);
+        assert_eq!(format!("{:?}", list), "[1, 2, 3]");
+
+        copy.extend([4, 5]);
+        assert_ne!(list, copy);
+        assert_eq!(elems(&copy), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn append() {
+        let mut list = list_from(&[1, 2]);
+        let mut other = list_from(&[3, 4]);
+        list.append(&mut other);
+        assert_eq!(elems(&list), [1, 2, 3, 4]);
+        assert!(other.is_empty());
+
+        other.append(&mut list);
+        assert_eq!(elems(&other), [1, 2, 3, 4]);
+        assert_eq!(list.len(), 0);
+    }
+
+    #[test]
+    fn cursor_moves_around() {
+        let mut list = list_from(&[1, 2, 3]);
+        let mut cursor = list.cursor_mut();
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.peek_next(), Some(&mut 1));
+        assert_eq!(cursor.peek_prev(), Some(&mut 3));
+
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(0));
+        assert_eq!(cursor.current(), Some(&mut 1));
+        assert_eq!(cursor.peek_prev(), None);
+
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.peek_next(), None);
+
+        // Wraps around through the ghost
+        cursor.move_next();
+        assert_eq!(cursor.index(), None);
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(cursor.current(), Some(&mut 3));
+
+        *cursor.current().unwrap() = 30;
+        assert_eq!(elems(&list), [1, 2, 30]);
+    }
+
+    #[test]
+    fn cursor_inserts_and_removes() {
+        let mut list = list_from(&[1, 3]);
+        let mut cursor = list.cursor_mut();
+        cursor.insert_before(4);
+        cursor.insert_after(0);
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        cursor.insert_after(2);
+        cursor.insert_before(-1);
+        assert_eq!(cursor.index(), Some(2));
+        assert_eq!(elems(&list), [0, -1, 1, 2, 3, 4]);
+
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        assert_eq!(cursor.remove_current(), Some(0));
+        assert_eq!(cursor.current(), Some(&mut -1));
+        cursor.move_prev();
+        cursor.move_prev();
+        assert_eq!(cursor.remove_current(), Some(4));
+        assert_eq!(cursor.index(), None);
+        assert_eq!(cursor.remove_current(), None);
+        assert_eq!(elems(&list), [-1, 1, 2, 3]);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.back(), Some(&3));
+    }
+
+    #[test]
+    fn cursor_splits() {
+        let mut list = list_from(&[1, 2, 3, 4, 5]);
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        cursor.move_next();
+        let before = cursor.split_before();
+        assert_eq!(cursor.index(), Some(0));
+        cursor.move_next();
+        let after = cursor.split_after();
+        assert_eq!(cursor.index(), Some(1));
+        assert_eq!(elems(&before), [1]);
+        assert_eq!(elems(&list), [2, 3]);
+        assert_eq!(elems(&after), [4, 5]);
+        assert_eq!((before.len(), list.len(), after.len()), (1, 2, 2));
+
+        // Nothing to split off at the ends, everything at the ghost
+        let mut cursor = list.cursor_mut();
+        cursor.move_next();
+        assert!(cursor.split_before().is_empty());
+        cursor.move_prev();
+        let all = cursor.split_after();
+        assert_eq!(elems(&all), [2, 3]);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn cursor_splices() {
+        let mut list = list_from(&[1, 4]);
+        let mut cursor = list.cursor_mut();
+        cursor.splice_before(list_from(&[5, 6]));
+        cursor.splice_after(list_from(&[-1, 0]));
+        cursor.move_next();
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), Some(&mut 1));
+        cursor.splice_after(list_from(&[2, 3]));
+        cursor.splice_before(List::new());
+        assert_eq!(cursor.index(), Some(2));
+        cursor.move_prev();
+        cursor.splice_before(list_from(&[-3, -2]));
+        assert_eq!(cursor.index(), Some(3));
+        assert_eq!(cursor.current(), Some(&mut 0));
+        assert_eq!(elems(&list), [-1, -3, -2, 0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(list.len(), 10);
+        assert_eq!(list.iter().rev().copied().collect::<Vec<_>>(), [6, 5, 4, 3, 2, 1, 0, -2, -3, -1]);
+    }
+
+    // Moving a number around a circle the way day20 mixes its file
+    #[test]
+    fn cursor_moves_elements_around_a_circle() {
+        let mut list = list_from(&[1, 2, -3, 3, -2, 0, 4]);
+        let mut cursor = list.cursor_mut();
+        for _ in 0..4 { cursor.move_next() }
+        let number = cursor.remove_current().unwrap();
+        if cursor.index().is_none() { cursor.move_next() }
+        for _ in 0..number.rem_euclid(6) {
+            cursor.move_next();
+            if cursor.index().is_none() { cursor.move_next() }
+        }
+        cursor.insert_before(number);
+
+        // The circle has no start, 3 moved between 4 and 1
+        assert_eq!(elems(&list), [3, 1, 2, -3, -2, 0, 4]);
+    }
+}
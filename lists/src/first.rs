@@ -1,50 +1,210 @@
-use std::mem;
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
-pub struct List {
-    head: Link,
-}
-
-enum Link {
-    Empty,
-    More(Box<Node>),
-}
+use crate::link::{self, Link, Node};
 
-struct Node {
-    elem: i32,
-    next: Link,
+pub struct List<T> {
+    head: Link<T>,
+    len: usize,
 }
 
-impl List {
+impl<T> List<T> {
     pub fn new() -> Self {
-        List { head: Link::Empty }
+        List { head: None, len: 0 }
     }
 
-    pub fn push(&mut self, elem: i32) {
+    pub fn push(&mut self, elem: T) {
         let new_node = Node {
-            elem: elem,
-            next: mem::replace(&mut self.head, Link::Empty)
+            elem,
+            next: self.head.take(),
         };
-        self.head = Link::More(Box::new(new_node));
+        self.head = Some(Box::new(new_node));
+        self.len += 1;
     }
 
-    pub fn pop(&mut self) -> Option<i32> {
-        match self.pop_node() {
-            Link::Empty => None,
-            Link::More(node) => {
-                self.head = node.next;
-                Some(node.elem)
-            }
-        }
+    pub fn pop(&mut self) -> Option<T> {
+        self.head.take().map(|node| {
+            self.head = node.next;
+            self.len -= 1;
+            node.elem
+        })
+    }
+
+    pub fn peek(&self) -> Option<&T> {
+        self.head.as_ref().map(|node| &node.elem)
+    }
+
+    pub fn peek_mut(&mut self) -> Option<&mut T> {
+        self.head.as_mut().map(|node| &mut node.elem)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Turns the stack upside down by relinking the nodes
+    pub fn reverse(&mut self) {
+        link::reverse(&mut self.head);
     }
 
-    fn pop_node(&mut self) -> Link {
-        mem::replace(&mut self.head, Link::Empty)
+    // Moves all the elements of other under the bottom of this stack, leaving other empty.
+    // Takes O(n) in the length of self, since it walks down to the bottom to hook other on.
+    pub fn append(&mut self, other: &mut List<T>) {
+        let other_len = other.len;
+        *link::link_at(&mut self.head, self.len) = other.head.take();
+        self.len += other_len;
+        other.len = 0;
+    }
+
+    // Splits the stack in two: self keeps the top `at` elements, the rest are returned
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        assert!(at <= self.len, "split_off index (is {}) should be <= len (is {})", at, self.len);
+        let rest = List { head: link::link_at(&mut self.head, at).take(), len: self.len - at };
+        self.len = at;
+        rest
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head.as_deref() }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head.as_deref_mut() }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
-impl Drop for List {
+impl<T> Drop for List<T> {
     fn drop(&mut self) {
-        while let Link::More(_) = self.pop_node() {}
+        let mut cur_link = self.head.take();
+        while let Some(mut boxed_node) = cur_link {
+            cur_link = boxed_node.next.take();
+        }
+    }
+}
+
+// Extending a stack pushes the elements one by one, so the last one ends up on top
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+// Collecting keeps the order of the elements, the first one ends up on top
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list.reverse();
+        list
+    }
+}
+
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        link::hash(self.iter(), self.len, state);
+    }
+}
+
+pub struct IntoIter<T>(List<T>);
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.map(|node| {
+            self.next = node.next.as_deref();
+            &node.elem
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            &mut node.elem
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
     }
 }
 
@@ -52,6 +212,10 @@ impl Drop for List {
 mod test {
     use super::List;
 
+    fn elems(list: &List<i32>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
     #[test]
     fn basics() {
         let mut list = List::new();
@@ -74,4 +238,101 @@ mod test {
         assert_eq!(list.pop(), Some(1));
         assert_eq!(list.pop(), None);
     }
+
+    #[test]
+    fn len() {
+        let mut list = List::new();
+        assert!(list.is_empty());
+
+        list.push("a");
+        list.push("b");
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.peek(), Some(&"b"));
+
+        list.pop();
+        list.pop();
+        list.pop();
+        assert_eq!(list.len(), 0);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn reverse() {
+        let mut list: List<i32> = (1..=4).collect();
+        list.reverse();
+        assert_eq!(elems(&list), [4, 3, 2, 1]);
+        assert_eq!(list.len(), 4);
+
+        let mut empty: List<i32> = List::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn append() {
+        let mut list: List<i32> = (1..=2).collect();
+        let mut other: List<i32> = (3..=5).collect();
+        list.append(&mut other);
+        assert_eq!(elems(&list), [1, 2, 3, 4, 5]);
+        assert_eq!(list.len(), 5);
+        assert!(other.is_empty());
+
+        other.append(&mut list);
+        assert_eq!(elems(&other), [1, 2, 3, 4, 5]);
+        assert_eq!(list.pop(), None);
+    }
+
+    #[test]
+    fn split_off() {
+        let mut list: List<i32> = (1..=5).collect();
+        let rest = list.split_off(2);
+        assert_eq!(elems(&list), [1, 2]);
+        assert_eq!(elems(&rest), [3, 4, 5]);
+        assert_eq!((list.len(), rest.len()), (2, 3));
+
+        assert!(list.split_off(2).is_empty());
+        let all = list.split_off(0);
+        assert!(list.is_empty());
+        assert_eq!(all, (1..=2).collect());
+    }
+
+    #[test]
+    #[should_panic(expected = "split_off index")]
+    fn split_off_past_the_end() {
+        let mut list: List<i32> = (1..=2).collect();
+        list.split_off(3);
+    }
+
+    #[test]
+    fn iterators() {
+        let mut list: List<i32> = (1..=3).collect();
+        for elem in &mut list {
+            *elem *= 2;
+        }
+        assert_eq!(list.iter().sum::<i32>(), 12);
+        assert_eq!(format!("{:?}", list), "[2, 4, 6]");
+        assert_eq!(list.clone().into_iter().collect::<Vec<_>>(), [2, 4, 6]);
+
+        list.extend([0]);
+        assert_eq!(elems(&list), [0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn hash() {
+        use std::collections::HashSet;
+
+        let lists: HashSet<List<List<i32>>> = [
+            [(1..=1).collect(), List::new()].into_iter().collect(),
+            [List::new(), (1..=1).collect()].into_iter().collect(),
+            [List::new(), (1..=1).collect()].into_iter().collect(),
+        ].into_iter().collect();
+        assert_eq!(lists.len(), 2);
+    }
+
+    #[test]
+    fn long_list_drops_without_recursion() {
+        let list: List<i32> = (0..100_000).collect();
+        assert_eq!(list.len(), 100_000);
+        drop(list);
+    }
 }
@@ -1,2 +1,8 @@
+pub mod arena;
+pub mod concurrent;
+pub mod deque;
 pub mod first;
+mod link;
+pub mod persistent;
+pub mod queue;
 pub mod second;
@@ -0,0 +1,38 @@
+// The singly linked nodes shared by the first and second stacks, and the walks both of them do on them
+use std::hash::{Hash, Hasher};
+use std::mem;
+
+pub(crate) type Link<T> = Option<Box<Node<T>>>;
+
+pub(crate) struct Node<T> {
+    pub(crate) elem: T,
+    pub(crate) next: Link<T>,
+}
+
+// Turns the chain upside down by relinking the nodes
+pub(crate) fn reverse<T>(head: &mut Link<T>) {
+    let mut reversed = None;
+    let mut cur_link = head.take();
+    while let Some(mut node) = cur_link {
+        cur_link = mem::replace(&mut node.next, reversed);
+        reversed = Some(node);
+    }
+    *head = reversed;
+}
+
+// The link pointing to the node at the given position, walking `index` nodes down from the head
+pub(crate) fn link_at<T>(head: &mut Link<T>, index: usize) -> &mut Link<T> {
+    let mut link = head;
+    for _ in 0..index {
+        link = &mut link.as_mut().expect("index is within the list").next;
+    }
+    link
+}
+
+// Hashes the elements followed by their count, which keeps lists of lists like [[1], []] and [[], [1]] apart
+pub(crate) fn hash<'a, T: Hash + 'a, H: Hasher>(elems: impl Iterator<Item = &'a T>, len: usize, state: &mut H) {
+    for elem in elems {
+        elem.hash(state);
+    }
+    len.hash(state);
+}
@@ -0,0 +1,188 @@
+// Immutable singly-linked lists that share their tails, so prepending an element or taking the tail
+// never copies anything. Both flavors are generated from the same code, only the pointer differs.
+macro_rules! persistent_list {
+    ($shared:ident) => {
+        pub struct List<T> {
+            head: Link<T>,
+        }
+
+        type Link<T> = Option<$shared<Node<T>>>;
+
+        struct Node<T> {
+            elem: T,
+            next: Link<T>,
+        }
+
+        impl<T> List<T> {
+            pub fn new() -> Self {
+                List { head: None }
+            }
+
+            pub fn prepend(&self, elem: T) -> List<T> {
+                List { head: Some($shared::new(Node { elem, next: self.head.clone() })) }
+            }
+
+            pub fn tail(&self) -> List<T> {
+                List { head: self.head.as_ref().and_then(|node| node.next.clone()) }
+            }
+
+            pub fn head(&self) -> Option<&T> {
+                self.head.as_ref().map(|node| &node.elem)
+            }
+
+            pub fn is_empty(&self) -> bool {
+                self.head.is_none()
+            }
+
+            pub fn iter(&self) -> Iter<'_, T> {
+                Iter { next: self.head.as_deref() }
+            }
+        }
+
+        // Cloning only shares the nodes with the original list
+        impl<T> Clone for List<T> {
+            fn clone(&self) -> Self {
+                List { head: self.head.clone() }
+            }
+        }
+
+        impl<T> Default for List<T> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        // Only the nodes nobody else points to are dropped, the rest of the list is still in use
+        impl<T> Drop for List<T> {
+            fn drop(&mut self) {
+                let mut head = self.head.take();
+                // Stop at the first node another list still shares
+                while let Some(mut node) = head.and_then($shared::into_inner) {
+                    head = node.next.take();
+                }
+            }
+        }
+
+        pub struct Iter<'a, T> {
+            next: Option<&'a Node<T>>,
+        }
+
+        impl<'a, T> Iterator for Iter<'a, T> {
+            type Item = &'a T;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                self.next.map(|node| {
+                    self.next = node.next.as_deref();
+                    &node.elem
+                })
+            }
+        }
+
+        impl<'a, T> IntoIterator for &'a List<T> {
+            type Item = &'a T;
+            type IntoIter = Iter<'a, T>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        #[cfg(test)]
+        mod test {
+            use super::List;
+
+            #[test]
+            fn basics() {
+                let list = List::new();
+                assert_eq!(list.head(), None);
+                assert!(list.is_empty());
+
+                let list = list.prepend(1).prepend(2).prepend(3);
+                assert_eq!(list.head(), Some(&3));
+
+                let list = list.tail();
+                assert_eq!(list.head(), Some(&2));
+
+                let list = list.tail();
+                assert_eq!(list.head(), Some(&1));
+
+                let list = list.tail();
+                assert_eq!(list.head(), None);
+
+                // Make sure empty tail works
+                let list = list.tail();
+                assert_eq!(list.head(), None);
+            }
+
+            #[test]
+            fn iter() {
+                let list = List::new().prepend(1).prepend(2).prepend(3);
+                let mut iter = list.iter();
+                assert_eq!(iter.next(), Some(&3));
+                assert_eq!(iter.next(), Some(&2));
+                assert_eq!(iter.next(), Some(&1));
+                assert_eq!(iter.next(), None);
+            }
+
+            #[test]
+            fn branches_share_the_tail() {
+                let path = List::new().prepend("AA").prepend("DD");
+                let left = path.prepend("CC");
+                let right = path.prepend("EE").prepend("FF");
+                drop(path);
+
+                assert_eq!(left.iter().copied().collect::<Vec<_>>(), ["CC", "DD", "AA"]);
+                assert_eq!(right.iter().copied().collect::<Vec<_>>(), ["FF", "EE", "DD", "AA"]);
+
+                // Dropping one branch leaves the shared part to the other one
+                drop(right);
+                assert_eq!((&left).into_iter().count(), 3);
+            }
+
+            #[test]
+            fn long_lists_drop_without_recursion() {
+                let mut list = List::new();
+                for i in 0..100_000 {
+                    list = list.prepend(i);
+                }
+                let branch = list.tail().tail().prepend(-1);
+                drop(list);
+                assert_eq!(branch.iter().nth(1), Some(&99_997));
+            }
+        }
+    };
+}
+
+// For single-threaded code
+pub mod rc {
+    use std::rc::Rc;
+
+    persistent_list!(Rc);
+}
+
+// Can be shared between threads
+pub mod arc {
+    use std::sync::Arc;
+
+    persistent_list!(Arc);
+
+    #[cfg(test)]
+    mod thread_test {
+        use std::thread;
+        use super::List;
+
+        #[test]
+        fn threads_extend_a_shared_list() {
+            let base = List::new().prepend(1).prepend(2);
+            let handles: Vec<_> = (0..4)
+                .map(|i| {
+                    let base = base.clone();
+                    thread::spawn(move || base.prepend(i * 10).iter().sum::<i32>())
+                })
+                .collect();
+            let sums: Vec<i32> = handles.into_iter().map(|handle| handle.join().unwrap()).collect();
+            assert_eq!(sums, [3, 13, 23, 33]);
+            assert_eq!(base.head(), Some(&2));
+        }
+    }
+}
@@ -0,0 +1,344 @@
+use std::marker::PhantomData;
+use std::ptr;
+
+// All the links are raw pointers, mixing them with Box would invalidate the tail pointer
+// under the stacked borrows rules. Check any changes with `cargo +nightly miri test -p lists queue`.
+pub struct List<T> {
+    head: Link<T>,
+    tail: Link<T>,
+    len: usize,
+    _owns: PhantomData<Box<Node<T>>>,
+}
+
+type Link<T> = *mut Node<T>;
+
+struct Node<T> {
+    elem: T,
+    next: Link<T>,
+}
+
+impl<T> List<T> {
+    pub fn new() -> Self {
+        List { head: ptr::null_mut(), tail: ptr::null_mut(), len: 0, _owns: PhantomData }
+    }
+
+    pub fn push_back(&mut self, elem: T) {
+        let new_tail = Box::into_raw(Box::new(Node { elem, next: ptr::null_mut() }));
+        if self.tail.is_null() {
+            self.head = new_tail;
+        } else {
+            unsafe { (*self.tail).next = new_tail };
+        }
+        self.tail = new_tail;
+        self.len += 1;
+    }
+
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.head.is_null() { return None }
+        let head = unsafe { Box::from_raw(self.head) };
+        self.head = head.next;
+        if self.head.is_null() {
+            self.tail = ptr::null_mut();
+        }
+        self.len -= 1;
+        Some(head.elem)
+    }
+
+    pub fn peek_front(&self) -> Option<&T> {
+        unsafe { self.head.as_ref().map(|node| &node.elem) }
+    }
+
+    pub fn peek_front_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.head.as_mut().map(|node| &mut node.elem) }
+    }
+
+    pub fn peek_back(&self) -> Option<&T> {
+        unsafe { self.tail.as_ref().map(|node| &node.elem) }
+    }
+
+    pub fn peek_back_mut(&mut self) -> Option<&mut T> {
+        unsafe { self.tail.as_mut().map(|node| &mut node.elem) }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { next: self.head, _list: PhantomData }
+    }
+
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head, _list: PhantomData }
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for List<T> {
+    fn drop(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+}
+
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push_back(elem);
+        }
+    }
+}
+
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        list.extend(iter);
+        list
+    }
+}
+
+// The queue only hands out its elements through the usual borrowing rules
+unsafe impl<T: Send> Send for List<T> {}
+unsafe impl<T: Sync> Sync for List<T> {}
+
+pub struct IntoIter<T>(List<T>);
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter(self)
+    }
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0.len, Some(self.0.len))
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Link<T>,
+    _list: PhantomData<&'a T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            self.next.as_ref().map(|node| {
+                self.next = node.next;
+                &node.elem
+            })
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct IterMut<'a, T> {
+    next: Link<T>,
+    _list: PhantomData<&'a mut T>,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            self.next.as_mut().map(|node| {
+                self.next = node.next;
+                &mut node.elem
+            })
+        }
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::List;
+
+    #[test]
+    fn basics() {
+        let mut list = List::new();
+
+        // Check empty list behaves right
+        assert_eq!(list.pop_front(), None);
+
+        // Populate list
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.len(), 3);
+
+        // Check normal removal
+        assert_eq!(list.pop_front(), Some(1));
+        assert_eq!(list.pop_front(), Some(2));
+
+        // Push some more just to make sure nothing's corrupted
+        list.push_back(4);
+        list.push_back(5);
+
+        // Check normal removal
+        assert_eq!(list.pop_front(), Some(3));
+        assert_eq!(list.pop_front(), Some(4));
+
+        // Check exhaustion
+        assert_eq!(list.pop_front(), Some(5));
+        assert_eq!(list.pop_front(), None);
+        assert!(list.is_empty());
+
+        // Check the exhaustion case fixed the pointer right
+        list.push_back(6);
+        list.push_back(7);
+
+        // Check normal removal
+        assert_eq!(list.pop_front(), Some(6));
+        assert_eq!(list.pop_front(), Some(7));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    #[test]
+    fn peek() {
+        let mut list = List::new();
+        assert_eq!(list.peek_front(), None);
+        assert_eq!(list.peek_back(), None);
+        assert_eq!(list.peek_front_mut(), None);
+        assert_eq!(list.peek_back_mut(), None);
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+        assert_eq!(list.peek_front(), Some(&1));
+        assert_eq!(list.peek_back(), Some(&3));
+
+        *list.peek_front_mut().unwrap() = 10;
+        *list.peek_back_mut().unwrap() = 30;
+        assert_eq!(list.pop_front(), Some(10));
+        assert_eq!(list.peek_front(), Some(&2));
+        assert_eq!(list.peek_back(), Some(&30));
+    }
+
+    #[test]
+    fn into_iter() {
+        let list: List<i32> = (1..=3).collect();
+        let mut iter = list.into_iter();
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next(), Some(2));
+        assert_eq!(iter.next(), Some(3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter() {
+        let list: List<i32> = (1..=3).collect();
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next(), Some(&2));
+        assert_eq!(iter.next(), Some(&3));
+        assert_eq!(iter.next(), None);
+    }
+
+    #[test]
+    fn iter_mut() {
+        let mut list: List<i32> = (1..=3).collect();
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next(), None);
+    }
+
+    // Interleaves every kind of access to the nodes, which is what Miri is most likely to complain about
+    #[test]
+    fn miri_food() {
+        let mut list = List::new();
+
+        list.push_back(1);
+        list.push_back(2);
+        list.push_back(3);
+
+        assert_eq!(list.pop_front(), Some(1));
+        list.push_back(4);
+        assert_eq!(list.pop_front(), Some(2));
+        list.push_back(5);
+
+        assert_eq!(list.peek_front(), Some(&3));
+        list.push_back(6);
+        if let Some(x) = list.peek_front_mut() { *x *= 10 }
+        assert_eq!(list.peek_front(), Some(&30));
+        assert_eq!(list.pop_front(), Some(30));
+
+        for elem in list.iter_mut() {
+            *elem *= 100;
+        }
+
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&400));
+        assert_eq!(iter.next(), Some(&500));
+        assert_eq!(iter.next(), Some(&600));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next(), None);
+
+        assert_eq!(list.pop_front(), Some(400));
+        if let Some(x) = list.peek_back_mut() { *x *= 10 }
+        assert_eq!(list.peek_back(), Some(&6000));
+        assert_eq!(list.pop_front(), Some(500));
+        assert_eq!(list.pop_front(), Some(6000));
+        assert_eq!(list.pop_front(), None);
+    }
+
+    // Visiting the cells of a grid breadth-first, the way path finding solvers do
+    #[test]
+    fn breadth_first_search() {
+        let size = 5_i32;
+        let mut distances = vec![None; (size * size) as usize];
+        let mut queue = List::new();
+        queue.push_back((0, 0, 0));
+        while let Some((x, y, distance)) = queue.pop_front() {
+            let cell = &mut distances[(y * size + x) as usize];
+            if cell.is_some() { continue }
+            *cell = Some(distance);
+            for (dx, dy) in [(1, 0), (0, 1), (-1, 0), (0, -1)] {
+                let (nx, ny) = (x + dx, y + dy);
+                if nx >= 0 && nx < size && ny >= 0 && ny < size {
+                    queue.push_back((nx, ny, distance + 1));
+                }
+            }
+        }
+        assert_eq!(distances[(size * size - 1) as usize], Some(8));
+        assert!(queue.is_empty());
+    }
+}
@@ -1,41 +1,78 @@
-pub struct List<T> {
-    head: Link<T>,
-}
+use std::fmt;
+use std::hash::{Hash, Hasher};
 
-type Link<T> = Option<Box<Node<T>>>;
+use crate::link::{self, Link, Node};
 
-struct Node<T> {
-    elem: T,
-    next: Link<T>,
+pub struct List<T> {
+    head: Link<T>,
+    len: usize,
 }
 
 impl<T> List<T> {
     pub fn new() -> Self {
-        List { head: None }
+        List { head: None, len: 0 }
     }
 
     pub fn push(&mut self, elem: T) {
         let new_node = Node {
-            elem: elem,
+            elem,
             next: self.head.take(),
         };
         self.head = Some(Box::new(new_node));
+        self.len += 1;
     }
 
     pub fn pop(&mut self) -> Option<T> {
         self.head.take().map(|node| {
             self.head = node.next;
+            self.len -= 1;
             node.elem
         })
     }
 
-    pub fn peek(&mut self) -> Option<&T> {
+    pub fn peek(&self) -> Option<&T> {
         self.head.as_ref().map(|node| &node.elem)
     }
 
     pub fn peek_mut(&mut self) -> Option<&mut T> {
         self.head.as_mut().map(|node| &mut node.elem)
     }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Turns the stack upside down by relinking the nodes
+    pub fn reverse(&mut self) {
+        link::reverse(&mut self.head);
+    }
+
+    // Moves all the elements of other under the bottom of this stack, leaving other empty.
+    // Takes O(n) in the length of self, since it walks down to the bottom to hook other on.
+    pub fn append(&mut self, other: &mut List<T>) {
+        let other_len = other.len;
+        *link::link_at(&mut self.head, self.len) = other.head.take();
+        self.len += other_len;
+        other.len = 0;
+    }
+
+    // Splits the stack in two: self keeps the top `at` elements, the rest are returned
+    pub fn split_off(&mut self, at: usize) -> List<T> {
+        assert!(at <= self.len, "split_off index (is {}) should be <= len (is {})", at, self.len);
+        let rest = List { head: link::link_at(&mut self.head, at).take(), len: self.len - at };
+        self.len = at;
+        rest
+    }
+}
+
+impl<T> Default for List<T> {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl<T> Drop for List<T> {
@@ -47,10 +84,62 @@ impl<T> Drop for List<T> {
     }
 }
 
+// Extending a stack pushes the elements one by one, so the last one ends up on top
+impl<T> Extend<T> for List<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elem in iter {
+            self.push(elem);
+        }
+    }
+}
+
+// Collecting keeps the order of the elements, the first one ends up on top
+impl<T> FromIterator<T> for List<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = List::new();
+        let mut tail = &mut list.head;
+        for elem in iter {
+            let node = tail.insert(Box::new(Node { elem, next: None }));
+            tail = &mut node.next;
+            list.len += 1;
+        }
+        list
+    }
+}
+
+impl<T: Clone> Clone for List<T> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for List<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for List<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other)
+    }
+}
+
+impl<T: Eq> Eq for List<T> {}
+
+impl<T: Hash> Hash for List<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        link::hash(self.iter(), self.len, state);
+    }
+}
+
 pub struct IntoIter<T>(List<T>);
 
-impl<T> List<T> {
-    pub fn into_iter(self) -> IntoIter<T> {
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
         IntoIter(self)
     }
 }
@@ -84,6 +173,45 @@ impl<'a, T> Iterator for Iter<'a, T> {
     }
 }
 
+impl<'a, T> IntoIterator for &'a List<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct IterMut<'a, T> {
+    next: Option<&'a mut Node<T>>,
+}
+
+impl<T> List<T> {
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut { next: self.head.as_deref_mut() }
+    }
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next.take().map(|node| {
+            self.next = node.next.as_deref_mut();
+            &mut node.elem
+        })
+    }
+}
+
+impl<'a, T> IntoIterator for &'a mut List<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::List;
@@ -146,9 +274,9 @@ mod test {
         assert_eq!(list.peek(), Some(&"banana"));
         assert_eq!(list.peek_mut(), Some(&mut "banana"));
 
-        list.peek_mut().map(|value| {
+        if let Some(value) = list.peek_mut() {
             *value = "yo";
-        });
+        }
 
         assert_eq!(list.peek(), Some(&"yo"));
         assert_eq!(list.peek_mut(), Some(&mut "yo"));
@@ -178,4 +306,83 @@ mod test {
         assert_eq!(iter.next(), Some(&2));
         assert_eq!(iter.next(), Some(&1));
     }
+
+    #[test]
+    fn iter_mut() {
+        let mut list = List::new();
+        list.push(1); list.push(2); list.push(3);
+
+        let mut iter = list.iter_mut();
+        assert_eq!(iter.next(), Some(&mut 3));
+        assert_eq!(iter.next(), Some(&mut 2));
+        assert_eq!(iter.next(), Some(&mut 1));
+        assert_eq!(iter.next(), None);
+
+        for elem in &mut list {
+            *elem *= 10;
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [30, 20, 10]);
+    }
+
+    #[test]
+    fn collect_and_extend() {
+        let mut list: List<i32> = (1..=3).collect();
+        assert_eq!(list.peek(), Some(&1));
+        assert_eq!(list.len(), 3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [1, 2, 3]);
+
+        list.extend([4, 5]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [5, 4, 1, 2, 3]);
+        assert_eq!(list.len(), 5);
+
+        let mut sum = 0;
+        for elem in &list {
+            sum += elem;
+        }
+        assert_eq!(sum, 15);
+        assert_eq!(list.into_iter().collect::<List<_>>(), [5, 4, 1, 2, 3].into_iter().collect());
+    }
+
+    #[test]
+    fn reverse_append_and_split_off() {
+        let mut list: List<i32> = (1..=3).collect();
+        list.reverse();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 2, 1]);
+
+        let mut other: List<i32> = (4..=5).collect();
+        list.append(&mut other);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 2, 1, 4, 5]);
+        assert_eq!((list.len(), other.len()), (5, 0));
+
+        let rest = list.split_off(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), [3, 2, 1]);
+        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), [4, 5]);
+        assert_eq!((list.len(), rest.len()), (3, 2));
+        assert!(list.split_off(3).is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "split_off index")]
+    fn split_off_past_the_end() {
+        let mut list: List<i32> = (1..=2).collect();
+        list.split_off(3);
+    }
+
+    #[test]
+    fn standard_traits() {
+        use std::collections::HashSet;
+
+        let list: List<&str> = ["a", "b"].into_iter().collect();
+        let copy = list.clone();
+        assert_eq!(list, copy);
+        assert_ne!(list, List::default());
+        assert_eq!(format!("{:?}", list), r#"["a", "b"]"#);
+
+        let lists: HashSet<List<List<i32>>> = [
+            [[1].into_iter().collect(), List::new()].into_iter().collect(),
+            [List::new(), [1].into_iter().collect()].into_iter().collect(),
+            [List::new(), [1].into_iter().collect()].into_iter().collect(),
+        ].into_iter().collect();
+        assert_eq!(lists.len(), 2);
+    }
 }
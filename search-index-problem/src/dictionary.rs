@@ -0,0 +1,152 @@
+use fst::automaton::Str;
+use fst::{Automaton, IntoStreamer, Set, Streamer};
+
+// All the indexed words in a finite state transducer, for looking up the words starting with
+// a prefix or a few typos away from a word without going through every one of them
+pub struct TermDictionary {
+  words: Set<Vec<u8>>,
+}
+
+impl TermDictionary {
+  pub fn new<'w>(words: impl IntoIterator<Item = &'w str>) -> Self {
+    let mut words: Vec<&str> = words.into_iter().collect();
+    words.sort_unstable();
+    words.dedup();
+    TermDictionary { words: Set::from_iter(words).expect("words are sorted and unique") }
+  }
+
+  pub fn with_prefix(&self, prefix: &str) -> Vec<String> {
+    self.search(Str::new(prefix).starts_with())
+  }
+
+  // Words at most `edits` insertions, deletions or substitutions of a character away from the word
+  pub fn within(&self, word: &str, edits: u32) -> Vec<String> {
+    self.search(WithinEdits { word: word.chars().collect(), edits })
+  }
+
+  fn search<A: Automaton>(&self, automaton: A) -> Vec<String> {
+    let mut stream = self.words.search(automaton).into_stream();
+    let mut words = Vec::new();
+    while let Some(word) = stream.next() {
+      words.push(String::from_utf8(word.to_vec()).expect("indexed words are UTF-8"));
+    }
+    words
+  }
+}
+
+// Walks the dictionary computing the edit distances to the word one row of the Levenshtein table
+// per character, and stops going down the branches where every distance is already over the budget.
+// fst has a Levenshtein automaton of its own, but it misses some of the words with multi-byte characters.
+struct WithinEdits {
+  word: Vec<char>,
+  edits: u32,
+}
+
+#[derive(Clone)]
+struct Row {
+  // Distances from every prefix of the word to the characters read so far
+  distances: Vec<u32>,
+  // Bytes of a character that isn't complete yet
+  partial: Vec<u8>,
+}
+
+impl Automaton for WithinEdits {
+  type State = Row;
+
+  fn start(&self) -> Row {
+    Row { distances: (0..=self.word.len() as u32).collect(), partial: Vec::new() }
+  }
+
+  fn is_match(&self, row: &Row) -> bool {
+    row.partial.is_empty() && row.distances[self.word.len()] <= self.edits
+  }
+
+  fn can_match(&self, row: &Row) -> bool {
+    row.distances.iter().any(|&distance| distance <= self.edits)
+  }
+
+  fn accept(&self, row: &Row, byte: u8) -> Row {
+    let mut partial = row.partial.clone();
+    partial.push(byte);
+    match std::str::from_utf8(&partial).ok().and_then(|text| text.chars().next()) {
+      Some(c) => Row { distances: next_row(&self.word, &row.distances, c), partial: Vec::new() },
+      None => Row { distances: row.distances.clone(), partial },
+    }
+  }
+}
+
+fn next_row(word: &[char], distances: &[u32], c: char) -> Vec<u32> {
+  let mut next = Vec::with_capacity(distances.len());
+  next.push(distances[0] + 1);
+  for (j, &word_char) in word.iter().enumerate() {
+    let substitution = distances[j] + u32::from(word_char != c);
+    next.push(substitution.min(distances[j + 1] + 1).min(next[j] + 1));
+  }
+  next
+}
+
+// Levenshtein distance counted in characters
+pub fn edit_distance(a: &str, b: &str) -> u32 {
+  let a: Vec<char> = a.chars().collect();
+  let distances = b.chars().fold((0..=a.len() as u32).collect(), |distances: Vec<u32>, c| next_row(&a, &distances, c));
+  distances[a.len()]
+}
+
+// The most typos a fuzzy search forgives; every extra one multiplies the terms the automaton visits
+pub const MAX_EDITS: u32 = 2;
+
+// How many typos to forgive in a word: none in the short ones, where a single edit makes a different word
+pub fn auto_edits(word: &str) -> u32 {
+  match word.chars().count() {
+    0..=2 => 0,
+    3..=5 => 1,
+    _ => MAX_EDITS,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn dictionary() -> TermDictionary {
+    TermDictionary::new(["keyboard", "key", "keychain", "mug", "mouse", "house", "größe", "key"])
+  }
+
+  #[test]
+  fn finds_words_by_prefix() {
+    let dictionary = dictionary();
+    assert_eq!(dictionary.with_prefix("key"), ["key", "keyboard", "keychain"]);
+    assert_eq!(dictionary.with_prefix("keyb"), ["keyboard"]);
+    assert_eq!(dictionary.with_prefix("grö"), ["größe"]);
+    assert!(dictionary.with_prefix("lamp").is_empty());
+    assert_eq!(dictionary.with_prefix("").len(), 7);
+  }
+
+  #[test]
+  fn finds_words_within_edits() {
+    let dictionary = dictionary();
+    assert_eq!(dictionary.within("keybaord", 2), ["keyboard"]);
+    assert!(dictionary.within("keybaord", 1).is_empty());
+    assert_eq!(dictionary.within("mouse", 1), ["house", "mouse"]);
+    assert_eq!(dictionary.within("mouse", 0), ["mouse"]);
+    assert_eq!(dictionary.within("grösse", 2), ["größe"]);
+    assert_eq!(dictionary.within("gröse", 1), ["größe"]);
+    assert_eq!(dictionary.within("keyboardkeyboardkeyboard", 20), ["keyboard", "keychain"]);
+  }
+
+  #[test]
+  fn measures_edit_distance() {
+    assert_eq!(edit_distance("keyboard", "keyboard"), 0);
+    assert_eq!(edit_distance("keybaord", "keyboard"), 2);
+    assert_eq!(edit_distance("keyboards", "keyboard"), 1);
+    assert_eq!(edit_distance("", "mug"), 3);
+    assert_eq!(edit_distance("größe", "grösse"), 2);
+  }
+
+  #[test]
+  fn forgives_more_typos_in_longer_words() {
+    assert_eq!(auto_edits("tv"), 0);
+    assert_eq!(auto_edits("mug"), 1);
+    assert_eq!(auto_edits("keyboard"), 2);
+  }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use crate::index::{Field, SearchIndex};
+use crate::query::Query;
+use crate::search::{scored_matches, top_hits, SearchResults};
+use crate::Product;
+
+// Fields with a handful of distinct values to narrow the results down by
+pub const FACETS: [Field; 2] = [Field::Vendor, Field::Color];
+
+// Products have to have exactly this value in the field. Several filters on the same field are
+// alternatives, filters on different fields all have to match.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Filter {
+  pub field: Field,
+  pub value: String,
+}
+
+// How many of the matching products have each value of a facet, the most common values first
+#[derive(Debug, PartialEq)]
+pub struct FacetCounts {
+  pub field: Field,
+  pub values: Vec<(String, usize)>,
+}
+
+pub struct FacetedResults<'a> {
+  pub hits: SearchResults<'a>,
+  // Number of the products matching both the query and the filters
+  pub total: usize,
+  pub facets: Vec<FacetCounts>,
+}
+
+// Whether the product passes the filters on all the fields except the ignored one
+fn passes(product: &Product, filters: &[Filter], ignored: Option<Field>) -> bool {
+  Field::ALL.into_iter().filter(|&field| Some(field) != ignored).all(|field| {
+    let mut values = filters.iter().filter(|filter| filter.field == field).map(|filter| filter.value.as_str()).peekable();
+    values.peek().is_none() || values.any(|value| value == field.text(product))
+  })
+}
+
+// The `limit` most relevant products matching the query (or all the products, without one) and the filters,
+// along with the counts of every facet over all the matches. The counts of a facet ignore the filters on
+// that facet itself, so picking "black" still shows how many of the matches come in other colors.
+pub fn search_faceted<'a>(index: &'a SearchIndex, query: Option<&Query>, filters: &[Filter], limit: usize) -> FacetedResults<'a> {
+  let matches = match query {
+    Some(query) => scored_matches(index, query),
+    None => index.live_positions().map(|position| (position, 0.0)).collect(),
+  };
+
+  let facets = FACETS.into_iter()
+    .map(|field| {
+      let mut counts: HashMap<&str, usize> = HashMap::new();
+      for &(position, _) in &matches {
+        let product = index.product(position);
+        if passes(product, filters, Some(field)) {
+          *counts.entry(field.text(product)).or_default() += 1;
+        }
+      }
+      let mut values: Vec<(String, usize)> = counts.into_iter().map(|(value, count)| (value.to_string(), count)).collect();
+      values.sort_unstable_by(|(a, a_count), (b, b_count)| b_count.cmp(a_count).then_with(|| a.cmp(b)));
+      FacetCounts { field, values }
+    })
+    .collect();
+
+  let filtered: Vec<(usize, f64)> = matches.into_iter().filter(|&(position, _)| passes(index.product(position), filters, None)).collect();
+  FacetedResults { total: filtered.len(), hits: top_hits(index, filtered, limit), facets }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::index::build_word_index;
+  use crate::query::parse_query;
+
+  fn product(id: i32, name: &str, vendor: &str, color: &str) -> Product {
+    Product {
+      id,
+      name: name.to_string(),
+      description: String::new(),
+      vendor: vendor.to_string(),
+      color: color.to_string(),
+    }
+  }
+
+  fn catalog() -> Vec<Product> {
+    vec![
+      product(1, "Gaming Keyboard", "Tech Titans", "black"),
+      product(2, "Gaming Mouse", "Tech Titans", "white"),
+      product(3, "Office Keyboard", "Desk Works", "black"),
+      product(4, "Black Mug", "Kitchen Creations", "white"),
+      product(5, "Gaming Headset", "Sound Co", "black"),
+    ]
+  }
+
+  fn filter(field: Field, value: &str) -> Filter {
+    Filter { field, value: value.to_string() }
+  }
+
+  fn counts(results: &FacetedResults, field: Field) -> Vec<(String, usize)> {
+    results.facets.iter().find(|facet| facet.field == field).unwrap().values.clone()
+  }
+
+  fn ids(results: &FacetedResults) -> Vec<i32> {
+    let mut ids: Vec<i32> = results.hits.iter().map(|hit| hit.product.id).collect();
+    ids.sort_unstable();
+    ids
+  }
+
+  fn values(pairs: &[(&str, usize)]) -> Vec<(String, usize)> {
+    pairs.iter().map(|&(value, count)| (value.to_string(), count)).collect()
+  }
+
+  #[test]
+  fn counts_the_values_of_the_matches() {
+    let index = build_word_index(catalog());
+    let query = parse_query("gaming OR keyboard").unwrap();
+    let results = search_faceted(&index, Some(&query), &[], usize::MAX);
+    assert_eq!(ids(&results), [1, 2, 3, 5]);
+    assert_eq!(counts(&results, Field::Vendor), values(&[("Tech Titans", 2), ("Desk Works", 1), ("Sound Co", 1)]));
+    assert_eq!(counts(&results, Field::Color), values(&[("black", 3), ("white", 1)]));
+  }
+
+  #[test]
+  fn filters_by_exact_values() {
+    let index = build_word_index(catalog());
+    let query = parse_query("gaming OR keyboard").unwrap();
+
+    let black = search_faceted(&index, Some(&query), &[filter(Field::Color, "black")], usize::MAX);
+    assert_eq!(ids(&black), [1, 3, 5]);
+    assert_eq!(black.total, 3);
+    // Other colors stay countable, the vendors only count the black products
+    assert_eq!(counts(&black, Field::Color), values(&[("black", 3), ("white", 1)]));
+    assert_eq!(counts(&black, Field::Vendor), values(&[("Desk Works", 1), ("Sound Co", 1), ("Tech Titans", 1)]));
+
+    let filters = [filter(Field::Color, "black"), filter(Field::Vendor, "Tech Titans"), filter(Field::Vendor, "Sound Co")];
+    assert_eq!(ids(&search_faceted(&index, Some(&query), &filters, usize::MAX)), [1, 5]);
+
+    // Exact values only, "black" in the name of the mug doesn't make it black
+    assert!(search_faceted(&index, Some(&query), &[filter(Field::Color, "Black")], usize::MAX).hits.is_empty());
+    let mugs = search_faceted(&index, None, &[filter(Field::Color, "black"), filter(Field::Vendor, "Kitchen Creations")], usize::MAX);
+    assert!(mugs.hits.is_empty());
+  }
+
+  #[test]
+  fn browses_without_a_query() {
+    let mut index = build_word_index(catalog());
+    index.delete(4).unwrap();
+    let results = search_faceted(&index, None, &[filter(Field::Vendor, "Tech Titans")], 1);
+    assert_eq!(ids(&results), [1]);
+    assert_eq!(results.total, 2);
+    assert_eq!(counts(&results, Field::Vendor)[0], ("Tech Titans".to_string(), 2));
+    assert_eq!(counts(&results, Field::Color), values(&[("black", 1), ("white", 1)]));
+  }
+}
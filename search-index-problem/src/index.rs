@@ -0,0 +1,400 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+
+use crate::dictionary::TermDictionary;
+use crate::ranking::Ranking;
+use crate::tokenizer::analyze;
+use crate::Product;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Field {
+  Name,
+  Description,
+  Vendor,
+  Color,
+}
+
+impl Field {
+  pub const ALL: [Field; 4] = [Field::Name, Field::Description, Field::Vendor, Field::Color];
+
+  pub fn name(self) -> &'static str {
+    match self {
+      Field::Name => "name",
+      Field::Description => "description",
+      Field::Vendor => "vendor",
+      Field::Color => "color",
+    }
+  }
+
+  pub fn text(self, product: &Product) -> &str {
+    match self {
+      Field::Name => &product.name,
+      Field::Description => &product.description,
+      Field::Vendor => &product.vendor,
+      Field::Color => &product.color,
+    }
+  }
+}
+
+// How many times a word occurs in a field of the product at the given position
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Posting {
+  pub position: usize,
+  pub count: u32,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum UpdateError {
+  DuplicateId(i32),
+  UnknownId(i32),
+}
+
+impl fmt::Display for UpdateError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      UpdateError::DuplicateId(id) => write!(f, "product {} is already in the index", id),
+      UpdateError::UnknownId(id) => write!(f, "no product with id {} in the index", id),
+    }
+  }
+}
+
+impl std::error::Error for UpdateError {}
+
+// Owns the products it was built from (the stored documents), postings refer to them by position.
+// Deleting or updating a product only leaves a tombstone at its old position, the postings are
+// cleaned up by the compaction once there are enough of them.
+pub struct SearchIndex {
+  products: Vec<Product>,
+  deleted: Vec<bool>,
+  deleted_count: usize,
+  // Positions of the live products by their ids
+  positions: HashMap<i32, usize>,
+  // Postings of every word in every field, in ascending order of the positions
+  words: HashMap<String, HashMap<Field, Vec<Posting>>>,
+  // Number of words in every field of every product
+  lengths: Vec<[u32; 4]>,
+  // Number of words in every field of all the live products together
+  total_lengths: [u64; 4],
+  // Built on the first prefix or fuzzy search after the words change
+  dictionary: OnceLock<TermDictionary>,
+  pub ranking: Ranking,
+}
+
+// build a word index covering every field of the products, the catalog isn't supposed to repeat ids,
+// but if it does the last product with the id wins
+pub fn build_word_index(products: Vec<Product>) -> SearchIndex {
+  let mut index = SearchIndex::from_parts(Vec::new(), Vec::new(), HashMap::new(), Vec::new()).expect("an empty index has no duplicates");
+  for product in products {
+    index.add(product);
+  }
+  index.compact();
+  index
+}
+
+impl SearchIndex {
+  // Puts together an index out of already built pieces, e.g. the ones read from disk
+  pub fn from_parts(
+    products: Vec<Product>,
+    deleted: Vec<bool>,
+    words: HashMap<String, HashMap<Field, Vec<Posting>>>,
+    lengths: Vec<[u32; 4]>,
+  ) -> Result<Self, UpdateError> {
+    let mut positions = HashMap::new();
+    let mut total_lengths = [0; 4];
+    for (position, product) in products.iter().enumerate().filter(|&(position, _)| !deleted[position]) {
+      if positions.insert(product.id, position).is_some() {
+        return Err(UpdateError::DuplicateId(product.id));
+      }
+      for field in Field::ALL {
+        total_lengths[field as usize] += lengths[position][field as usize] as u64;
+      }
+    }
+    let deleted_count = deleted.iter().filter(|&&deleted| deleted).count();
+    Ok(SearchIndex { products, deleted, deleted_count, positions, words, lengths, total_lengths, dictionary: OnceLock::new(), ranking: Ranking::default() })
+  }
+
+  // Adds a product that isn't in the index yet
+  pub fn insert(&mut self, product: Product) -> Result<(), UpdateError> {
+    if self.positions.contains_key(&product.id) {
+      return Err(UpdateError::DuplicateId(product.id));
+    }
+    self.add(product);
+    Ok(())
+  }
+
+  // Replaces the product with the same id
+  pub fn update(&mut self, product: Product) -> Result<(), UpdateError> {
+    if !self.positions.contains_key(&product.id) {
+      return Err(UpdateError::UnknownId(product.id));
+    }
+    self.add(product);
+    self.compact_if_needed();
+    Ok(())
+  }
+
+  pub fn delete(&mut self, id: i32) -> Result<(), UpdateError> {
+    let position = self.positions.remove(&id).ok_or(UpdateError::UnknownId(id))?;
+    self.tombstone(position);
+    self.compact_if_needed();
+    Ok(())
+  }
+
+  // Appends the product to the stored ones, replacing the live product with the same id if there is one.
+  // The new position is past all the others, so the postings stay sorted.
+  fn add(&mut self, product: Product) {
+    let position = self.products.len();
+    if let Some(previous) = self.positions.insert(product.id, position) {
+      self.tombstone(previous);
+    }
+
+    let mut product_lengths = [0; 4];
+    for field in Field::ALL {
+      let field_words = analyze(field.text(&product));
+      product_lengths[field as usize] = field_words.len() as u32;
+      self.total_lengths[field as usize] += field_words.len() as u64;
+      for word in field_words {
+        let postings = self.words.entry(word).or_default().entry(field).or_default();
+        match postings.last_mut() {
+          Some(posting) if posting.position == position => posting.count += 1,
+          _ => postings.push(Posting { position, count: 1 }),
+        }
+      }
+    }
+    self.products.push(product);
+    self.deleted.push(false);
+    self.lengths.push(product_lengths);
+    self.dictionary = OnceLock::new();
+  }
+
+  fn tombstone(&mut self, position: usize) {
+    self.deleted[position] = true;
+    self.deleted_count += 1;
+    for field in Field::ALL {
+      self.total_lengths[field as usize] -= self.lengths[position][field as usize] as u64;
+    }
+  }
+
+  // Compacts once a quarter of the stored products are dead, so the cost of it is spread over many updates
+  fn compact_if_needed(&mut self) {
+    if self.deleted_count * 4 > self.products.len() {
+      self.compact();
+    }
+  }
+
+  // Drops the deleted products along with their postings and moves the live ones together
+  pub fn compact(&mut self) {
+    if self.deleted_count == 0 { return }
+
+    let mut new_positions = Vec::with_capacity(self.products.len());
+    let mut live = 0;
+    for &deleted in &self.deleted {
+      new_positions.push((!deleted).then_some(live));
+      live += usize::from(!deleted);
+    }
+
+    let deleted = std::mem::take(&mut self.deleted);
+    self.products = std::mem::take(&mut self.products).into_iter().zip(&deleted).filter(|(_, &deleted)| !deleted).map(|(product, _)| product).collect();
+    self.lengths = std::mem::take(&mut self.lengths).into_iter().zip(&deleted).filter(|(_, &deleted)| !deleted).map(|(lengths, _)| lengths).collect();
+    self.deleted = vec![false; live];
+    self.deleted_count = 0;
+    self.dictionary = OnceLock::new();
+
+    self.words.retain(|_, field_postings| {
+      field_postings.retain(|_, postings| {
+        postings.retain_mut(|posting| match new_positions[posting.position] {
+          Some(position) => { posting.position = position; true },
+          None => false,
+        });
+        !postings.is_empty()
+      });
+      !field_postings.is_empty()
+    });
+    for position in self.positions.values_mut() {
+      *position = new_positions[*position].expect("live products keep their positions");
+    }
+  }
+
+  // Number of live products
+  pub fn len(&self) -> usize {
+    self.positions.len()
+  }
+
+  pub fn product(&self, position: usize) -> &Product {
+    &self.products[position]
+  }
+
+  // The live product with the id
+  pub fn get(&self, id: i32) -> Option<&Product> {
+    self.positions.get(&id).map(|&position| &self.products[position])
+  }
+
+  // All the stored products, deleted ones included
+  pub fn products(&self) -> &[Product] {
+    &self.products
+  }
+
+  pub fn is_deleted(&self, position: usize) -> bool {
+    self.deleted[position]
+  }
+
+  // Positions of the live products in ascending order
+  pub fn live_positions(&self) -> impl Iterator<Item = usize> + '_ {
+    (0..self.products.len()).filter(|&position| !self.deleted[position])
+  }
+
+  pub fn words(&self) -> &HashMap<String, HashMap<Field, Vec<Posting>>> {
+    &self.words
+  }
+
+  pub fn field_lengths(&self) -> &[[u32; 4]] {
+    &self.lengths
+  }
+
+  pub fn dictionary(&self) -> &TermDictionary {
+    self.dictionary.get_or_init(|| TermDictionary::new(self.words.keys().map(String::as_str)))
+  }
+
+  pub fn postings(&self, word: &str, field: Field) -> &[Posting] {
+    self.words.get(word).and_then(|field_postings| field_postings.get(&field)).map_or(&[], |postings| postings)
+  }
+
+  // How many times the word occurs in the field of the product
+  pub fn count(&self, word: &str, field: Field, position: usize) -> u32 {
+    let postings = self.postings(word, field);
+    postings.binary_search_by_key(&position, |posting| posting.position).map_or(0, |i| postings[i].count)
+  }
+
+  pub fn field_length(&self, field: Field, position: usize) -> u32 {
+    self.lengths[position][field as usize]
+  }
+
+  pub fn average_field_length(&self, field: Field) -> f64 {
+    self.total_lengths[field as usize] as f64 / self.len().max(1) as f64
+  }
+
+  // Positions of the products containing the words one after another in any of the given fields,
+  // in ascending order. The postings only narrow down the candidates, the order of the words
+  // is checked against the product text.
+  pub fn phrase_positions(&self, words: &[String], fields: &[Field]) -> Vec<usize> {
+    let mut positions = Vec::new();
+    for &field in fields {
+      let Some((first, rest)) = words.split_first() else { continue };
+      let candidates = self.postings(first, field).iter()
+        .map(|posting| posting.position)
+        .filter(|&position| !self.deleted[position])
+        .filter(|&position| rest.iter().all(|word| self.count(word, field, position) > 0));
+      for position in candidates {
+        if rest.is_empty() || analyze(field.text(&self.products[position])).windows(words.len()).any(|window| window == words) {
+          positions.push(position);
+        }
+      }
+    }
+    positions.sort_unstable();
+    positions.dedup();
+    positions
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn product(id: i32, name: &str, description: &str, vendor: &str, color: &str) -> Product {
+    Product {
+      id,
+      name: name.to_string(),
+      description: description.to_string(),
+      vendor: vendor.to_string(),
+      color: color.to_string(),
+    }
+  }
+
+  #[test]
+  fn counts_words_per_field() {
+    let products = vec![
+      product(1, "Gaming Keyboard", "A gaming keyboard for gaming.", "Tech Titans", "black"),
+      product(2, "Black Coffee Mug", "Keeps coffee hot.", "Kitchen Creations", "white"),
+    ];
+    let index = build_word_index(products);
+    assert_eq!(index.postings("gaming", Field::Description), [Posting { position: 0, count: 2 }]);
+    assert_eq!(index.postings("black", Field::Name), [Posting { position: 1, count: 1 }]);
+    assert_eq!(index.count("black", Field::Color, 0), 1);
+    assert_eq!(index.count("black", Field::Color, 1), 0);
+    assert_eq!(index.field_length(Field::Description, 0), 5);
+    assert_eq!(index.average_field_length(Field::Name), 2.5);
+  }
+
+  #[test]
+  fn finds_phrases() {
+    let products = vec![
+      product(1, "Gaming Keyboard", "A keyboard for gaming.", "Tech Titans", "black"),
+      product(2, "Keyboard Gaming Mat", "Gaming keyboards slide on it.", "Tech Titans", "black"),
+    ];
+    let index = build_word_index(products);
+    let words = ["gaming".to_string(), "keyboard".to_string()];
+    // "Gaming keyboards" is the same phrase once the plural is stemmed
+    assert_eq!(index.phrase_positions(&words, &Field::ALL), [0, 1]);
+    assert_eq!(index.phrase_positions(&words, &[Field::Description]), [1]);
+    assert_eq!(index.phrase_positions(&words, &[Field::Name]), [0]);
+    assert_eq!(index.phrase_positions(&words[1..], &[Field::Description]), [0, 1]);
+  }
+
+  fn ids(index: &SearchIndex, word: &str) -> Vec<i32> {
+    index.phrase_positions(&[word.to_string()], &Field::ALL).into_iter().map(|position| index.product(position).id).collect()
+  }
+
+  #[test]
+  fn inserts_updates_and_deletes_products() {
+    let mut index = build_word_index(vec![
+      product(1, "Gaming Keyboard", "Clicky.", "Tech Titans", "black"),
+      product(2, "Coffee Mug", "Keeps coffee hot.", "Kitchen Creations", "white"),
+    ]);
+    index.insert(product(3, "Gaming Mouse", "Precise.", "Tech Titans", "black")).unwrap();
+    assert_eq!(ids(&index, "gaming"), [1, 3]);
+    assert_eq!(index.insert(product(3, "Other Mouse", "", "", "")), Err(UpdateError::DuplicateId(3)));
+
+    index.update(product(1, "Office Keyboard", "Quiet.", "Tech Titans", "grey")).unwrap();
+    assert_eq!(ids(&index, "gaming"), [3]);
+    assert_eq!(ids(&index, "keyboard"), [1]);
+    assert_eq!(index.update(product(4, "Lamp", "", "", "")), Err(UpdateError::UnknownId(4)));
+
+    index.delete(2).unwrap();
+    assert!(ids(&index, "coffee").is_empty());
+    assert_eq!(index.delete(2), Err(UpdateError::UnknownId(2)));
+    assert_eq!(index.len(), 2);
+    assert!(index.get(2).is_none());
+    assert_eq!(index.get(1).map(|product| product.name.as_str()), Some("Office Keyboard"));
+    assert_eq!(index.average_field_length(Field::Name), 2.0);
+    assert_eq!(index.live_positions().map(|position| index.product(position).id).collect::<Vec<_>>(), [3, 1]);
+  }
+
+  #[test]
+  fn compacts_once_enough_products_are_deleted() {
+    let mut index = build_word_index((1..=8).map(|id| product(id, &format!("Mug {}", id), "A mug.", "Kitchen Co", "white")).collect());
+    index.delete(3).unwrap();
+    index.delete(5).unwrap();
+    assert_eq!(index.products().len(), 8);
+    assert_eq!(index.postings("3", Field::Name).len(), 1);
+
+    // The third tombstone is more than a quarter of the stored products
+    index.update(product(8, "Jug 8", "A jug.", "Kitchen Co", "white")).unwrap();
+    assert_eq!(index.products().len(), 6);
+    assert!(index.words().get("3").is_none());
+    assert_eq!(index.postings("mug", Field::Name).iter().map(|posting| posting.position).collect::<Vec<_>>(), [0, 1, 2, 3, 4]);
+    assert_eq!(ids(&index, "mug"), [1, 2, 4, 6, 7]);
+    assert_eq!(ids(&index, "8"), [8]);
+
+    index.delete(1).unwrap();
+    assert_eq!(ids(&index, "mug"), [2, 4, 6, 7]);
+  }
+
+  #[test]
+  fn repeated_ids_keep_the_last_product() {
+    let index = build_word_index(vec![product(1, "Old Mug", "", "", ""), product(1, "New Mug", "", "", "")]);
+    assert_eq!(index.len(), 1);
+    assert_eq!(index.products().len(), 1);
+    assert!(ids(&index, "old").is_empty());
+    assert_eq!(ids(&index, "new"), [1]);
+  }
+}
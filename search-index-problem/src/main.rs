@@ -1,8 +1,38 @@
-use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
 
 use serde::{Deserialize, Serialize};
 
+use facets::{search_faceted, FacetedResults, Filter};
+use index::{build_word_index, Field};
+use query::parse_query;
+use ranking::Ranking;
+use search::{
+  find_by_keyword, find_by_keywords_intersect, find_by_keywords_union, find_by_prefix, find_fuzzy, find_in_field, search, search_top, SearchResults,
+};
+
+mod dictionary;
+mod facets;
+mod index;
+mod query;
+mod ranking;
+mod search;
+mod server;
+mod store;
+mod tokenizer;
+
 const DATA_FILE: &str = "products.json";
+const INDEX_FILE: &str = "products.idx";
+const SERVER_ADDRESS: &str = "127.0.0.1:8080";
+
+const USAGE: &str = "\
+usage: search-index-problem                           run the demo queries over products.json
+       search-index-problem build [products] [index]  index a JSON catalog and save the index
+       search-index-problem open [index] [query]      search a saved index
+       search-index-problem insert <index> <product>  add a product given as JSON to a saved index
+       search-index-problem update <index> <product>  replace the product with the same id
+       search-index-problem delete <index> <id>       remove a product from a saved index
+       search-index-problem serve [index] [address]   answer searches over HTTP, on 127.0.0.1:8080 by default";
 
 #[derive(Serialize, Deserialize, PartialEq)]
 struct Product {
@@ -13,52 +43,101 @@ struct Product {
   color: String,
 }
 
-// build a word index from a vector of Product structs
-fn build_word_index(products: &[Product]) -> HashMap<String, Vec<&Product>> {
-  let mut word_index: HashMap<String, Vec<&Product>> = HashMap::new();
-
-  for product in products {
-    let words: Vec<&str> = product.name.split(" ").collect();
-    for word in words {
-      let keyword = word.to_string().to_lowercase();
-      if !word_index.contains_key(&keyword) {
-        word_index.insert(keyword.clone(), Vec::new());
-      }
-      let keyword_products = word_index.get_mut(&keyword).unwrap();
-      keyword_products.push(product);
-    }
+// load json file DATA_FILE
+// return a vector of Product structs
+fn load_products(filename: &str) -> Result<Vec<Product>, Box<dyn Error>> {
+  let data = std::fs::read_to_string(filename).map_err(|e| format!("can't read {}: {}", filename, e))?;
+  Ok(serde_json::from_str(&data)?)
+}
+
+fn main() {
+  let args: Vec<String> = std::env::args().skip(1).collect();
+  let arg = |i: usize, default: &'static str| args.get(i).map_or(default, String::as_str);
+  let result = match args.first().map(String::as_str) {
+    None => demo(),
+    Some("build") => build(arg(1, DATA_FILE), arg(2, INDEX_FILE)),
+    Some("open") => open(arg(1, INDEX_FILE), args.get(2).map(String::as_str)),
+    Some(command @ ("insert" | "update" | "delete")) if args.len() == 3 => change(command, &args[1], &args[2]),
+    Some("serve") => serve(arg(1, INDEX_FILE), arg(2, SERVER_ADDRESS)),
+    Some(_) => {
+      eprintln!("{}", USAGE);
+      std::process::exit(2);
+    },
+  };
+  if let Err(e) = result {
+    eprintln!("Error: {}", e);
+    std::process::exit(1);
   }
+}
 
-  return word_index;
+// Tokenizes the whole catalog once and saves the index to a file, so the following runs can open it instead
+fn build(products_file: &str, index_file: &str) -> Result<(), Box<dyn Error>> {
+  let word_index = build_word_index(load_products(products_file)?);
+  store::save(&word_index, Path::new(index_file))?;
+  println!("Indexed {} products from {} into {}", word_index.len(), products_file, index_file);
+  Ok(())
 }
 
-// load json file DATA_FILE
-// return a vector of Product structs
-fn load_products(filename: &str) -> Vec<Product> {
-  let data = std::fs::read_to_string(filename).expect("Something went wrong reading the file");
-  return serde_json::from_str(&data).unwrap();
+fn open(index_file: &str, query: Option<&str>) -> Result<(), Box<dyn Error>> {
+  let word_index = store::open(Path::new(index_file))?;
+  match query {
+    Some(text) => print_results(&format!("Found products matching '{}':", text), &search(&word_index, &parse_query(text)?)),
+    None => println!("{} products in {}", word_index.len(), index_file),
+  }
+  Ok(())
 }
 
-type SearchIndex<'a> = HashMap<String, Vec<&'a Product>>;
-type SearchResults<'a> = Vec<&'a Product>;
+// Applies a single change to a saved index, the rest of the products are not tokenized again
+fn change(command: &str, index_file: &str, arg: &str) -> Result<(), Box<dyn Error>> {
+  let mut word_index = store::open(Path::new(index_file))?;
+  match command {
+    "insert" => word_index.insert(serde_json::from_str(arg)?)?,
+    "update" => word_index.update(serde_json::from_str(arg)?)?,
+    _ => word_index.delete(arg.parse()?)?,
+  }
+  store::save(&word_index, Path::new(index_file))?;
+  println!("{} products in {}", word_index.len(), index_file);
+  Ok(())
+}
 
-fn main() {
+fn serve(index_file: &str, address: &str) -> Result<(), Box<dyn Error>> {
+  let word_index = store::open(Path::new(index_file))?;
+  server::serve(&word_index, address)?;
+  Ok(())
+}
+
+fn demo() -> Result<(), Box<dyn Error>> {
   // load the data file
-  let products = load_products(DATA_FILE);
+  let products = load_products(DATA_FILE)?;
 
   println!("Products: ");
   for product in &products {
     println!("- id={}, name={}, description={}, vendor={}, color={}", product.id, product.name, product.description, product.vendor, product.color);
   }
 
-  let word_index = build_word_index(products.as_slice());
+  let mut word_index = build_word_index(products);
 
   let keyboards = find_by_keyword(&word_index, "keyboard");
   print_results("Found products with keyword 'keyboard':", &keyboards);
 
+  let rgb = find_by_keyword(&word_index, "RGB");
+  print_results("Found products with keyword 'RGB':", &rgb);
+
+  let black = find_in_field(&word_index, Field::Color, "black");
+  print_results("Found products with color 'black':", &black);
+
   let bananas = find_by_keyword(&word_index, "banana");
   print_results("Found products with keyword 'banana':", &bananas);
 
+  let typos = find_by_keyword(&word_index, "keybaords");
+  print_results("Found products with keyword 'keybaords':", &typos);
+
+  let strict = find_fuzzy(&word_index, "keybaords", 1);
+  print_results("Found products with keyword 'keybaords' and at most one typo:", &strict);
+
+  let typing = find_by_prefix(&word_index, "gaming keyb");
+  print_results("Found products as 'gaming keyb' is being typed:", &typing);
+
   let keywords = Vec::from(["cool", "keyboard"]);
   let cool_keyboards = find_by_keywords_union(&word_index, keywords);
   print_results("Found products with keywords 'cool OR keyboard':", &cool_keyboards);
@@ -66,50 +145,46 @@ fn main() {
   let keywords = Vec::from(["cool", "keyboard"]);
   let cool_keyboards = find_by_keywords_intersect(&word_index, keywords);
   print_results("Found products with keywords 'cool AND keyboard':", &cool_keyboards);
-}
 
-fn print_results(title: &str, products: &SearchResults) {
-  println!("\n{}", title);
-  if products.is_empty() {
-    println!("No results found");
-    return;
+  let queries = [r#"vendor:"Tech Titans" color:black"#, r#""gaming keyboard" NOT steel"#, "(cool OR sturdy) AND NOT table", "blak~ OR wat*"];
+  for text in queries {
+    match parse_query(text) {
+      Ok(query) => print_results(&format!("Found products matching '{}':", text), &search(&word_index, &query)),
+      Err(e) => println!("\nInvalid query '{}': {}", text, e),
+    }
   }
 
-  for product in products {
-    println!("- id={}, name={}, description={}, vendor={}, color={}", product.id, product.name, product.description, product.vendor, product.color);
-  }
+  let query = parse_query("keyboard OR mouse OR gaming").unwrap();
+  print_results("Top 3 products matching 'keyboard OR mouse OR gaming':", &search_top(&word_index, &query, 3));
+
+  let faceted_query = parse_query("gaming OR keyboard OR watch OR bag").unwrap();
+  let everything = search_faceted(&word_index, Some(&faceted_query), &[], 3);
+  print_faceted("Top 3 products matching 'gaming OR keyboard OR watch OR bag':", &everything);
+  let black = search_faceted(&word_index, Some(&faceted_query), &[Filter { field: Field::Color, value: "black".to_string() }], 3);
+  print_faceted("Top 3 black products matching 'gaming OR keyboard OR watch OR bag':", &black);
+
+  word_index.ranking = Ranking::default().with_boost(Field::Description, 3.0);
+  print_results("Top 3 products matching 'keyboard OR mouse OR gaming', with descriptions boosted:", &search_top(&word_index, &query, 3));
+  Ok(())
 }
 
-fn find_by_keywords_intersect<'a>(word_index: &'a SearchIndex, keywords: Vec<&str>) -> SearchResults<'a> {
-  let mut results: SearchResults = Vec::new();
-  for keyword in keywords {
-    let keyword_results = find_by_keyword(word_index, keyword);
-    if results.is_empty() {
-      results = keyword_results;
-      continue;
-    }
-    results = results.into_iter().filter(|product| keyword_results.contains(product)).collect();
+fn print_results(title: &str, results: &SearchResults) {
+  println!("\n{}", title);
+  if results.is_empty() {
+    println!("No results found");
+    return;
   }
-  return results;
-}
 
-fn find_by_keywords_union<'a>(word_index: &'a SearchIndex, keywords: Vec<&str>) -> SearchResults<'a> {
-  let mut results: SearchResults = Vec::new();
-  for keyword in keywords {
-    let keyword_results = find_by_keyword(word_index, keyword);
-    for keyword_result in keyword_results {
-      if !results.contains(&keyword_result) {
-        results.push(keyword_result);
-      }
-    }
+  for hit in results {
+    let product = hit.product;
+    println!("- score={:.3}, id={}, name={}, description={}, vendor={}, color={}", hit.score, product.id, product.name, product.description, product.vendor, product.color);
   }
-  return results;
 }
 
-fn find_by_keyword<'a>(word_index: &'a SearchIndex, keyword: &str) -> SearchResults<'a> {
-  let keyword = keyword.to_string().to_lowercase();
-  if !word_index.contains_key(&keyword) {
-    return Vec::new();
+fn print_faceted(title: &str, results: &FacetedResults) {
+  print_results(title, &results.hits);
+  for facet in &results.facets {
+    let values: Vec<String> = facet.values.iter().map(|(value, count)| format!("{} ({})", value, count)).collect();
+    println!("  {}: {}", facet.field.name(), values.join(" / "));
   }
-  return word_index.get(&keyword).unwrap().clone();
 }
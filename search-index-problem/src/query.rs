@@ -0,0 +1,294 @@
+use std::fmt;
+
+use crate::dictionary::{auto_edits, MAX_EDITS};
+use crate::index::Field;
+use crate::tokenizer::analyze;
+
+// Parsed query, words are already analyzed the same way the products were indexed
+#[derive(Debug, PartialEq)]
+pub enum Query {
+  // Consecutive words (a single word for plain keywords), in the given field or in any of them
+  Phrase { field: Option<Field>, words: Vec<String> },
+  // Any word starting with the prefix, written as `keyb*`
+  Prefix { field: Option<Field>, prefix: String },
+  // Any word at most `edits` typos away, written as `keybaord~2`, or `keybaord~` to pick the budget by the length
+  Fuzzy { field: Option<Field>, word: String, edits: u32 },
+  And(Box<Query>, Box<Query>),
+  Or(Box<Query>, Box<Query>),
+  Not(Box<Query>),
+}
+
+#[derive(Debug, PartialEq)]
+pub struct QueryError {
+  // Byte offset in the query text
+  pub position: usize,
+  pub message: String,
+}
+
+impl fmt::Display for QueryError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{} at position {}", self.message, self.position + 1)
+  }
+}
+
+impl std::error::Error for QueryError {}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+  LParen,
+  RParen,
+  And,
+  Or,
+  Not,
+  Term { field: Option<Field>, text: String, quoted: bool },
+}
+
+fn error<T>(position: usize, message: impl Into<String>) -> Result<T, QueryError> {
+  Err(QueryError { position, message: message.into() })
+}
+
+fn words(position: usize, text: &str) -> Result<Vec<String>, QueryError> {
+  let words = analyze(text);
+  if words.is_empty() {
+    return error(position, format!("'{}' has no words to search for", text));
+  }
+  Ok(words)
+}
+
+fn single_word(position: usize, text: &str) -> Result<String, QueryError> {
+  match words(position, text)?.as_mut_slice() {
+    [word] => Ok(std::mem::take(word)),
+    _ => error(position, format!("'{}' has to be a single word for a prefix or a fuzzy search", text)),
+  }
+}
+
+fn field_named(name: &str) -> Option<Field> {
+  Field::ALL.into_iter().find(|field| field.name() == name.to_lowercase())
+}
+
+// Splits the query into tokens along with their positions
+fn lex(text: &str) -> Result<Vec<(usize, Token)>, QueryError> {
+  let mut tokens = Vec::new();
+  let mut chars = text.char_indices().peekable();
+  while let Some(&(start, c)) = chars.peek() {
+    match c {
+      _ if c.is_whitespace() => { chars.next(); },
+      '(' => { chars.next(); tokens.push((start, Token::LParen)) },
+      ')' => { chars.next(); tokens.push((start, Token::RParen)) },
+      _ => {
+        // A bare word, a quoted phrase, or either of them with a field qualifier
+        let mut field = None;
+        let mut text_start = start;
+        let mut end = start;
+        while let Some(&(i, c)) = chars.peek() {
+          if c.is_whitespace() || c == '(' || c == ')' || c == '"' { break }
+          chars.next();
+          end = i + c.len_utf8();
+          if c == ':' && field.is_none() {
+            let name = &text[start..i];
+            field = Some(field_named(name).ok_or_else(|| QueryError {
+              position: start,
+              message: format!("unknown field '{}' (expected name, description, vendor or color)", name),
+            })?);
+            text_start = end;
+          }
+        }
+
+        let quoted = matches!(chars.peek(), Some((_, '"')));
+        let term = if let Some(&(quote, '"')) = chars.peek() {
+          if end != text_start {
+            return error(quote, "unexpected quote in the middle of a word");
+          }
+          chars.next();
+          let phrase_start = quote + 1;
+          let phrase_end = loop {
+            match chars.next() {
+              Some((i, '"')) => break i,
+              Some(_) => {},
+              None => return error(quote, "unterminated phrase"),
+            }
+          };
+          &text[phrase_start..phrase_end]
+        } else {
+          &text[text_start..end]
+        };
+
+        let token = match (field, term) {
+          (None, "AND") => Token::And,
+          (None, "OR") => Token::Or,
+          (None, "NOT") => Token::Not,
+          (Some(_), "") => return error(start, "missing a word or a phrase after the field name"),
+          _ => Token::Term { field, text: term.to_string(), quoted },
+        };
+        tokens.push((start, token));
+      },
+    }
+  }
+  Ok(tokens)
+}
+
+struct Parser {
+  tokens: Vec<(usize, Token)>,
+  pos: usize,
+  end: usize,
+}
+
+impl Parser {
+  fn peek(&self) -> Option<&Token> {
+    self.tokens.get(self.pos).map(|(_, token)| token)
+  }
+
+  fn position(&self) -> usize {
+    self.tokens.get(self.pos).map_or(self.end, |&(position, _)| position)
+  }
+
+  // or := and ("OR" and)*
+  fn or(&mut self) -> Result<Query, QueryError> {
+    let mut query = self.and()?;
+    while self.peek() == Some(&Token::Or) {
+      self.pos += 1;
+      query = Query::Or(Box::new(query), Box::new(self.and()?));
+    }
+    Ok(query)
+  }
+
+  // and := not ("AND"? not)*, terms next to each other all have to match
+  fn and(&mut self) -> Result<Query, QueryError> {
+    let mut query = self.not()?;
+    loop {
+      match self.peek() {
+        Some(Token::And) => self.pos += 1,
+        Some(Token::Not | Token::LParen | Token::Term { .. }) => {},
+        _ => return Ok(query),
+      }
+      query = Query::And(Box::new(query), Box::new(self.not()?));
+    }
+  }
+
+  // not := "NOT" not | primary
+  fn not(&mut self) -> Result<Query, QueryError> {
+    if self.peek() == Some(&Token::Not) {
+      self.pos += 1;
+      return Ok(Query::Not(Box::new(self.not()?)));
+    }
+    self.primary()
+  }
+
+  // primary := "(" or ")" | term
+  fn primary(&mut self) -> Result<Query, QueryError> {
+    let position = self.position();
+    match self.tokens.get(self.pos).map(|(_, token)| token) {
+      Some(Token::LParen) => {
+        self.pos += 1;
+        let query = self.or()?;
+        if self.peek() != Some(&Token::RParen) {
+          return error(self.position(), "missing a closing parenthesis");
+        }
+        self.pos += 1;
+        Ok(query)
+      },
+      Some(Token::Term { field, text, quoted }) => {
+        let field = *field;
+        let query = if *quoted {
+          Query::Phrase { field, words: words(position, text)? }
+        } else if let Some(prefix) = text.strip_suffix('*') {
+          Query::Prefix { field, prefix: single_word(position, prefix)? }
+        } else if let Some((word, budget)) = text.rsplit_once('~') {
+          let word = single_word(position, word)?;
+          let edits = match budget {
+            "" => auto_edits(&word),
+            _ => budget.parse().or_else(|_| error(position, format!("'{}' is not a number of typos", budget)))?,
+          };
+          if edits > MAX_EDITS {
+            return error(position, format!("{} typos are too many, a fuzzy search forgives at most {}", edits, MAX_EDITS));
+          }
+          Query::Fuzzy { field, word, edits }
+        } else {
+          Query::Phrase { field, words: words(position, text)? }
+        };
+        self.pos += 1;
+        Ok(query)
+      },
+      Some(token) => error(position, format!("expected a word, a phrase or a parenthesis, found {:?}", token)),
+      None => error(position, "unexpected end of the query"),
+    }
+  }
+}
+
+pub fn parse_query(text: &str) -> Result<Query, QueryError> {
+  let mut parser = Parser { tokens: lex(text)?, pos: 0, end: text.len() };
+  let query = parser.or()?;
+  if parser.pos < parser.tokens.len() {
+    return error(parser.position(), "unexpected closing parenthesis");
+  }
+  Ok(query)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  fn phrase(field: Option<Field>, words: &[&str]) -> Query {
+    Query::Phrase { field, words: words.iter().map(|word| word.to_string()).collect() }
+  }
+
+  fn and(left: Query, right: Query) -> Query {
+    Query::And(Box::new(left), Box::new(right))
+  }
+
+  fn or(left: Query, right: Query) -> Query {
+    Query::Or(Box::new(left), Box::new(right))
+  }
+
+  fn not(query: Query) -> Query {
+    Query::Not(Box::new(query))
+  }
+
+  #[test]
+  fn parses_operators_with_precedence() {
+    assert_eq!(parse_query("a OR b c").unwrap(), or(phrase(None, &["a"]), and(phrase(None, &["b"]), phrase(None, &["c"]))));
+    assert_eq!(parse_query("NOT a AND b").unwrap(), and(not(phrase(None, &["a"])), phrase(None, &["b"])));
+    assert_eq!(parse_query("(a OR b) AND NOT NOT c").unwrap(), and(or(phrase(None, &["a"]), phrase(None, &["b"])), not(not(phrase(None, &["c"])))));
+    assert_eq!(parse_query("and or").unwrap(), and(phrase(None, &["and"]), phrase(None, &["or"])));
+  }
+
+  #[test]
+  fn parses_phrases_and_fields() {
+    assert_eq!(
+      parse_query(r#"vendor:"Tech Titans" color:black"#).unwrap(),
+      and(phrase(Some(Field::Vendor), &["tech", "titan"]), phrase(Some(Field::Color), &["black"])),
+    );
+    assert_eq!(parse_query(r#""gaming keyboard""#).unwrap(), phrase(None, &["gaming", "keyboard"]));
+    assert_eq!(parse_query("Name:high-performance").unwrap(), phrase(Some(Field::Name), &["high", "performance"]));
+  }
+
+  #[test]
+  fn parses_prefixes_and_typos() {
+    let prefix = |field, prefix: &str| Query::Prefix { field, prefix: prefix.to_string() };
+    let fuzzy = |field, word: &str, edits| Query::Fuzzy { field, word: word.to_string(), edits };
+    assert_eq!(parse_query("keyb*").unwrap(), prefix(None, "keyb"));
+    assert_eq!(parse_query("name:Keyb* mugs").unwrap(), and(prefix(Some(Field::Name), "keyb"), phrase(None, &["mug"])));
+    assert_eq!(parse_query("keybaord~").unwrap(), fuzzy(None, "keybaord", 2));
+    assert_eq!(parse_query("mgu~ OR color:blak~1").unwrap(), or(fuzzy(None, "mgu", 1), fuzzy(Some(Field::Color), "blak", 1)));
+    assert_eq!(parse_query("keybaords~0").unwrap(), fuzzy(None, "keybaord", 0));
+    assert_eq!(parse_query(r#""keyb*""#).unwrap(), phrase(None, &["keyb"]));
+  }
+
+  #[test]
+  fn reports_errors_with_positions() {
+    let message = |text: &str| parse_query(text).unwrap_err().to_string();
+    assert_eq!(message("price:10"), "unknown field 'price' (expected name, description, vendor or color) at position 1");
+    assert_eq!(message("(a OR b"), "missing a closing parenthesis at position 8");
+    assert_eq!(message("a)"), "unexpected closing parenthesis at position 2");
+    assert_eq!(message(r#"a "b"#), "unterminated phrase at position 3");
+    assert_eq!(message("a AND"), "unexpected end of the query at position 6");
+    assert_eq!(message("OR a"), "expected a word, a phrase or a parenthesis, found Or at position 1");
+    assert_eq!(message("color: black"), "missing a word or a phrase after the field name at position 1");
+    assert_eq!(message("a ..."), "'...' has no words to search for at position 3");
+    assert_eq!(message(""), "unexpected end of the query at position 1");
+    assert_eq!(message("a high-perf*"), "'high-perf' has to be a single word for a prefix or a fuzzy search at position 3");
+    assert_eq!(message("keyboard~two"), "'two' is not a number of typos at position 1");
+    assert_eq!(message("keyboard~3"), "3 typos are too many, a fuzzy search forgives at most 2 at position 1");
+    assert_eq!(message("~1"), "'' has no words to search for at position 1");
+  }
+}
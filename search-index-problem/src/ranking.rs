@@ -0,0 +1,118 @@
+use std::cmp::{Ordering, Reverse};
+use std::collections::BinaryHeap;
+
+use crate::index::{Field, SearchIndex};
+
+// Okapi BM25 parameters and how much a match in each of the fields is worth
+#[derive(Clone, Debug)]
+pub struct Ranking {
+  // How quickly repeating a word stops adding to the score
+  pub k1: f64,
+  // How much matches in longer than average fields are penalized, from 0 (not at all) to 1
+  pub b: f64,
+  boosts: [f64; 4],
+}
+
+impl Default for Ranking {
+  fn default() -> Self {
+    let mut boosts = [1.0; 4];
+    boosts[Field::Name as usize] = 2.0;
+    boosts[Field::Vendor as usize] = 1.5;
+    Ranking { k1: 1.2, b: 0.75, boosts }
+  }
+}
+
+impl Ranking {
+  pub fn with_boost(mut self, field: Field, boost: f64) -> Self {
+    self.boosts[field as usize] = boost;
+    self
+  }
+
+  pub fn boost(&self, field: Field) -> f64 {
+    self.boosts[field as usize]
+  }
+}
+
+// A word to score the products by, in a single field or in any of them. Words a prefix or a fuzzy
+// search was expanded to count for less the further they are from what was typed.
+pub struct Term {
+  pub field: Option<Field>,
+  pub word: String,
+  pub weight: f64,
+}
+
+// BM25 score of a product for the given words, summed over the fields weighted by their boosts
+pub fn score(index: &SearchIndex, position: usize, terms: &[Term]) -> f64 {
+  let ranking = &index.ranking;
+  let products = index.len() as f64;
+  let mut score = 0.0;
+  for term in terms {
+    for field in term.field.map_or(Field::ALL.to_vec(), |field| vec![field]) {
+      let count = index.count(&term.word, field, position) as f64;
+      if count == 0.0 { continue }
+
+      // Postings of the deleted products linger until the compaction, so there can be more of them than products
+      let matching = (index.postings(&term.word, field).len() as f64).min(products);
+      let idf = (1.0 + (products - matching + 0.5) / (matching + 0.5)).ln();
+      let average_length = index.average_field_length(field);
+      let relative_length = if average_length > 0.0 { index.field_length(field, position) as f64 / average_length } else { 1.0 };
+      let tf = count * (ranking.k1 + 1.0) / (count + ranking.k1 * (1.0 - ranking.b + ranking.b * relative_length));
+      score += term.weight * ranking.boost(field) * idf * tf;
+    }
+  }
+  score
+}
+
+// Scores order by their value first, the earlier indexed products win the ties
+#[derive(PartialEq)]
+struct Scored {
+  score: f64,
+  position: usize,
+}
+
+impl Eq for Scored {}
+
+impl Ord for Scored {
+  fn cmp(&self, other: &Self) -> Ordering {
+    self.score.total_cmp(&other.score).then(other.position.cmp(&self.position))
+  }
+}
+
+impl PartialOrd for Scored {
+  fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+// The `limit` best scored positions, best first. Only keeps that many of them around at a time.
+pub fn top_k(scores: impl IntoIterator<Item = (usize, f64)>, limit: usize) -> Vec<(usize, f64)> {
+  let mut heap = BinaryHeap::new();
+  for (position, score) in scores {
+    heap.push(Reverse(Scored { score, position }));
+    if heap.len() > limit {
+      heap.pop();
+    }
+  }
+  heap.into_sorted_vec().into_iter().map(|Reverse(scored)| (scored.position, scored.score)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn keeps_the_best_scores() {
+    let scores = [(0, 1.0), (1, 3.0), (2, 2.0), (3, 3.0), (4, 0.5)];
+    assert_eq!(top_k(scores, 3), [(1, 3.0), (3, 3.0), (2, 2.0)]);
+    assert_eq!(top_k(scores, 0), []);
+    assert_eq!(top_k(scores, usize::MAX).len(), 5);
+  }
+
+  #[test]
+  fn boosts_are_configurable() {
+    let ranking = Ranking::default().with_boost(Field::Color, 3.0);
+    assert_eq!(ranking.boost(Field::Name), 2.0);
+    assert_eq!(ranking.boost(Field::Description), 1.0);
+    assert_eq!(ranking.boost(Field::Color), 3.0);
+  }
+}
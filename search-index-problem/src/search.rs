@@ -0,0 +1,340 @@
+use crate::dictionary::{auto_edits, edit_distance};
+use crate::index::{Field, SearchIndex};
+use crate::query::Query;
+use crate::ranking::{score, top_k, Term};
+use crate::tokenizer::analyze;
+use crate::Product;
+
+pub struct Hit<'a> {
+  pub product: &'a Product,
+  pub score: f64,
+}
+
+// Best matches first
+pub type SearchResults<'a> = Vec<Hit<'a>>;
+
+fn fields(field: Option<Field>) -> Vec<Field> {
+  field.map_or(Field::ALL.to_vec(), |field| vec![field])
+}
+
+// Indexed words a prefix or a fuzzy search stands for, weighted by how much of them was typed
+// or how few typos there are in them
+fn expansions(index: &SearchIndex, query: &Query) -> Vec<(String, f64)> {
+  match query {
+    Query::Prefix { prefix, .. } => index.dictionary().with_prefix(prefix).into_iter()
+      .map(|word| {
+        let weight = prefix.chars().count() as f64 / word.chars().count() as f64;
+        (word, weight)
+      })
+      .collect(),
+    Query::Fuzzy { word, edits, .. } => index.dictionary().within(word, *edits).into_iter()
+      .map(|close| {
+        let weight = 1.0 / (1 + edit_distance(word, &close)) as f64;
+        (close, weight)
+      })
+      .collect(),
+    _ => Vec::new(),
+  }
+}
+
+// Positions of the matching products in ascending order
+fn evaluate(index: &SearchIndex, query: &Query) -> Vec<usize> {
+  match query {
+    Query::Phrase { field, words } => index.phrase_positions(words, &fields(*field)),
+    Query::Prefix { field, .. } | Query::Fuzzy { field, .. } => {
+      let fields = fields(*field);
+      let mut positions: Vec<usize> = expansions(index, query).into_iter()
+        .flat_map(|(word, _)| index.phrase_positions(&[word], &fields))
+        .collect();
+      positions.sort_unstable();
+      positions.dedup();
+      positions
+    },
+    Query::And(left, right) => {
+      let right = evaluate(index, right);
+      evaluate(index, left).into_iter().filter(|position| right.binary_search(position).is_ok()).collect()
+    },
+    Query::Or(left, right) => {
+      let mut positions = evaluate(index, left);
+      positions.extend(evaluate(index, right));
+      positions.sort_unstable();
+      positions.dedup();
+      positions
+    },
+    Query::Not(query) => {
+      let excluded = evaluate(index, query);
+      index.live_positions().filter(|position| excluded.binary_search(position).is_err()).collect()
+    },
+  }
+}
+
+// Words that make a product more relevant, the ones under NOT only filter the results
+fn scored_terms(index: &SearchIndex, query: &Query, terms: &mut Vec<Term>) {
+  match query {
+    Query::Phrase { field, words } => terms.extend(words.iter().map(|word| Term { field: *field, word: word.clone(), weight: 1.0 })),
+    Query::Prefix { field, .. } | Query::Fuzzy { field, .. } => {
+      terms.extend(expansions(index, query).into_iter().map(|(word, weight)| Term { field: *field, word, weight }));
+    },
+    Query::And(left, right) | Query::Or(left, right) => {
+      scored_terms(index, left, terms);
+      scored_terms(index, right, terms);
+    },
+    Query::Not(_) => {},
+  }
+}
+
+// Positions of the products matching the query in ascending order, along with their scores
+pub fn scored_matches(index: &SearchIndex, query: &Query) -> Vec<(usize, f64)> {
+  let mut terms = Vec::new();
+  scored_terms(index, query, &mut terms);
+  evaluate(index, query).into_iter().map(|position| (position, score(index, position, &terms))).collect()
+}
+
+// The `limit` best of the scored positions, best first
+pub fn top_hits(index: &SearchIndex, scores: impl IntoIterator<Item = (usize, f64)>, limit: usize) -> SearchResults<'_> {
+  top_k(scores, limit).into_iter().map(|(position, score)| Hit { product: index.product(position), score }).collect()
+}
+
+// The `limit` most relevant products matching the query
+pub fn search_top<'a>(index: &'a SearchIndex, query: &Query, limit: usize) -> SearchResults<'a> {
+  top_hits(index, scored_matches(index, query), limit)
+}
+
+pub fn search<'a>(index: &'a SearchIndex, query: &Query) -> SearchResults<'a> {
+  search_top(index, query, usize::MAX)
+}
+
+fn keyword_query(field: Option<Field>, keyword: &str) -> Query {
+  Query::Phrase { field, words: analyze(keyword) }
+}
+
+// Products matching every word of the keywords, with each word built into a query of its own
+fn find_by_words<'a>(word_index: &'a SearchIndex, keywords: &str, word_query: impl Fn(String) -> Query) -> SearchResults<'a> {
+  let query = analyze(keywords).into_iter().map(word_query).reduce(|left, right| Query::And(Box::new(left), Box::new(right)));
+  query.map_or_else(Vec::new, |query| search(word_index, &query))
+}
+
+// Forgiving about typos, the longer the word the more of them it can have
+pub fn find_by_keyword<'a>(word_index: &'a SearchIndex, keyword: &str) -> SearchResults<'a> {
+  find_by_words(word_index, keyword, |word| Query::Fuzzy { field: None, edits: auto_edits(&word), word })
+}
+
+pub fn find_fuzzy<'a>(word_index: &'a SearchIndex, keyword: &str, edits: u32) -> SearchResults<'a> {
+  find_by_words(word_index, keyword, |word| Query::Fuzzy { field: None, word, edits })
+}
+
+// Search as you type: the last word may be incomplete
+pub fn find_by_prefix<'a>(word_index: &'a SearchIndex, text: &str) -> SearchResults<'a> {
+  let mut words = analyze(text);
+  let last = words.pop();
+  let query = words.into_iter()
+    .map(|word| Query::Phrase { field: None, words: vec![word] })
+    .chain(last.map(|prefix| Query::Prefix { field: None, prefix }))
+    .reduce(|left, right| Query::And(Box::new(left), Box::new(right)));
+  query.map_or_else(Vec::new, |query| search(word_index, &query))
+}
+
+pub fn find_in_field<'a>(word_index: &'a SearchIndex, field: Field, keyword: &str) -> SearchResults<'a> {
+  search(word_index, &keyword_query(Some(field), keyword))
+}
+
+pub fn find_by_keywords_intersect<'a>(word_index: &'a SearchIndex, keywords: Vec<&str>) -> SearchResults<'a> {
+  let query = keywords.into_iter().map(|keyword| keyword_query(None, keyword)).reduce(|left, right| Query::And(Box::new(left), Box::new(right)));
+  query.map_or_else(Vec::new, |query| search(word_index, &query))
+}
+
+pub fn find_by_keywords_union<'a>(word_index: &'a SearchIndex, keywords: Vec<&str>) -> SearchResults<'a> {
+  let query = keywords.into_iter().map(|keyword| keyword_query(None, keyword)).reduce(|left, right| Query::Or(Box::new(left), Box::new(right)));
+  query.map_or_else(Vec::new, |query| search(word_index, &query))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::index::build_word_index;
+  use crate::query::parse_query;
+
+  fn product(id: i32, name: &str, description: &str, vendor: &str, color: &str) -> Product {
+    Product {
+      id,
+      name: name.to_string(),
+      description: description.to_string(),
+      vendor: vendor.to_string(),
+      color: color.to_string(),
+    }
+  }
+
+  fn ids(results: &SearchResults) -> Vec<i32> {
+    results.iter().map(|hit| hit.product.id).collect()
+  }
+
+  fn sorted_ids(results: &SearchResults) -> Vec<i32> {
+    let mut ids = ids(results);
+    ids.sort_unstable();
+    ids
+  }
+
+  fn catalog() -> Vec<Product> {
+    vec![
+      product(1, "Innovative Gaming Keyboard", "A high-performance gaming keyboard with customizable RGB lighting.", "Tech Titans", "black"),
+      product(2, "Cool Metal Table", "A cool table with steel legs.", "Steel Co", "steel"),
+      product(3, "Black Coffee Mug", "Keeps coffee hot, black or not.", "Kitchen Creations", "white"),
+    ]
+  }
+
+  #[test]
+  fn finds_words_in_every_field() {
+    let products = catalog();
+    let index = build_word_index(products);
+    assert_eq!(ids(&find_by_keyword(&index, "RGB")), [1]);
+    assert_eq!(ids(&find_by_keyword(&index, "titans")), [1]);
+    assert_eq!(ids(&find_by_keyword(&index, "steel")), [2]);
+    assert_eq!(ids(&find_by_keyword(&index, "performance")), [1]);
+    assert_eq!(sorted_ids(&find_by_keyword(&index, "black")), [1, 3]);
+    assert!(find_by_keyword(&index, "banana").is_empty());
+  }
+
+  #[test]
+  fn punctuation_does_not_stick_to_words() {
+    let products = catalog();
+    let index = build_word_index(products);
+    assert_eq!(ids(&find_by_keyword(&index, "lighting")), [1]);
+    assert_eq!(ids(&find_by_keyword(&index, "hot")), [3]);
+  }
+
+  #[test]
+  fn postings_are_per_field() {
+    let products = catalog();
+    let index = build_word_index(products);
+    assert_eq!(ids(&find_in_field(&index, Field::Color, "black")), [1]);
+    assert_eq!(ids(&find_in_field(&index, Field::Name, "black")), [3]);
+    assert!(find_in_field(&index, Field::Vendor, "black").is_empty());
+  }
+
+  #[test]
+  fn combines_keywords() {
+    let products = catalog();
+    let index = build_word_index(products);
+    assert_eq!(sorted_ids(&find_by_keywords_union(&index, vec!["cool", "keyboard"])), [1, 2]);
+    assert_eq!(ids(&find_by_keywords_intersect(&index, vec!["black", "coffee"])), [3]);
+    assert!(find_by_keywords_union(&index, vec![]).is_empty());
+  }
+
+  #[test]
+  fn evaluates_queries() {
+    let products = vec![
+      product(1, "Innovative Gaming Keyboard", "A gaming keyboard with RGB lighting.", "Tech Titans", "black"),
+      product(2, "Cool Gaming Keyboard", "A cool keyboard for gaming.", "Steel Co", "steel"),
+      product(3, "Cool Metal Table", "A cool table with steel legs.", "Steel Co", "steel"),
+      product(4, "Black Coffee Mug", "Keeps coffee hot.", "Tech Titans", "white"),
+    ];
+    let index = build_word_index(products);
+    let matching = |query: &str| sorted_ids(&search(&index, &parse_query(query).unwrap()));
+    assert_eq!(matching(r#"vendor:"Tech Titans" color:black"#), [1]);
+    assert_eq!(matching(r#"vendor:"Titans Tech""#), [] as [i32; 0]);
+    assert_eq!(matching(r#""gaming keyboard""#), [1, 2]);
+    assert_eq!(matching(r#"description:"gaming keyboard""#), [1]);
+    assert_eq!(matching("cool AND NOT keyboard"), [3]);
+    assert_eq!(matching("(cool OR black) keyboard"), [1, 2]);
+    assert_eq!(matching("NOT steel"), [1, 4]);
+    assert_eq!(matching("black OR mug OR table"), [1, 3, 4]);
+  }
+
+  #[test]
+  fn ranks_name_matches_above_description_matches() {
+    let products = vec![
+      product(1, "Desk Lamp", "Bright enough to read a keyboard manual.", "Light Co", "white"),
+      product(2, "Mechanical Keyboard", "Clicky keys.", "Tech Titans", "black"),
+      product(3, "Wrist Rest", "Soft support for long typing sessions.", "Tech Titans", "black"),
+    ];
+    let index = build_word_index(products);
+    let results = find_by_keyword(&index, "keyboard");
+    assert_eq!(ids(&results), [2, 1]);
+    assert!(results[0].score > results[1].score);
+  }
+
+  #[test]
+  fn rare_repeated_and_short_matches_score_higher() {
+    let products = vec![
+      product(1, "Steel Mug", "Steel mug, steel handle.", "Steel Co", "steel"),
+      product(2, "Steel Mug", "A mug.", "Steel Co", "steel"),
+      product(3, "Glass Mug", "A mug.", "Glass Co", "clear"),
+    ];
+    let index = build_word_index(products);
+    assert_eq!(ids(&find_by_keyword(&index, "steel")), [1, 2]);
+    // Glass is the rarest word, and the same mug counts for more in a shorter description
+    assert_eq!(ids(&find_by_keywords_union(&index, vec!["mug", "glass"])), [3, 2, 1]);
+  }
+
+  #[test]
+  fn negated_words_do_not_add_to_the_score() {
+    let products = vec![
+      product(1, "Gaming Mouse", "A mouse.", "Tech Titans", "black"),
+      product(2, "Office Mouse", "A mouse.", "Tech Titans", "black"),
+    ];
+    let index = build_word_index(products);
+    let results = search(&index, &parse_query("mouse NOT gaming").unwrap());
+    assert_eq!(ids(&results), [2]);
+    assert_eq!(results[0].score, search(&index, &parse_query("mouse").unwrap())[1].score);
+  }
+
+  #[test]
+  fn keeps_the_top_results_only() {
+    let products = catalog();
+    let index = build_word_index(products);
+    let query = parse_query("black OR cool OR coffee").unwrap();
+    let all = search(&index, &query);
+    let top = search_top(&index, &query, 2);
+    assert_eq!(ids(&all)[..2], ids(&top)[..]);
+    assert_eq!(ids(&top), [3, 2]);
+  }
+
+  #[test]
+  fn field_boosts_change_the_order() {
+    let products = vec![
+      product(1, "Black Mug", "A mug.", "Kitchen Creations", "white"),
+      product(2, "White Mug", "A mug.", "Kitchen Creations", "black"),
+    ];
+    let mut index = build_word_index(products);
+    assert_eq!(ids(&find_by_keyword(&index, "black")), [1, 2]);
+    index.ranking = index.ranking.clone().with_boost(Field::Color, 4.0);
+    assert_eq!(ids(&find_by_keyword(&index, "black")), [2, 1]);
+  }
+
+  #[test]
+  fn forgives_plurals_and_typos() {
+    let products = catalog();
+    let index = build_word_index(products);
+    assert_eq!(ids(&find_by_keyword(&index, "keyboards")), [1]);
+    assert_eq!(ids(&find_by_keyword(&index, "keybaord")), [1]);
+    assert_eq!(ids(&find_by_keyword(&index, "Mugs")), [3]);
+    assert_eq!(ids(&find_by_keyword(&index, "coffe mig")), [3]);
+    assert!(find_by_keyword(&index, "keyb").is_empty());
+    assert!(find_fuzzy(&index, "keybaord", 1).is_empty());
+    assert_eq!(ids(&find_fuzzy(&index, "keybaord", 2)), [1]);
+  }
+
+  #[test]
+  fn exact_matches_rank_above_typos() {
+    let products = vec![
+      product(1, "Black Mug", "A mug.", "Kitchen Creations", "white"),
+      product(2, "Block Mug", "A mug.", "Kitchen Creations", "white"),
+    ];
+    let index = build_word_index(products);
+    assert_eq!(ids(&find_by_keyword(&index, "block")), [2, 1]);
+    assert_eq!(ids(&find_by_keyword(&index, "black")), [1, 2]);
+  }
+
+  #[test]
+  fn completes_the_last_word() {
+    let products = catalog();
+    let index = build_word_index(products);
+    assert_eq!(ids(&find_by_prefix(&index, "keyb")), [1]);
+    assert_eq!(ids(&find_by_prefix(&index, "gaming keyb")), [1]);
+    assert!(find_by_prefix(&index, "gaming z").is_empty());
+    assert_eq!(sorted_ids(&find_by_prefix(&index, "c")), [1, 2, 3]);
+    // Longer completions of the same prefix count for less
+    assert_eq!(ids(&search(&index, &parse_query("co*").unwrap())), [2, 3]);
+    assert_eq!(ids(&search(&index, &parse_query("color:wh* OR name:keyboard~").unwrap())), [1, 3]);
+  }
+}
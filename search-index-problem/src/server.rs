@@ -0,0 +1,175 @@
+use std::io;
+use std::thread;
+
+use serde_json::{json, Value};
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use crate::facets::{search_faceted, Filter};
+use crate::index::{Field, SearchIndex};
+use crate::query::parse_query;
+
+const WORKERS: usize = 4;
+const DEFAULT_LIMIT: usize = 10;
+const MAX_LIMIT: usize = 100;
+
+// Serves the index over HTTP until the process is killed:
+//
+//   GET /search?q=<query>&limit=10&offset=0&vendor=<value>&color=<value>
+//     ranked hits and the facet counts, q can be left out to browse by the facets alone
+//   GET /products/<id>
+//     a single product
+//
+// Everything is JSON, errors included: {"error": "..."}
+pub fn serve(index: &SearchIndex, address: &str) -> io::Result<()> {
+  let server = Server::http(address).map_err(io::Error::other)?;
+  // The tests and the scripts starting the server on port 0 read the port from here
+  println!("Listening on http://{}", server.server_addr());
+
+  // Requests only read the index, so the workers can share it as is
+  thread::scope(|scope| {
+    for _ in 0..WORKERS {
+      scope.spawn(|| {
+        for request in server.incoming_requests() {
+          respond(index, request);
+        }
+      });
+    }
+  });
+  Ok(())
+}
+
+fn respond(index: &SearchIndex, request: Request) {
+  let (status, body) = route(index, request.method(), request.url());
+  let content_type: Header = "Content-Type: application/json".parse().expect("the header is valid");
+  let response = Response::from_string(body.to_string()).with_status_code(status).with_header(content_type);
+  // Nobody is left to tell if the client hung up already
+  let _ = request.respond(response);
+}
+
+fn error(status: u16, message: impl Into<String>) -> (u16, Value) {
+  (status, json!({ "error": message.into() }))
+}
+
+// Status code and body of the response to a request
+fn route(index: &SearchIndex, method: &Method, url: &str) -> (u16, Value) {
+  let (path, query_string) = url.split_once('?').unwrap_or((url, ""));
+  if *method != Method::Get {
+    return error(405, format!("{} is not supported, only GET is", method));
+  }
+  match path.strip_prefix("/products/") {
+    Some(id) => product(index, id),
+    None if path == "/search" => search(index, query_string),
+    None => error(404, format!("nothing at {}", path)),
+  }
+}
+
+fn product(index: &SearchIndex, id: &str) -> (u16, Value) {
+  let Ok(id) = id.parse() else { return error(400, format!("'{}' is not a product id", id)) };
+  match index.get(id) {
+    Some(product) => (200, json!(product)),
+    None => error(404, format!("no product with id {}", id)),
+  }
+}
+
+fn search(index: &SearchIndex, query_string: &str) -> (u16, Value) {
+  let mut text = None;
+  let mut limit = DEFAULT_LIMIT;
+  let mut offset = 0;
+  let mut filters = Vec::new();
+  for (name, value) in form_urlencoded::parse(query_string.as_bytes()) {
+    match name.as_ref() {
+      "q" => text = Some(value.into_owned()),
+      "limit" | "offset" => {
+        let Ok(number) = value.parse::<usize>() else { return error(400, format!("{} has to be a number, not '{}'", name, value)) };
+        if name == "limit" { limit = number.min(MAX_LIMIT) } else { offset = number }
+      },
+      "vendor" => filters.push(Filter { field: Field::Vendor, value: value.into_owned() }),
+      "color" => filters.push(Filter { field: Field::Color, value: value.into_owned() }),
+      _ => {},
+    }
+  }
+
+  let query = match text.as_deref().map(str::trim) {
+    None | Some("") => None,
+    Some(text) => match parse_query(text) {
+      Ok(query) => Some(query),
+      Err(e) => return error(400, e.to_string()),
+    },
+  };
+  let results = search_faceted(index, query.as_ref(), &filters, offset.saturating_add(limit));
+
+  let hits: Vec<Value> = results.hits.iter().skip(offset).map(|hit| json!({ "score": hit.score, "product": hit.product })).collect();
+  let facets: serde_json::Map<String, Value> = results.facets.iter()
+    .map(|facet| {
+      let values = facet.values.iter().map(|(value, count)| json!({ "value": value, "count": count })).collect();
+      (facet.field.name().to_string(), Value::Array(values))
+    })
+    .collect();
+  (200, json!({ "total": results.total, "offset": offset, "limit": limit, "hits": hits, "facets": facets }))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::index::build_word_index;
+  use crate::Product;
+
+  fn index() -> SearchIndex {
+    let product = |id, name: &str, vendor: &str, color: &str| Product {
+      id,
+      name: name.to_string(),
+      description: String::new(),
+      vendor: vendor.to_string(),
+      color: color.to_string(),
+    };
+    build_word_index(vec![
+      product(1, "Gaming Keyboard", "Tech Titans", "black"),
+      product(2, "Gaming Mouse", "Tech Titans", "white"),
+      product(3, "Office Keyboard", "Desk Works", "black"),
+    ])
+  }
+
+  fn get(index: &SearchIndex, url: &str) -> (u16, Value) {
+    route(index, &Method::Get, url)
+  }
+
+  fn ids(body: &Value) -> Vec<i64> {
+    body["hits"].as_array().unwrap().iter().map(|hit| hit["product"]["id"].as_i64().unwrap()).collect()
+  }
+
+  #[test]
+  fn pages_through_the_hits() {
+    let index = index();
+    let (status, body) = get(&index, "/search?q=keyboard+OR+gaming");
+    assert_eq!(status, 200);
+    assert_eq!(ids(&body), [1, 2, 3]);
+    assert_eq!((body["total"].as_u64(), body["limit"].as_u64()), (Some(3), Some(10)));
+
+    let (_, page) = get(&index, "/search?q=keyboard+OR+gaming&limit=1&offset=1");
+    assert_eq!(ids(&page), [2]);
+    assert_eq!(page["total"], 3);
+    assert!(ids(&get(&index, "/search?q=keyboard&offset=5").1).is_empty());
+    assert_eq!(get(&index, "/search?q=keyboard&limit=1000").1["limit"], 100);
+  }
+
+  #[test]
+  fn filters_and_counts_facets() {
+    let index = index();
+    let (_, body) = get(&index, "/search?vendor=Tech%20Titans&color=black");
+    assert_eq!(ids(&body), [1]);
+    assert_eq!(body["facets"]["color"], json!([{ "value": "black", "count": 1 }, { "value": "white", "count": 1 }]));
+    assert_eq!(body["facets"]["vendor"], json!([{ "value": "Desk Works", "count": 1 }, { "value": "Tech Titans", "count": 1 }]));
+  }
+
+  #[test]
+  fn reports_bad_requests() {
+    let index = index();
+    assert_eq!(get(&index, "/search?q=a+AND"), error(400, "unexpected end of the query at position 6"));
+    assert_eq!(get(&index, "/search?q=mouse~9"), error(400, "9 typos are too many, a fuzzy search forgives at most 2 at position 1"));
+    assert_eq!(get(&index, "/search?limit=ten"), error(400, "limit has to be a number, not 'ten'"));
+    assert_eq!(get(&index, "/products/one"), error(400, "'one' is not a product id"));
+    assert_eq!(get(&index, "/products/7"), error(404, "no product with id 7"));
+    assert_eq!(get(&index, "/"), error(404, "nothing at /"));
+    assert_eq!(route(&index, &Method::Post, "/search"), error(405, "POST is not supported, only GET is"));
+  }
+}
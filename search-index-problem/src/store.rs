@@ -0,0 +1,309 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::index::{Field, Posting, SearchIndex};
+use crate::Product;
+
+// Index file layout, all the numbers are little-endian and strings are a u32 length followed by UTF-8:
+//
+//   magic "SIDX", u32 version
+//   u32 product count, then for every product:
+//     u8 deleted flag, i32 id, name, description, vendor, color, u32 word count of every field
+//   u32 word count, then for every word in ascending order:
+//     word, u8 field count, then for every field:
+//       u8 field, u32 posting count, then (u32 position, u32 count) for every posting
+//
+// Bump the version whenever the layout or the analysis of the words changes, older files are rejected
+// rather than misread.
+const MAGIC: &[u8; 4] = b"SIDX";
+const VERSION: u32 = 3;
+
+fn corrupt(message: impl Into<String>) -> io::Error {
+  io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
+
+struct Writer {
+  bytes: Vec<u8>,
+}
+
+impl Writer {
+  fn u8(&mut self, value: u8) {
+    self.bytes.push(value);
+  }
+
+  fn u32(&mut self, value: u32) {
+    self.bytes.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn i32(&mut self, value: i32) {
+    self.bytes.extend_from_slice(&value.to_le_bytes());
+  }
+
+  fn len(&mut self, len: usize) {
+    self.u32(u32::try_from(len).expect("index sections fit in u32 lengths"));
+  }
+
+  fn string(&mut self, value: &str) {
+    self.len(value.len());
+    self.bytes.extend_from_slice(value.as_bytes());
+  }
+}
+
+struct Reader<'b> {
+  bytes: &'b [u8],
+  offset: usize,
+}
+
+impl<'b> Reader<'b> {
+  fn take(&mut self, len: usize) -> io::Result<&'b [u8]> {
+    let bytes = self.bytes.get(self.offset..self.offset.saturating_add(len))
+      .ok_or_else(|| corrupt(format!("index file is truncated at byte {}", self.offset)))?;
+    self.offset += len;
+    Ok(bytes)
+  }
+
+  fn u8(&mut self) -> io::Result<u8> {
+    Ok(self.take(1)?[0])
+  }
+
+  fn u32(&mut self) -> io::Result<u32> {
+    Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+  }
+
+  fn i32(&mut self) -> io::Result<i32> {
+    Ok(i32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+  }
+
+  // A count of the items that follow, capped for preallocation so a corrupt count can't eat up the memory
+  fn count(&mut self) -> io::Result<(usize, usize)> {
+    let count = self.u32()? as usize;
+    Ok((count, count.min(self.bytes.len() - self.offset)))
+  }
+
+  fn string(&mut self) -> io::Result<String> {
+    let offset = self.offset;
+    let len = self.u32()? as usize;
+    let bytes = self.take(len)?;
+    String::from_utf8(bytes.to_vec()).map_err(|_| corrupt(format!("invalid UTF-8 in the string at byte {}", offset)))
+  }
+
+  fn field(&mut self) -> io::Result<Field> {
+    let offset = self.offset;
+    let field = self.u8()?;
+    Field::ALL.get(field as usize).copied().ok_or_else(|| corrupt(format!("unknown field {} at byte {}", field, offset)))
+  }
+}
+
+pub fn encode(index: &SearchIndex) -> Vec<u8> {
+  let mut writer = Writer { bytes: Vec::new() };
+  writer.bytes.extend_from_slice(MAGIC);
+  writer.u32(VERSION);
+
+  writer.len(index.products().len());
+  for (position, (product, lengths)) in index.products().iter().zip(index.field_lengths()).enumerate() {
+    writer.u8(index.is_deleted(position) as u8);
+    writer.i32(product.id);
+    for field in Field::ALL {
+      writer.string(field.text(product));
+    }
+    for &length in lengths {
+      writer.u32(length);
+    }
+  }
+
+  // Sorted so that the same index is always saved the same way
+  let mut words: Vec<_> = index.words().iter().collect();
+  words.sort_unstable_by_key(|(word, _)| word.as_str());
+  writer.len(words.len());
+  for (word, field_postings) in words {
+    writer.string(word);
+    writer.u8(field_postings.len() as u8);
+    for field in Field::ALL {
+      let Some(postings) = field_postings.get(&field) else { continue };
+      writer.u8(field as u8);
+      writer.len(postings.len());
+      for posting in postings {
+        writer.len(posting.position);
+        writer.u32(posting.count);
+      }
+    }
+  }
+  writer.bytes
+}
+
+pub fn decode(bytes: &[u8]) -> io::Result<SearchIndex> {
+  let mut reader = Reader { bytes, offset: 0 };
+  if reader.take(MAGIC.len()).ok() != Some(MAGIC.as_slice()) {
+    return Err(corrupt("not a search index file"));
+  }
+  let version = reader.u32()?;
+  if version != VERSION {
+    return Err(corrupt(format!("unsupported index version {} (expected {})", version, VERSION)));
+  }
+
+  let (product_count, capacity) = reader.count()?;
+  let mut products = Vec::with_capacity(capacity);
+  let mut lengths = Vec::with_capacity(capacity);
+  let mut deleted = Vec::with_capacity(capacity);
+  for _ in 0..product_count {
+    deleted.push(reader.u8()? != 0);
+    products.push(Product {
+      id: reader.i32()?,
+      name: reader.string()?,
+      description: reader.string()?,
+      vendor: reader.string()?,
+      color: reader.string()?,
+    });
+    let mut product_lengths = [0; 4];
+    for length in &mut product_lengths {
+      *length = reader.u32()?;
+    }
+    lengths.push(product_lengths);
+  }
+
+  let (word_count, capacity) = reader.count()?;
+  let mut words = HashMap::with_capacity(capacity);
+  for _ in 0..word_count {
+    let word = reader.string()?;
+    let mut field_postings = HashMap::new();
+    for _ in 0..reader.u8()? {
+      let field = reader.field()?;
+      let (posting_count, capacity) = reader.count()?;
+      let mut postings: Vec<Posting> = Vec::with_capacity(capacity);
+      for _ in 0..posting_count {
+        let offset = reader.offset;
+        let posting = Posting { position: reader.u32()? as usize, count: reader.u32()? };
+        // Searching relies on both, so catch them here rather than panic in the middle of a query
+        if posting.position >= product_count || postings.last().is_some_and(|last| last.position >= posting.position) {
+          return Err(corrupt(format!("invalid posting for '{}' at byte {}", word, offset)));
+        }
+        postings.push(posting);
+      }
+      field_postings.insert(field, postings);
+    }
+    words.insert(word, field_postings);
+  }
+
+  if reader.offset != bytes.len() {
+    return Err(corrupt(format!("unexpected data after the end of the index at byte {}", reader.offset)));
+  }
+  SearchIndex::from_parts(products, deleted, words, lengths).map_err(|e| corrupt(e.to_string()))
+}
+
+// Persists the index for open to read back, build and every change to a saved index end with it.
+// Writes the index next to the destination first, so a crash never leaves a half-written index behind
+pub fn save(index: &SearchIndex, path: &Path) -> io::Result<()> {
+  let mut temp_path = path.as_os_str().to_owned();
+  temp_path.push(".tmp");
+  fs::write(&temp_path, encode(index))?;
+  fs::rename(&temp_path, path)
+}
+
+// Reads back an index written by save, without tokenizing the products again. The file is decoded
+// whole, so the index is held in memory like a freshly built one.
+pub fn open(path: &Path) -> io::Result<SearchIndex> {
+  decode(&fs::read(path)?)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::index::build_word_index;
+  use crate::query::parse_query;
+  use crate::search::search;
+
+  fn catalog() -> Vec<Product> {
+    let product = |id, name: &str, description: &str, vendor: &str, color: &str| Product {
+      id,
+      name: name.to_string(),
+      description: description.to_string(),
+      vendor: vendor.to_string(),
+      color: color.to_string(),
+    };
+    vec![
+      product(1, "Innovative Gaming Keyboard", "A gaming keyboard with RGB lighting.", "Tech Titans", "black"),
+      product(7, "Cool Metal Table", "A cool table with steel legs.", "Steel Co", "steel"),
+      product(3, "Black Coffee Mug", "Keeps coffee hot, black or not. Ünïcödé too.", "Kitchen Creations", "white"),
+    ]
+  }
+
+  fn results(index: &SearchIndex, query: &str) -> Vec<(i32, String)> {
+    search(index, &parse_query(query).unwrap()).iter().map(|hit| (hit.product.id, format!("{:.6}", hit.score))).collect()
+  }
+
+  #[test]
+  fn decoded_index_searches_the_same() {
+    let index = build_word_index(catalog());
+    let decoded = decode(&encode(&index)).unwrap();
+    assert!(decoded.products() == index.products());
+    assert_eq!(decoded.field_lengths(), index.field_lengths());
+    assert_eq!(decoded.words(), index.words());
+    for query in ["black", r#""gaming keyboard""#, "cool OR coffee", "NOT steel", "ünïcödé"] {
+      assert_eq!(results(&decoded, query), results(&index, query));
+    }
+  }
+
+  #[test]
+  fn keeps_tombstones() {
+    let mut index = build_word_index(catalog());
+    for (id, name) in [(8, "Steel Kettle"), (9, "Cool Box")] {
+      index.insert(Product { id, name: name.to_string(), description: String::new(), vendor: String::new(), color: String::new() }).unwrap();
+    }
+    index.delete(7).unwrap();
+    assert!(index.is_deleted(1));
+
+    let decoded = decode(&encode(&index)).unwrap();
+    assert!(decoded.is_deleted(1));
+    assert_eq!(decoded.len(), 4);
+    for query in ["cool", "steel", "NOT black"] {
+      assert_eq!(results(&decoded, query), results(&index, query));
+    }
+  }
+
+  #[test]
+  fn encoding_is_deterministic() {
+    assert_eq!(encode(&build_word_index(catalog())), encode(&build_word_index(catalog())));
+  }
+
+  #[test]
+  fn saves_and_opens_files() {
+    let path = std::env::temp_dir().join(format!("search-index-{}.idx", std::process::id()));
+    let index = build_word_index(catalog());
+    save(&index, &path).unwrap();
+    let opened = open(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    assert_eq!(results(&opened, "black"), results(&index, "black"));
+  }
+
+  #[test]
+  fn rejects_broken_files() {
+    let bytes = encode(&build_word_index(catalog()));
+    let message = |bytes: &[u8]| decode(bytes).err().unwrap().to_string();
+
+    assert_eq!(message(b"{\"products\": []}"), "not a search index file");
+
+    let mut old = bytes.clone();
+    old[4..8].copy_from_slice(&1u32.to_le_bytes());
+    assert_eq!(message(&old), "unsupported index version 1 (expected 3)");
+
+    for len in [8, 20, bytes.len() - 1] {
+      assert!(message(&bytes[..len]).starts_with("index file is truncated"));
+    }
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert!(message(&trailing).starts_with("unexpected data after the end"));
+  }
+
+  #[test]
+  fn rejects_postings_past_the_products() {
+    let products = catalog().into_iter().take(1).collect();
+    let mut bytes = encode(&build_word_index(products));
+    // The last posting is the position and the count of the last field of the last word
+    let position = bytes.len() - 8;
+    bytes[position..position + 4].copy_from_slice(&5u32.to_le_bytes());
+    assert!(decode(&bytes).err().unwrap().to_string().starts_with("invalid posting"));
+  }
+}
@@ -0,0 +1,69 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+// Splits the text on Unicode word boundaries, dropping punctuation and whitespace,
+// so "high-performance" and "night's" become words the way people would search for them
+pub fn tokenize(text: &str) -> Vec<String> {
+  text.unicode_words().map(|word| word.to_lowercase()).collect()
+}
+
+// Words the way they are indexed and searched for: tokenized and stemmed
+pub fn analyze(text: &str) -> Vec<String> {
+  tokenize(text).into_iter().map(|word| stem(&word)).collect()
+}
+
+// A light stemmer that only turns plurals and possessives into the singular, so "keyboards",
+// "batteries" and "watches" find "keyboard", "battery" and "watch". Words like "glass", "bus" and
+// "analysis" are left alone, anything smarter would start conflating unrelated words.
+pub fn stem(word: &str) -> String {
+  let chars = word.chars().count();
+  if let Some(base) = word.strip_suffix("'s").or_else(|| word.strip_suffix("’s")) {
+    return base.to_string();
+  }
+  if chars <= 3 {
+    return word.to_string();
+  }
+  if let Some(base) = word.strip_suffix("ies") {
+    if chars > 4 && !base.ends_with(['a', 'e']) {
+      return format!("{}y", base);
+    }
+  }
+  if let Some(base) = word.strip_suffix("es") {
+    if ["ss", "x", "ch", "sh", "zz"].iter().any(|ending| base.ends_with(ending)) {
+      return base.to_string();
+    }
+  }
+  if word.ends_with('s') && !["ss", "us", "is"].iter().any(|ending| word.ends_with(ending)) {
+    return word[..word.len() - 1].to_string();
+  }
+  word.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn strips_punctuation_and_lowercases() {
+    assert_eq!(tokenize("A high-performance gaming keyboard with RGB lighting."), [
+      "a", "high", "performance", "gaming", "keyboard", "with", "rgb", "lighting",
+    ]);
+    assert_eq!(tokenize("a restful night's sleep!"), ["a", "restful", "night's", "sleep"]);
+  }
+
+  #[test]
+  fn handles_unicode() {
+    assert_eq!(tokenize("Crème Brûlée, ÜBER-Größe"), ["crème", "brûlée", "über", "größe"]);
+    assert!(tokenize(" -- ... ").is_empty());
+  }
+
+  #[test]
+  fn stems_plurals() {
+    let stems = |words: &[&str]| words.iter().map(|word| stem(word)).collect::<Vec<_>>();
+    assert_eq!(stems(&["keyboards", "batteries", "watches", "boxes", "glasses", "shoes", "tables"]), [
+      "keyboard", "battery", "watch", "box", "glass", "shoe", "table",
+    ]);
+    assert_eq!(stems(&["glass", "bus", "analysis", "ties", "keys", "gas", "its"]), ["glass", "bus", "analysis", "tie", "key", "gas", "its"]);
+    assert_eq!(stems(&["night's", "kids'"]), ["night", "kids'"]);
+    assert_eq!(analyze("Wireless Earbuds, 2 batteries"), ["wireless", "earbud", "2", "battery"]);
+  }
+}
@@ -0,0 +1,124 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpStream;
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use serde_json::Value;
+
+const BINARY: &str = env!("CARGO_BIN_EXE_search-index-problem");
+
+// The server over an index of products.json on a free localhost port, stopped when dropped
+struct Server {
+  process: Child,
+  address: String,
+  index_file: PathBuf,
+}
+
+impl Server {
+  fn start() -> Server {
+    static SERVERS: AtomicUsize = AtomicUsize::new(0);
+    let name = format!("search-index-server-{}-{}.idx", std::process::id(), SERVERS.fetch_add(1, Ordering::Relaxed));
+    let index_file = std::env::temp_dir().join(name);
+    let products_file = concat!(env!("CARGO_MANIFEST_DIR"), "/products.json");
+    let status = Command::new(BINARY).arg("build").arg(products_file).arg(&index_file).stdout(Stdio::null()).status().unwrap();
+    assert!(status.success(), "building the index failed");
+
+    let mut process = Command::new(BINARY).arg("serve").arg(&index_file).arg("127.0.0.1:0").stdout(Stdio::piped()).spawn().unwrap();
+    let mut line = String::new();
+    BufReader::new(process.stdout.take().unwrap()).read_line(&mut line).unwrap();
+    let address = line.trim().strip_prefix("Listening on http://").unwrap_or_else(|| panic!("unexpected output: {:?}", line)).to_string();
+    Server { process, address, index_file }
+  }
+
+  // Just enough HTTP/1.1 for the tests: the status code, the headers and the JSON body of the response
+  fn request(&self, method: &str, path: &str) -> (u16, String, Value) {
+    let mut stream = TcpStream::connect(&self.address).unwrap();
+    write!(stream, "{} {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n", method, path, self.address).unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, head.to_lowercase(), serde_json::from_str(body).unwrap())
+  }
+
+  fn get(&self, path: &str) -> (u16, Value) {
+    let (status, _, body) = self.request("GET", path);
+    (status, body)
+  }
+}
+
+impl Drop for Server {
+  fn drop(&mut self) {
+    let _ = self.process.kill();
+    let _ = self.process.wait();
+    let _ = std::fs::remove_file(&self.index_file);
+  }
+}
+
+fn ids(body: &Value) -> Vec<i64> {
+  body["hits"].as_array().unwrap().iter().map(|hit| hit["product"]["id"].as_i64().unwrap()).collect()
+}
+
+#[test]
+fn searches_the_catalog() {
+  let server = Server::start();
+  let (status, headers, body) = server.request("GET", "/search?q=gaming+keyboard");
+  assert_eq!(status, 200);
+  assert!(headers.contains("content-type: application/json"));
+  let mut found = ids(&body);
+  found.sort_unstable();
+  assert_eq!(found, [2, 13]);
+  assert_eq!(body["total"], 2);
+
+  let scores: Vec<f64> = body["hits"].as_array().unwrap().iter().map(|hit| hit["score"].as_f64().unwrap()).collect();
+  assert!(scores.windows(2).all(|pair| pair[0] >= pair[1]));
+  assert_eq!(body["hits"][0]["product"]["vendor"], "Steel Co");
+}
+
+#[test]
+fn decodes_the_query() {
+  let server = Server::start();
+  let (_, body) = server.get("/search?q=vendor%3A%22Tech%20Titans%22+color%3Ablack");
+  assert_eq!(ids(&body), [2]);
+  let (_, body) = server.get("/search?q=keybaords~");
+  assert_eq!(body["total"], 2);
+}
+
+#[test]
+fn pages_and_filters() {
+  let server = Server::start();
+  let (_, all) = server.get("/search?q=black");
+  let (_, page) = server.get("/search?q=black&limit=1&offset=1");
+  assert_eq!(ids(&page), ids(&all)[1..2]);
+  assert_eq!(page["total"], all["total"]);
+
+  let (_, black) = server.get("/search?color=black&limit=100");
+  assert_eq!(black["total"], black["hits"].as_array().unwrap().len());
+  let colors = black["facets"]["color"].as_array().unwrap();
+  let black_count = colors.iter().find(|color| color["value"] == "black").unwrap()["count"].clone();
+  assert_eq!(black["total"], black_count);
+  assert!(colors.len() > 1);
+}
+
+#[test]
+fn serves_products_by_id() {
+  let server = Server::start();
+  let (status, product) = server.get("/products/2");
+  assert_eq!(status, 200);
+  assert_eq!(product["name"], "Innovative Gaming Keyboard");
+  assert_eq!(product["color"], "black");
+
+  assert_eq!(server.get("/products/999"), (404, serde_json::json!({ "error": "no product with id 999" })));
+  assert_eq!(server.get("/products/two").0, 400);
+}
+
+#[test]
+fn reports_errors_as_json() {
+  let server = Server::start();
+  let (status, body) = server.get("/search?q=%22unterminated");
+  assert_eq!(status, 400);
+  assert_eq!(body["error"], "unterminated phrase at position 1");
+  assert_eq!(server.get("/nowhere").0, 404);
+  assert_eq!(server.request("DELETE", "/products/2").0, 405);
+}